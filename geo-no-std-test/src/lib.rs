@@ -0,0 +1,34 @@
+//! Checks that the planar sweep of `geo` builds without `std`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use geo::sweep::Intersections;
+use geo::{Coordinate, Line, LineIntersection};
+
+/// The points where the lines cross, or start to overlap.
+pub fn crossings(lines: &[Line<f64>]) -> Vec<Coordinate<f64>> {
+    Intersections::from_iter(lines.iter().copied())
+        .map(|(_, _, int)| match int {
+            LineIntersection::SinglePoint { intersection, .. } => intersection,
+            LineIntersection::Collinear { intersection } => intersection.start,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::coord;
+
+    #[test]
+    fn crossing_lines() {
+        let lines = [
+            Line::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. }),
+            Line::new(coord! { x: 0., y: 2. }, coord! { x: 2., y: 0. }),
+            Line::new(coord! { x: 3., y: 0. }, coord! { x: 3., y: 2. }),
+        ];
+        assert_eq!(crossings(&lines), [coord! { x: 1., y: 1. }]);
+    }
+}
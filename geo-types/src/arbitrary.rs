@@ -2,7 +2,8 @@ use crate::{
     CoordFloat, Coordinate, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use std::mem;
+use alloc::vec::Vec;
+use core::mem;
 
 impl<'a, T> arbitrary::Arbitrary<'a> for Coordinate<T>
 where
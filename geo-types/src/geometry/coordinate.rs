@@ -97,7 +97,7 @@ impl<T: CoordNum> Coordinate<T> {
     }
 }
 
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Negate a coordinate.
 ///
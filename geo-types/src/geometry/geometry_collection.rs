@@ -1,9 +1,10 @@
-use crate::{CoordNum, Geometry};
+use crate::{CoordNum, Geometry, MultiPolygon};
+use alloc::{vec, vec::Vec};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// A collection of [`Geometry`](enum.Geometry.html) types.
 ///
@@ -106,6 +107,36 @@ impl<T: CoordNum> GeometryCollection<T> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// The polygons of this GeometryCollection, including those of its
+    /// `MultiPolygon`s, as a `MultiPolygon`. The other geometries are
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, GeometryCollection, Geometry, MultiPolygon, Point};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// let gc = GeometryCollection::new_from(vec![
+    ///     Geometry::Polygon(square.clone()),
+    ///     Geometry::Point(Point::new(2., 2.)),
+    /// ]);
+    ///
+    /// assert_eq!(gc.just_polygons(), MultiPolygon::new(vec![square]));
+    /// ```
+    pub fn just_polygons(self) -> MultiPolygon<T> {
+        MultiPolygon::new(
+            self.0
+                .into_iter()
+                .flat_map(|geom| match geom {
+                    Geometry::Polygon(poly) => vec![poly],
+                    Geometry::MultiPolygon(mp) => mp.0,
+                    _ => vec![],
+                })
+                .collect(),
+        )
+    }
 }
 
 #[deprecated(since = 0.7.5, note = "Use `GeometryCollection::from(vec![geom])` instead.")]
@@ -146,7 +177,7 @@ impl<T: CoordNum> IndexMut<usize> for GeometryCollection<T> {
 // structure helper for consuming iterator
 #[derive(Debug)]
 pub struct IntoIteratorHelper<T: CoordNum> {
-    iter: ::std::vec::IntoIter<Geometry<T>>,
+    iter: ::alloc::vec::IntoIter<Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a consuming iterator. Iteration will
@@ -176,7 +207,7 @@ impl<T: CoordNum> Iterator for IntoIteratorHelper<T> {
 // structure helper for non-consuming iterator
 #[derive(Debug)]
 pub struct IterHelper<'a, T: CoordNum> {
-    iter: ::std::slice::Iter<'a, Geometry<T>>,
+    iter: ::core::slice::Iter<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a non-consuming iterator. Iteration will
@@ -206,7 +237,7 @@ impl<'a, T: CoordNum> Iterator for IterHelper<'a, T> {
 // structure helper for mutable non-consuming iterator
 #[derive(Debug)]
 pub struct IterMutHelper<'a, T: CoordNum> {
-    iter: ::std::slice::IterMut<'a, Geometry<T>>,
+    iter: ::core::slice::IterMut<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a mutable non-consuming iterator. Iteration will
@@ -321,7 +352,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{GeometryCollection, Point};
+    use crate::{polygon, Geometry, GeometryCollection, Line, MultiPolygon, Point};
 
     #[test]
     fn from_vec() {
@@ -329,4 +360,22 @@ mod tests {
         let p = Point::try_from(gc[0].clone()).unwrap();
         assert_eq!(p.y(), 2);
     }
+
+    #[test]
+    fn just_polygons() {
+        let a = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1)];
+        let b = polygon![(x: 2, y: 0), (x: 3, y: 0), (x: 2, y: 1)];
+        let c = polygon![(x: 4, y: 0), (x: 5, y: 0), (x: 4, y: 1)];
+        let gc = GeometryCollection::new_from(vec![
+            Geometry::Line(Line::new((0, 0), (1, 1))),
+            Geometry::Polygon(a.clone()),
+            Geometry::MultiPolygon(MultiPolygon::new(vec![b.clone(), c.clone()])),
+            Geometry::Point(Point::new(1, 2)),
+        ]);
+        assert_eq!(gc.just_polygons(), MultiPolygon::new(vec![a, b, c]));
+        assert!(GeometryCollection::<f64>::default()
+            .just_polygons()
+            .0
+            .is_empty());
+    }
 }
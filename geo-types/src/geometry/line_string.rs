@@ -2,8 +2,9 @@
 use approx::{AbsDiffEq, RelativeEq};
 
 use crate::{CoordNum, Coordinate, Line, Point, Triangle};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use alloc::{vec, vec::Vec};
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// An ordered collection of two or more [`Coordinate`]s, representing a
 /// path between locations.
@@ -136,7 +137,7 @@ pub struct LineString<T: CoordNum = f64>(pub Vec<Coordinate<T>>);
 
 /// A [`Point`] iterator returned by the `points` method
 #[derive(Debug)]
-pub struct PointsIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coordinate<T>>);
+pub struct PointsIter<'a, T: CoordNum + 'a>(::core::slice::Iter<'a, Coordinate<T>>);
 
 impl<'a, T: CoordNum> Iterator for PointsIter<'a, T> {
     type Item = Point<T>;
@@ -164,7 +165,7 @@ impl<'a, T: CoordNum> DoubleEndedIterator for PointsIter<'a, T> {
 
 /// A [`Coordinate`] iterator used by the `into_iter` method on a [`LineString`]
 #[derive(Debug)]
-pub struct CoordinatesIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coordinate<T>>);
+pub struct CoordinatesIter<'a, T: CoordNum + 'a>(::core::slice::Iter<'a, Coordinate<T>>);
 
 impl<'a, T: CoordNum> Iterator for CoordinatesIter<'a, T> {
     type Item = &'a Coordinate<T>;
@@ -287,6 +288,33 @@ impl<T: CoordNum> LineString<T> {
         }
     }
 
+    /// Return a copy of the [`LineString`] with its coordinates in the reverse order.
+    ///
+    /// This flips the orientation of a closed ring, from clockwise to
+    /// counter-clockwise and back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 7., y: 9.)];
+    /// assert_eq!(
+    ///     line_string.reverse(),
+    ///     line_string![(x: 7., y: 9.), (x: 5., y: 0.), (x: 0., y: 0.)]
+    /// );
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut reversed = self.clone();
+        reversed.reverse_mut();
+        reversed
+    }
+
+    /// Reverse the order of the coordinates of the [`LineString`] in place.
+    pub fn reverse_mut(&mut self) {
+        self.0.reverse();
+    }
+
     /// Return the number of coordinates in the [`LineString`].
     ///
     /// # Examples
@@ -358,7 +386,7 @@ impl<T: CoordNum, IC: Into<Coordinate<T>>> FromIterator<IC> for LineString<T> {
 /// Iterate over all the [`Coordinate`]s in this [`LineString`].
 impl<T: CoordNum> IntoIterator for LineString<T> {
     type Item = Coordinate<T>;
-    type IntoIter = ::std::vec::IntoIter<Coordinate<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Coordinate<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -377,9 +405,9 @@ impl<'a, T: CoordNum> IntoIterator for &'a LineString<T> {
 /// Mutably iterate over all the [`Coordinate`]s in this [`LineString`]
 impl<'a, T: CoordNum> IntoIterator for &'a mut LineString<T> {
     type Item = &'a mut Coordinate<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Coordinate<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Coordinate<T>>;
 
-    fn into_iter(self) -> ::std::slice::IterMut<'a, Coordinate<T>> {
+    fn into_iter(self) -> ::core::slice::IterMut<'a, Coordinate<T>> {
         self.0.iter_mut()
     }
 }
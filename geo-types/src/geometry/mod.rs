@@ -19,7 +19,7 @@ pub use multi_line_string::MultiLineString;
 pub use multi_point::MultiPoint;
 pub use multi_polygon::MultiPolygon;
 pub use point::Point;
-pub use polygon::Polygon;
+pub use polygon::{Polygon, PolygonBuilder, RingId, RingProblem, ValidationError};
 pub use rect::Rect;
 pub use triangle::Triangle;
 
@@ -28,7 +28,7 @@ use crate::{CoordNum, Error};
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 use core::any::type_name;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 /// An enum representing any possible geometry type.
 ///
@@ -1,9 +1,10 @@
 use crate::{CoordNum, Point};
+use alloc::{vec, vec::Vec};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 
 /// A collection of [`Point`s](struct.Point.html). Can
 /// be created from a `Vec` of `Point`s, or from an
@@ -58,7 +59,7 @@ impl<T: CoordNum, IP: Into<Point<T>>> FromIterator<IP> for MultiPoint<T> {
 /// Iterate over the `Point`s in this `MultiPoint`.
 impl<T: CoordNum> IntoIterator for MultiPoint<T> {
     type Item = Point<T>;
-    type IntoIter = ::std::vec::IntoIter<Point<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Point<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -67,7 +68,7 @@ impl<T: CoordNum> IntoIterator for MultiPoint<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a MultiPoint<T> {
     type Item = &'a Point<T>;
-    type IntoIter = ::std::slice::Iter<'a, Point<T>>;
+    type IntoIter = ::core::slice::Iter<'a, Point<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self.0).iter()
@@ -76,7 +77,7 @@ impl<'a, T: CoordNum> IntoIterator for &'a MultiPoint<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a mut MultiPoint<T> {
     type Item = &'a mut Point<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Point<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Point<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&mut self.0).iter_mut()
@@ -1,8 +1,9 @@
 use crate::{CoordNum, Polygon};
+use alloc::{vec, vec::Vec};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 
 /// A collection of [`Polygon`s](struct.Polygon.html). Can
 /// be created from a `Vec` of `Polygon`s, or from an
@@ -49,7 +50,7 @@ impl<T: CoordNum, IP: Into<Polygon<T>>> FromIterator<IP> for MultiPolygon<T> {
 
 impl<T: CoordNum> IntoIterator for MultiPolygon<T> {
     type Item = Polygon<T>;
-    type IntoIter = ::std::vec::IntoIter<Polygon<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Polygon<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -58,7 +59,7 @@ impl<T: CoordNum> IntoIterator for MultiPolygon<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a MultiPolygon<T> {
     type Item = &'a Polygon<T>;
-    type IntoIter = ::std::slice::Iter<'a, Polygon<T>>;
+    type IntoIter = ::core::slice::Iter<'a, Polygon<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self.0).iter()
@@ -67,7 +68,7 @@ impl<'a, T: CoordNum> IntoIterator for &'a MultiPolygon<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a mut MultiPolygon<T> {
     type Item = &'a mut Polygon<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Polygon<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Polygon<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&mut self.0).iter_mut()
@@ -87,6 +88,51 @@ impl<T: CoordNum> MultiPolygon<T> {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Polygon<T>> {
         self.0.iter_mut()
     }
+
+    /// Instantiate Self from its constituent `Polygon`s.
+    ///
+    /// The polygons are taken as they are: overlapping polygons are not
+    /// dissolved, and the result is only valid if they are disjoint.
+    /// Use the `union` boolean operation of `geo` to dissolve them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, MultiPolygon};
+    ///
+    /// let parts = vec![
+    ///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)],
+    ///     polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 2., y: 1.)],
+    /// ];
+    /// let multi = MultiPolygon::from_parts(parts.clone());
+    /// assert_eq!(multi.0, parts);
+    /// ```
+    pub fn from_parts(parts: impl IntoIterator<Item = Polygon<T>>) -> Self {
+        Self(parts.into_iter().collect())
+    }
+
+    /// Concatenate the `Polygon`s of several `MultiPolygon`s, in order.
+    ///
+    /// Like [`from_parts`](MultiPolygon::from_parts), this does not
+    /// dissolve overlapping polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, MultiPolygon};
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+    /// let b = polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 2., y: 1.)];
+    /// let multi = MultiPolygon::flatten(vec![
+    ///     MultiPolygon::new(vec![a.clone()]),
+    ///     MultiPolygon::new(vec![]),
+    ///     MultiPolygon::new(vec![b.clone()]),
+    /// ]);
+    /// assert_eq!(multi, MultiPolygon::new(vec![a, b]));
+    /// ```
+    pub fn flatten(multis: impl IntoIterator<Item = MultiPolygon<T>>) -> Self {
+        Self(multis.into_iter().flat_map(|multi| multi.0).collect())
+    }
 }
 
 #[cfg(any(feature = "approx", test))]
@@ -215,6 +261,22 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_from_parts_and_flatten() {
+        let a = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 1, y: 2), (x:0, y:0)];
+        let b = polygon![(x: 1, y: 0), (x: 3, y: 0), (x: 2, y: 2), (x:1, y:0)];
+        let c = polygon![(x: 10, y: 10), (x: 12, y: 10), (x: 11, y: 12), (x:10, y:10)];
+
+        // Overlapping parts are kept as they are.
+        let ab = MultiPolygon::from_parts(vec![a.clone(), b.clone()]);
+        assert_eq!(ab.0, vec![a.clone(), b.clone()]);
+        assert_eq!(MultiPolygon::<i32>::from_parts(vec![]).0.len(), 0);
+
+        let flat = MultiPolygon::flatten(vec![ab, MultiPolygon::new(vec![]), c.clone().into()]);
+        assert_eq!(flat, MultiPolygon::new(vec![a, b, c]));
+        assert_eq!(MultiPolygon::<i32>::flatten(vec![]).0.len(), 0);
+    }
+
     #[test]
     fn test_iter_mut() {
         let mut multi = MultiPolygon::new(vec![
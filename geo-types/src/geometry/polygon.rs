@@ -1,4 +1,5 @@
-use crate::{CoordFloat, CoordNum, LineString, Point, Rect, Triangle};
+use crate::{CoordFloat, CoordNum, Coordinate, LineString, Point, Rect, Triangle};
+use alloc::{vec, vec::Vec};
 use num_traits::{Float, Signed};
 
 #[cfg(any(feature = "approx", test))]
@@ -384,6 +385,125 @@ impl<T: CoordNum> Polygon<T> {
         self.interiors.push(new_interior);
     }
 
+    /// Remove the interior ring at `index` from the `Polygon`, shifting
+    /// the following ones, and return it.
+    ///
+    /// Use [`interiors_mut`](Self::interiors_mut) to reorder the
+    /// interior rings in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, LineString};
+    ///
+    /// let mut polygon = polygon![
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+    ///     interiors: [
+    ///         [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+    ///         [(x: 3., y: 3.), (x: 3.5, y: 3.), (x: 3.5, y: 3.5), (x: 3., y: 3.5)],
+    ///     ],
+    /// ];
+    ///
+    /// let removed = polygon.interiors_remove(0);
+    /// assert_eq!(removed.0[0], (1., 1.).into());
+    /// assert_eq!(polygon.interiors().len(), 1);
+    /// ```
+    pub fn interiors_remove(&mut self, index: usize) -> LineString<T> {
+        self.interiors.remove(index)
+    }
+
+    /// Return a copy of the `Polygon` with the coordinates of the exterior
+    /// and of all the interiors in the reverse order, flipping the
+    /// orientation of every ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, polygon};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+    /// assert_eq!(
+    ///     polygon.reverse().exterior(),
+    ///     &line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 0.)]
+    /// );
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut reversed = self.clone();
+        reversed.reverse_mut();
+        reversed
+    }
+
+    /// Reverse the coordinates of the exterior and of all the interiors of
+    /// the `Polygon` in place.
+    pub fn reverse_mut(&mut self) {
+        self.exterior.reverse_mut();
+        for interior in &mut self.interiors {
+            interior.reverse_mut();
+        }
+    }
+
+    /// Create a new `Polygon` from raw rings, checking and normalizing them
+    /// into the canonical form expected by the boolean operations.
+    ///
+    /// Unlike [`Polygon::new`], the rings are not closed: each ring must
+    /// already end at its first coordinate. Consecutive duplicate
+    /// coordinates are removed, and the rings are oriented with the
+    /// exterior counter-clockwise and the interiors clockwise.
+    ///
+    /// The problems of all the rings are reported together: rings that
+    /// are not closed, have fewer than three distinct vertices, or
+    /// enclose no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Polygon, RingId, RingProblem};
+    ///
+    /// // A clockwise exterior with a duplicate coordinate.
+    /// let exterior = line_string![
+    ///     (x: 0., y: 0.), (x: 0., y: 2.), (x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 0.)
+    /// ];
+    /// let polygon = Polygon::try_clean(exterior, vec![]).unwrap();
+    /// assert_eq!(
+    ///     polygon.exterior(),
+    ///     &line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)]
+    /// );
+    ///
+    /// let unclosed = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+    /// let err = Polygon::try_clean(unclosed, vec![]).unwrap_err();
+    /// assert_eq!(err.problems, vec![(RingId::Exterior, RingProblem::Unclosed)]);
+    /// ```
+    pub fn try_clean(
+        exterior: LineString<T>,
+        interiors: Vec<LineString<T>>,
+    ) -> Result<Self, ValidationError> {
+        let mut problems = vec![];
+        let mut clean = |ring: LineString<T>, id: RingId| match clean_ring(ring, id) {
+            Ok(ring) => Some(ring),
+            Err(problem) => {
+                problems.push((id, problem));
+                None
+            }
+        };
+        let exterior = clean(exterior, RingId::Exterior);
+        let interiors: Vec<_> = interiors
+            .into_iter()
+            .enumerate()
+            .map(|(idx, ring)| clean(ring, RingId::Interior(idx)))
+            .collect();
+        if !problems.is_empty() {
+            return Err(ValidationError { problems });
+        }
+        Ok(Self {
+            exterior: exterior.unwrap(),
+            interiors: interiors.into_iter().map(Option::unwrap).collect(),
+        })
+    }
+
     /// Wrap-around previous-vertex
     fn previous_vertex(&self, current_vertex: usize) -> usize
     where
@@ -393,6 +513,157 @@ impl<T: CoordNum> Polygon<T> {
     }
 }
 
+/// Remove the consecutive duplicates of a closed ring, and orient it
+/// counter-clockwise if it is the exterior, clockwise otherwise.
+fn clean_ring<T: CoordNum>(
+    mut ring: LineString<T>,
+    id: RingId,
+) -> Result<LineString<T>, RingProblem> {
+    if !ring.is_closed() {
+        return Err(RingProblem::Unclosed);
+    }
+    ring.0.dedup();
+    if ring.0.len() < 4 {
+        return Err(RingProblem::TooFewPoints);
+    }
+    // Twice the signed area, positive if counter-clockwise.
+    let area = ring.lines().fold(T::zero(), |area, line| {
+        area + line.start.x * line.end.y - line.end.x * line.start.y
+    });
+    if area == T::zero() {
+        return Err(RingProblem::ZeroArea);
+    }
+    if (area > T::zero()) != (id == RingId::Exterior) {
+        ring.0.reverse();
+    }
+    Ok(ring)
+}
+
+/// A ring of a `Polygon`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingId {
+    Exterior,
+    /// The interior at the given index.
+    Interior(usize),
+}
+
+/// A problem of a ring, found by [`Polygon::try_clean`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingProblem {
+    /// The last coordinate is not the first one.
+    Unclosed,
+    /// There are fewer than three distinct vertices.
+    TooFewPoints,
+    /// The ring encloses no area.
+    ZeroArea,
+}
+
+/// The problems of the rings given to [`Polygon::try_clean`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub problems: Vec<(RingId, RingProblem)>,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid polygon")?;
+        for (idx, (id, problem)) in self.problems.iter().enumerate() {
+            let sep = if idx == 0 { ": " } else { "; " };
+            match id {
+                RingId::Exterior => write!(f, "{}exterior", sep)?,
+                RingId::Interior(idx) => write!(f, "{}interior {}", sep, idx)?,
+            }
+            match problem {
+                RingProblem::Unclosed => write!(f, " is not closed")?,
+                RingProblem::TooFewPoints => write!(f, " has too few points")?,
+                RingProblem::ZeroArea => write!(f, " encloses no area")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A builder of a `Polygon` from its coordinates, ring by ring.
+///
+/// The rings are closed if needed, and the `Polygon` is then checked and
+/// normalized by [`Polygon::try_clean`].
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{polygon, PolygonBuilder};
+///
+/// let frame = PolygonBuilder::new()
+///     .exterior([(0., 0.), (4., 0.), (4., 4.), (0., 4.)])
+///     .interior([(1., 1.), (1., 3.), (3., 3.), (3., 1.)])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     frame,
+///     polygon![
+///         exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///         interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+///     ]
+/// );
+///
+/// // The exterior may also be given coordinate by coordinate.
+/// let degenerate = PolygonBuilder::new().push((0., 0.)).push((1., 1.)).build();
+/// assert!(degenerate.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolygonBuilder<T: CoordNum = f64> {
+    exterior: Vec<Coordinate<T>>,
+    interiors: Vec<LineString<T>>,
+}
+
+impl<T: CoordNum> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self {
+            exterior: vec![],
+            interiors: vec![],
+        }
+    }
+}
+
+impl<T: CoordNum> PolygonBuilder<T> {
+    /// A builder with an empty exterior and no interiors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a coordinate to the exterior.
+    pub fn push(mut self, coord: impl Into<Coordinate<T>>) -> Self {
+        self.exterior.push(coord.into());
+        self
+    }
+
+    /// Add coordinates to the exterior.
+    pub fn exterior<C: Into<Coordinate<T>>>(mut self, coords: impl IntoIterator<Item = C>) -> Self {
+        self.exterior.extend(coords.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add an interior ring of the given coordinates.
+    pub fn interior<C: Into<Coordinate<T>>>(mut self, coords: impl IntoIterator<Item = C>) -> Self {
+        self.interiors.push(coords.into_iter().collect());
+        self
+    }
+
+    /// Close the rings, and check and normalize them by
+    /// [`Polygon::try_clean`].
+    pub fn build(self) -> Result<Polygon<T>, ValidationError> {
+        let mut exterior = LineString::new(self.exterior);
+        exterior.close();
+        let mut interiors = self.interiors;
+        interiors.iter_mut().for_each(LineString::close);
+        Polygon::try_clean(exterior, interiors)
+    }
+}
+
 // used to check the sign of a vec of floats
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum ListSign {
@@ -413,7 +684,7 @@ impl<T: CoordFloat + Signed> Polygon<T> {
     // see: http://stackoverflow.com/a/1881201/416626
     #[deprecated(
         since = "0.6.1",
-        note = "Please use `geo::is_convex` on `poly.exterior()` instead"
+        note = "Please use `geo::IsConvex`, which also accounts for the interiors, instead"
     )]
     pub fn is_convex(&self) -> bool {
         let convex = self
@@ -564,3 +835,107 @@ impl_rstar_polygon!(rstar_0_8);
 
 #[cfg(feature = "rstar_0_9")]
 impl_rstar_polygon!(rstar_0_9);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn reverse() {
+        let poly = polygon![
+            exterior: [(x: 0, y: 0), (x: 6, y: 0), (x: 6, y: 6), (x: 0, y: 6)],
+            interiors: [[(x: 1, y: 1), (x: 1, y: 2), (x: 2, y: 2), (x: 2, y: 1)]],
+        ];
+        // Twice the signed area, positive if counter-clockwise.
+        let signed_area = |ring: &LineString<i32>| {
+            ring.lines().fold(0, |area, line| {
+                area + line.start.x * line.end.y - line.end.x * line.start.y
+            })
+        };
+        let areas = |poly: &Polygon<i32>| {
+            let mut areas = vec![signed_area(poly.exterior())];
+            areas.extend(poly.interiors().iter().map(signed_area));
+            areas
+        };
+
+        let reversed = poly.reverse();
+        assert_eq!(areas(&poly), vec![72, -2]);
+        assert_eq!(areas(&reversed), vec![-72, 2]);
+        assert_eq!(reversed.reverse(), poly);
+
+        let mut in_place = poly.clone();
+        in_place.reverse_mut();
+        assert_eq!(in_place, reversed);
+        in_place.exterior_mut(|ring| ring.reverse_mut());
+        assert_eq!(in_place.exterior(), poly.exterior());
+        assert_eq!(poly.exterior().reverse().reverse(), *poly.exterior());
+    }
+
+    #[test]
+    fn try_clean() {
+        let ccw =
+            line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4), (x: 0, y: 0)];
+        let cw = line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 2), (x: 1, y: 1)];
+
+        // Wrong windings are fixed.
+        let mut reversed = ccw.clone();
+        reversed.0.reverse();
+        let polygon = Polygon::try_clean(reversed, vec![cw.clone()]).unwrap();
+        assert_eq!(polygon.exterior(), &ccw);
+        let mut hole = cw.clone();
+        hole.0.reverse();
+        assert_eq!(polygon.interiors(), &[hole]);
+
+        // All the problems are listed.
+        let unclosed = line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)];
+        let flat = line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 3, y: 1), (x: 1, y: 1)];
+        let few = line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 1), (x: 1, y: 1)];
+        let err = Polygon::try_clean(ccw, vec![unclosed, cw, flat, few]).unwrap_err();
+        assert_eq!(
+            err.problems,
+            vec![
+                (RingId::Interior(0), RingProblem::Unclosed),
+                (RingId::Interior(2), RingProblem::ZeroArea),
+                (RingId::Interior(3), RingProblem::TooFewPoints),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid polygon: interior 0 is not closed; interior 2 encloses no area; \
+             interior 3 has too few points"
+        );
+
+        let empty = Polygon::<i32>::try_clean(LineString::new(vec![]), vec![]).unwrap_err();
+        assert_eq!(
+            empty.problems,
+            vec![(RingId::Exterior, RingProblem::TooFewPoints)]
+        );
+    }
+
+    #[test]
+    fn interiors_remove() {
+        let exterior =
+            line_string![(x: 0, y: 0), (x: 9, y: 0), (x: 9, y: 9), (x: 0, y: 9), (x: 0, y: 0)];
+        let holes: Vec<LineString<i32>> = (0..3)
+            .map(|i| {
+                let x = 1 + 3 * i;
+                line_string![(x: x, y: 1), (x: x, y: 2), (x: x + 1, y: 2), (x: x + 1, y: 1), (x: x, y: 1)]
+            })
+            .collect();
+        let mut polygon = Polygon::new(exterior.clone(), holes.clone());
+
+        assert_eq!(polygon.interiors_remove(1), holes[1]);
+        assert_eq!(polygon.exterior(), &exterior);
+        assert_eq!(polygon.interiors(), &[holes[0].clone(), holes[2].clone()]);
+
+        // Reordered in place, and pushed back closed.
+        polygon.interiors_mut(|interiors| interiors.reverse());
+        polygon.interiors_push(holes[1].0[..4].to_vec());
+        assert_eq!(
+            polygon.interiors(),
+            &[holes[2].clone(), holes[0].clone(), holes[1].clone()]
+        );
+        assert_eq!(polygon.exterior(), &exterior);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{coord, polygon, CoordFloat, CoordNum, Coordinate, Line, Polygon};
+use alloc::vec::Vec;
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -341,6 +342,148 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// Split a rectangle into four quadrants of equal size, by
+    /// [`split_x`](Rect::split_x) and [`split_y`](Rect::split_y).
+    ///
+    /// The quadrants are ordered by rows from the minimum y: the lower
+    /// left, the lower right, the upper left, and the upper right one.
+    /// The quadrants of a rectangle of zero width or height are
+    /// degenerate in the same direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 2. });
+    /// let [lower_left, _, _, upper_right] = rect.subdivide();
+    ///
+    /// assert_eq!(lower_left, Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 1. }));
+    /// assert_eq!(upper_right, Rect::new(coord! { x: 2., y: 1. }, coord! { x: 4., y: 2. }));
+    /// ```
+    pub fn subdivide(self) -> [Rect<T>; 4] {
+        let [lower, upper] = self.split_y();
+        let [lower_left, lower_right] = lower.split_x();
+        let [upper_left, upper_right] = upper.split_x();
+        [lower_left, lower_right, upper_left, upper_right]
+    }
+
+    /// Split a rectangle into a regular grid of `nx` columns and `ny`
+    /// rows of cells.
+    ///
+    /// The cells are ordered by rows from the minimum y, and by columns
+    /// from the minimum x within a row: the cell of column `i` and row
+    /// `j` is at index `i + j * nx`. Adjacent cells share their edges
+    /// exactly, and the outer edges of the grid are those of `self`.
+    ///
+    /// There are no cells if `nx` or `ny` is zero. The cells of a
+    /// rectangle of zero width or height are degenerate in the same
+    /// direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 2. });
+    /// let cells = rect.split_into_grid(3, 2);
+    ///
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[0], Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }));
+    /// assert_eq!(cells[4], Rect::new(coord! { x: 1., y: 1. }, coord! { x: 2., y: 2. }));
+    /// ```
+    pub fn split_into_grid(self, nx: usize, ny: usize) -> Vec<Rect<T>> {
+        // The `i`th of `n` divisions between `min` and `max`, exactly
+        // `max` for the last one.
+        let division = |min: T, max: T, i: usize, n: usize| {
+            if i == n {
+                max
+            } else {
+                min + (max - min) * T::from(i).unwrap() / T::from(n).unwrap()
+            }
+        };
+        let xs: Vec<_> = (0..=nx)
+            .map(|i| division(self.min.x, self.max.x, i, nx))
+            .collect();
+        let ys: Vec<_> = (0..=ny)
+            .map(|j| division(self.min.y, self.max.y, j, ny))
+            .collect();
+        ys.windows(2)
+            .flat_map(|y| {
+                xs.windows(2).map(move |x| Rect {
+                    min: coord! { x: x[0], y: y[0] },
+                    max: coord! { x: x[1], y: y[1] },
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the intersection of two `Rect`s, or `None` if they are
+    /// disjoint.
+    ///
+    /// The `Rect`s are closed, so the intersection of two `Rect`s that
+    /// only touch along an edge, or at a corner, is a degenerate `Rect`
+    /// of zero width or height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let a = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    /// let b = Rect::new(coord! { x: 2., y: 1. }, coord! { x: 6., y: 3. });
+    /// assert_eq!(
+    ///     a.intersection(&b),
+    ///     Some(Rect::new(coord! { x: 2., y: 1. }, coord! { x: 4., y: 3. })),
+    /// );
+    ///
+    /// let c = Rect::new(coord! { x: 5., y: 5. }, coord! { x: 6., y: 6. });
+    /// assert_eq!(a.intersection(&c), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let min = coord! {
+            x: partial_max(self.min.x, other.min.x),
+            y: partial_max(self.min.y, other.min.y),
+        };
+        let max = coord! {
+            x: partial_min(self.max.x, other.max.x),
+            y: partial_min(self.max.y, other.max.y),
+        };
+        let rect = Rect { min, max };
+        if rect.has_valid_bounds() {
+            Some(rect)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest `Rect` containing both `Rect`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let a = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    /// let b = Rect::new(coord! { x: 5., y: 1. }, coord! { x: 6., y: 3. });
+    /// assert_eq!(
+    ///     a.union(&b),
+    ///     Rect::new(coord! { x: 0., y: 0. }, coord! { x: 6., y: 4. }),
+    /// );
+    /// ```
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        Rect {
+            min: coord! {
+                x: partial_min(self.min.x, other.min.x),
+                y: partial_min(self.min.y, other.min.y),
+            },
+            max: coord! {
+                x: partial_max(self.max.x, other.max.x),
+                y: partial_max(self.max.y, other.max.y),
+            },
+        }
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!("{}", RECT_INVALID_BOUNDS_ERROR);
@@ -352,6 +495,22 @@ impl<T: CoordNum> Rect<T> {
     }
 }
 
+fn partial_min<T: CoordNum>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn partial_max<T: CoordNum>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
 impl<T: CoordFloat> Rect<T> {
     /// Returns the center `Coordinate` of the `Rect`.
     ///
@@ -468,11 +627,12 @@ where
 pub struct InvalidRectCoordinatesError;
 
 #[allow(deprecated)]
+#[cfg(feature = "std")]
 impl std::error::Error for InvalidRectCoordinatesError {}
 
 #[allow(deprecated)]
-impl std::fmt::Display for InvalidRectCoordinatesError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for InvalidRectCoordinatesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", RECT_INVALID_BOUNDS_ERROR)
     }
 }
@@ -509,6 +669,99 @@ mod test {
         assert_relative_eq!(rect.height(), 10.);
     }
 
+    #[test]
+    fn rect_intersection() {
+        let rect = Rect::new((0, 0), (10, 10));
+
+        // Overlapping.
+        assert_eq!(
+            rect.intersection(&Rect::new((5, -5), (15, 5))),
+            Some(Rect::new((5, 0), (10, 5)))
+        );
+        // Contained.
+        let inner = Rect::new((2, 2), (3, 3));
+        assert_eq!(rect.intersection(&inner), Some(inner));
+        assert_eq!(inner.intersection(&rect), Some(inner));
+        // Touching along an edge, and at a corner.
+        assert_eq!(
+            rect.intersection(&Rect::new((10, 2), (20, 8))),
+            Some(Rect::new((10, 2), (10, 8)))
+        );
+        assert_eq!(
+            rect.intersection(&Rect::new((10, 10), (20, 20))),
+            Some(Rect::new((10, 10), (10, 10)))
+        );
+        // Disjoint, and overlapping along one axis only.
+        assert_eq!(rect.intersection(&Rect::new((11, 11), (20, 20))), None);
+        assert_eq!(rect.intersection(&Rect::new((2, 11), (8, 20))), None);
+        // Empty.
+        let point = Rect::new((5, 5), (5, 5));
+        assert_eq!(rect.intersection(&point), Some(point));
+    }
+
+    #[test]
+    fn rect_union() {
+        let rect = Rect::new((0., 0.), (10., 10.));
+        assert_eq!(
+            rect.union(&Rect::new((5., -5.), (15., 5.))),
+            Rect::new((0., -5.), (15., 10.))
+        );
+        assert_eq!(
+            rect.union(&Rect::new((20., 20.), (30., 30.))),
+            Rect::new((0., 0.), (30., 30.))
+        );
+        assert_eq!(rect.union(&Rect::new((2., 2.), (3., 3.))), rect);
+    }
+
+    #[test]
+    fn rect_subdivide() {
+        let quadrants = Rect::new((0., 0.), (1., 1.)).subdivide();
+        assert_eq!(
+            quadrants,
+            [
+                Rect::new((0., 0.), (0.5, 0.5)),
+                Rect::new((0.5, 0.), (1., 0.5)),
+                Rect::new((0., 0.5), (0.5, 1.)),
+                Rect::new((0.5, 0.5), (1., 1.)),
+            ]
+        );
+        // A degenerate rectangle has degenerate quadrants.
+        let line = Rect::new((0., 0.), (2., 0.));
+        assert_eq!(line.subdivide()[1], Rect::new((1., 0.), (2., 0.)));
+        assert_eq!(line.subdivide()[3], Rect::new((1., 0.), (2., 0.)));
+    }
+
+    #[test]
+    fn rect_split_into_grid() {
+        let rect = Rect::new((0.1, 0.2), (1.3, 0.9));
+        let cells = rect.split_into_grid(7, 3);
+        assert_eq!(cells.len(), 21);
+        assert_eq!(cells[0].min(), rect.min());
+        assert_eq!(cells[20].max(), rect.max());
+        for j in 0..3 {
+            for i in 0..7 {
+                let cell = cells[i + 7 * j];
+                assert_relative_eq!(cell.width(), 1.2 / 7., epsilon = 1e-12);
+                assert_relative_eq!(cell.height(), 0.7 / 3., epsilon = 1e-12);
+                // Adjacent cells share their edges exactly.
+                if i > 0 {
+                    assert_eq!(cells[i - 1 + 7 * j].max().x, cell.min().x);
+                }
+                if j > 0 {
+                    assert_eq!(cells[i + 7 * (j - 1)].max().y, cell.min().y);
+                }
+            }
+        }
+        assert_eq!(rect.split_into_grid(1, 1), vec![rect]);
+        assert!(rect.split_into_grid(0, 3).is_empty());
+
+        // Integer coordinates, and degenerate rectangles.
+        let cells = Rect::new((0, 0), (10, 0)).split_into_grid(3, 2);
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[1], Rect::new((3, 0), (6, 0)));
+        assert_eq!(cells[5], Rect::new((6, 0), (10, 0)));
+    }
+
     #[test]
     fn rect_center() {
         assert_relative_eq!(
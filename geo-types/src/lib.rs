@@ -1,4 +1,5 @@
 #![warn(missing_debug_implementations)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
 //! The `geo-types` library defines geometric types for the [GeoRust] ecosystem.
 //!
@@ -60,6 +61,8 @@
 //!
 //! The following optional [Cargo features] are available:
 //!
+//! - `std`: Enabled by default. Without it, the crate only depends on `core` and `alloc`, and
+//!   the floating point functions are provided by [libm]
 //! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
 //! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
 //! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
@@ -72,12 +75,14 @@
 //! [GeoRust]: https://georust.org
 //! [GEOS]: https://trac.osgeo.org/geos
 //! [JTS]: https://github.com/locationtech/jts
+//! [libm]: https://github.com/rust-lang/libm
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
 //! [rstar]: https://github.com/Stoeoef/rstar
 //! [Serde]: https://serde.rs/
+extern crate alloc;
 extern crate num_traits;
+use core::fmt::Debug;
 use num_traits::{Float, Num, NumCast};
-use std::fmt::Debug;
 
 #[cfg(feature = "serde")]
 #[macro_use]
@@ -124,6 +129,13 @@ pub use error::Error;
 #[macro_use]
 mod macros;
 
+#[doc(hidden)]
+pub mod _alloc {
+    //! Re-exports of `alloc` for the macros, which must also work in
+    //! `no_std` crates without `extern crate alloc`.
+    pub use ::alloc::{boxed, vec};
+}
+
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
@@ -123,7 +123,7 @@ macro_rules! coord {
 /// [`LineString`]: ./line_string/struct.LineString.html
 #[macro_export]
 macro_rules! line_string {
-    () => { $crate::LineString::new(vec![]) };
+    () => { $crate::LineString::new($crate::_alloc::vec![]) };
     (
         $(( $($tag:tt : $val:expr),* $(,)? )),*
         $(,)?
@@ -140,7 +140,7 @@ macro_rules! line_string {
     ) => {
         $crate::LineString::new(
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [$($coord), *]
                 )
             )
@@ -216,7 +216,7 @@ macro_rules! line_string {
 /// [`Polygon`]: ./struct.Polygon.html
 #[macro_export]
 macro_rules! polygon {
-    () => { $crate::Polygon::new(line_string![], vec![]) };
+    () => { $crate::Polygon::new(line_string![], $crate::_alloc::vec![]) };
     (
         exterior: [
             $(( $($exterior_tag:tt : $exterior_val:expr),* $(,)? )),*
@@ -263,7 +263,7 @@ macro_rules! polygon {
                 $($exterior_coord), *
             ],
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [
                         $(
                             $crate::line_string![$($interior_coord),*]
@@ -287,7 +287,7 @@ macro_rules! polygon {
     ) => {
         $crate::Polygon::new(
             $crate::line_string![$($coord,)*],
-            vec![],
+            $crate::_alloc::vec![],
         )
     };
 }
@@ -4,7 +4,7 @@ extern crate criterion;
 extern crate geo;
 
 use geo::contains::Contains;
-use geo::{polygon, Line, Point, Polygon};
+use geo::{polygon, BoundingRect, ContainsIndex, Line, Point, Polygon, Rect};
 
 use criterion::Criterion;
 
@@ -74,6 +74,48 @@ fn criterion_benchmark(c: &mut Criterion) {
             );
         });
     });
+
+    {
+        let polygon = Polygon::<f64>::new(geo_test_fixtures::louisiana(), vec![]);
+        let bounds = polygon.bounding_rect().unwrap();
+        let (nx, ny) = (30, 30);
+        let (dx, dy) = (bounds.width() / nx as f64, bounds.height() / ny as f64);
+        // A grid of small squares over, and beyond Louisiana.
+        let candidates: Vec<_> = (-5..nx + 5)
+            .flat_map(|i| (-5..ny + 5).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let x = bounds.min().x + i as f64 * dx + dx / 4.;
+                let y = bounds.min().y + j as f64 * dy + dy / 4.;
+                Rect::new((x, y), (x + dx / 2., y + dy / 2.)).to_polygon()
+            })
+            .collect();
+
+        let mut group = c.benchmark_group("complex polygon containing grid of polygons");
+        group.sample_size(10);
+        group.bench_function("brute force", |bencher| {
+            bencher.iter(|| {
+                criterion::black_box(&candidates)
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| criterion::black_box(&polygon).contains(*p))
+                    .map(|(idx, _)| idx)
+                    .collect::<Vec<_>>()
+            });
+        });
+        group.bench_function("index", |bencher| {
+            let index = ContainsIndex::new(&candidates);
+            bencher.iter(|| index.containing_indices(criterion::black_box(&polygon)));
+        });
+        group.bench_function("index, including build", |bencher| {
+            bencher.iter(|| {
+                geo::containing_indices(
+                    criterion::black_box(&polygon),
+                    criterion::black_box(&candidates),
+                )
+            });
+        });
+        group.finish();
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
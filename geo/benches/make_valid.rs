@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::{BatchSize, Criterion};
+use geo::{Coordinate, LineString, MakeValid, MultiPolygon, Polygon};
+
+/// A star polygon, joining every third of `n` points on a circle, so
+/// that each edge crosses four others.
+fn star(n: usize) -> MultiPolygon<f64> {
+    let coords: Vec<_> = (0..n)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * (3 * i) as f64 / n as f64;
+            Coordinate {
+                x: angle.cos(),
+                y: angle.sin(),
+            }
+        })
+        .collect();
+    Polygon::new(LineString::new(coords), vec![]).into()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = star(10_000);
+
+    c.bench_function("make_valid star", |bencher| {
+        bencher.iter_batched(
+            || input.clone(),
+            |input| {
+                let valid = criterion::black_box(&input).make_valid();
+                drop(input);
+                valid
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    c.bench_function("into_make_valid star", |bencher| {
+        bencher.iter_batched(
+            || input.clone(),
+            |input| criterion::black_box(input).into_make_valid(),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+
+criterion_main!(benches);
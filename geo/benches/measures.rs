@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::{Area, BoundingRect, Centroid, Measure, Point, Polygon, Rect};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+    let plots = geo_test_fixtures::nl_plots::<f64>();
+
+    c.bench_function("measures: norway, separately", |bencher| {
+        bencher.iter(|| separately(criterion::black_box(&norway)));
+    });
+
+    c.bench_function("measures: norway, single pass", |bencher| {
+        bencher.iter(|| criterion::black_box(&norway).measures());
+    });
+
+    c.bench_function("measures: nl plots, separately", |bencher| {
+        bencher.iter(|| separately(criterion::black_box(&plots)));
+    });
+
+    c.bench_function("measures: nl plots, single pass", |bencher| {
+        bencher.iter(|| criterion::black_box(&plots).measures());
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn separately<G>(geom: &G) -> (f64, Option<Point<f64>>, Option<Rect<f64>>)
+where
+    G: Area<f64> + Centroid<Output = Option<Point<f64>>>,
+    G: BoundingRect<f64, Output = Option<Rect<f64>>>,
+{
+    (geom.signed_area(), geom.centroid(), geom.bounding_rect())
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::Criterion;
+use geo::bool_ops::{Op, OpType};
+use geo::{Coordinate, CoordsIter, LineString, MultiPolygon, Polygon};
+
+/// The system allocator, counting the reallocations.
+struct CountingAlloc;
+
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// A grid of `n` by `n` squares, shifted by `offset`.
+fn grid(n: usize, offset: f64) -> MultiPolygon<f64> {
+    let square = |x: f64, y: f64| {
+        let coords = [(x, y), (x + 2., y), (x + 2., y + 2.), (x, y + 2.)];
+        Polygon::new(
+            LineString::from(
+                coords
+                    .iter()
+                    .map(|&(x, y)| Coordinate { x, y })
+                    .collect::<Vec<_>>(),
+            ),
+            vec![],
+        )
+    };
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| square(3. * i as f64 + offset, 3. * j as f64 + offset)))
+        .collect()
+}
+
+/// Sweep the union of `a` and `b`, added one after the other, with a
+/// capacity hint of `segment_count`, if any.
+fn union(a: &MultiPolygon<f64>, b: &MultiPolygon<f64>, segment_count: Option<usize>) -> usize {
+    let mut op = Op::new(OpType::Union, 0);
+    if let Some(segment_count) = segment_count {
+        op = op.with_capacity(segment_count);
+    }
+    op.add_multi_polygon(a, true);
+    op.add_multi_polygon(b, false);
+    op.sweep().len()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    // Two grids of 2500 squares, each square crossing one of the other.
+    let a = grid(50, 0.);
+    let b = grid(50, 1.);
+    let edges = a.coords_count() + b.coords_count();
+    // Each crossing splits two edges.
+    let hint = edges + 2 * 2 * 2500;
+
+    for (name, segment_count) in [("without hint", None), ("with hint", Some(hint))] {
+        let before = REALLOCS.load(Ordering::Relaxed);
+        union(&a, &b, segment_count);
+        let reallocs = REALLOCS.load(Ordering::Relaxed) - before;
+        println!("Op union {}: {} reallocations", name, reallocs);
+    }
+
+    c.bench_function("Op union without hint", |bencher| {
+        bencher.iter(|| union(criterion::black_box(&a), criterion::black_box(&b), None));
+    });
+    c.bench_function("Op union with hint", |bencher| {
+        bencher.iter(|| {
+            union(
+                criterion::black_box(&a),
+                criterion::black_box(&b),
+                Some(hint),
+            )
+        });
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::bool_ops::unary_union;
+use geo::{LineString, Polygon};
+
+/// A square of side `size`, with its lower-left corner at `(x, y)`.
+fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+    Polygon::new(
+        LineString::from(vec![
+            (x, y),
+            (x + size, y),
+            (x + size, y + size),
+            (x, y + size),
+        ]),
+        vec![],
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    // A grid of 10k disjoint squares, with a few clusters of overlapping
+    // ones.
+    let mut polygons: Vec<_> = (0..100)
+        .flat_map(|i| (0..100).map(move |j| square(3. * i as f64, 3. * j as f64, 1.)))
+        .collect();
+    for cluster in 0..10 {
+        let origin = 30. * cluster as f64;
+        polygons.extend((0..20).map(|k| square(origin + 0.25 * k as f64, origin, 2.)));
+    }
+
+    c.bench_function("unary_union mostly disjoint", |bencher| {
+        bencher.iter(|| unary_union(criterion::black_box(&polygons)));
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+use crate::map_coords::MapCoords;
+use crate::{CoordFloat, Coordinate};
+
+/// Scale and rotate a geometry relative to a chosen origin, rather than
+/// `(0, 0)`.
+///
+/// The origin is the fixed point of the transforms: it is moved to
+/// `(0, 0)`, the geometry is transformed, and then moved back. As the
+/// transforms map each coordinate independently, they compose with the
+/// other coordinate maps, such as [`Translate`](crate::Translate).
+pub trait AnchoredTransform<T: CoordFloat> {
+    /// Scale a geometry by `factor` about `origin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, line_string, AnchoredTransform};
+    ///
+    /// let ls = line_string![(x: 1., y: 1.), (x: 3., y: 2.)];
+    /// let scaled = ls.scale_about(2., coord! { x: 1., y: 1. });
+    ///
+    /// assert_eq!(scaled, line_string![(x: 1., y: 1.), (x: 5., y: 3.)]);
+    /// ```
+    fn scale_about(&self, factor: T, origin: Coordinate<T>) -> Self;
+
+    /// Rotate a geometry by an angle about `origin`.
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are
+    /// clockwise rotations.
+    ///
+    /// # Units
+    ///
+    /// - `radians`: radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{coord, line_string, AnchoredTransform};
+    /// use std::f64::consts::PI;
+    ///
+    /// let ls = line_string![(x: 1., y: 1.), (x: 3., y: 1.)];
+    /// let rotated = ls.rotate_about(PI, coord! { x: 1., y: 1. });
+    ///
+    /// assert_relative_eq!(rotated, line_string![(x: 1., y: 1.), (x: -1., y: 1.)]);
+    /// ```
+    fn rotate_about(&self, radians: T, origin: Coordinate<T>) -> Self;
+}
+
+impl<T, G> AnchoredTransform<T> for G
+where
+    T: CoordFloat,
+    G: MapCoords<T, T, Output = G>,
+{
+    fn scale_about(&self, factor: T, origin: Coordinate<T>) -> Self {
+        self.map_coords(|c| origin + (c - origin) * factor)
+    }
+
+    fn rotate_about(&self, radians: T, origin: Coordinate<T>) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        self.map_coords(|c| {
+            let Coordinate { x, y } = c - origin;
+            origin
+                + Coordinate {
+                    x: x * cos - y * sin,
+                    y: x * sin + y * cos,
+                }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon, Area, Centroid, MultiPolygon, RotatePoint, Translate};
+
+    #[test]
+    fn scale_about_centroid() {
+        let poly = polygon![(x: 1., y: 1.), (x: 5., y: 1.), (x: 4., y: 3.), (x: 1., y: 4.)];
+        let centroid = poly.centroid().unwrap();
+
+        let scaled = poly.scale_about(3., centroid.into());
+        assert_relative_eq!(scaled.centroid().unwrap(), centroid, epsilon = 1e-12);
+        assert_relative_eq!(scaled.unsigned_area(), 9. * poly.unsigned_area());
+
+        let multi = MultiPolygon::new(vec![poly.clone(), poly.translate(10., 0.)]);
+        let centroid = multi.centroid().unwrap();
+        let scaled = multi.scale_about(0.5, centroid.into());
+        assert_relative_eq!(scaled.centroid().unwrap(), centroid, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn rotate_about_matches_rotate() {
+        let poly = polygon![(x: 1., y: 1.), (x: 5., y: 1.), (x: 4., y: 3.), (x: 1., y: 4.)];
+        let origin = coord! { x: -2., y: 3. };
+        assert_relative_eq!(
+            poly.rotate_about(30f64.to_radians(), origin),
+            poly.rotate_around_point(30., origin.into()),
+            epsilon = 1e-12
+        );
+        // The transforms compose.
+        let there_and_back = poly
+            .scale_about(2., origin)
+            .rotate_about(1., origin)
+            .scale_about(0.5, origin)
+            .rotate_about(-1., origin);
+        assert_relative_eq!(there_and_back, poly, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::{CoordFloat, Coordinate, GeoFloat, Point, Triangle};
+
+/// The barycentric coordinates of a point relative to a triangle.
+///
+/// The weights `(w0, w1, w2)` of the vertices of the triangle sum to
+/// `1`, and the weighted sum of the vertices is the point. A point is
+/// in the interior of the triangle if all three weights are positive,
+/// on its boundary if one is zero, and outside of it if one is
+/// negative; use [`Contains`](crate::Contains) for an exact test. The
+/// weights interpolate values given at the vertices, e.g. elevations,
+/// linearly over the triangle.
+///
+/// Returns `None` for a degenerate triangle, whose vertices are
+/// collinear by the robust orientation predicate.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Barycentric, Triangle};
+///
+/// let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+/// let (w0, w1, w2) = triangle.barycentric(&point!(x: 1., y: 2.)).unwrap();
+/// assert_eq!((w0, w1, w2), (0.25, 0.25, 0.5));
+///
+/// // Interpolate the elevations of the vertices.
+/// let elevation = w0 * 10. + w1 * 20. + w2 * 30.;
+/// assert_eq!(elevation, 22.5);
+///
+/// let flat = Triangle::from([(0., 0.), (1., 1.), (2., 2.)]);
+/// assert!(flat.barycentric(&point!(x: 1., y: 2.)).is_none());
+/// ```
+pub trait Barycentric<T: CoordFloat> {
+    fn barycentric(&self, point: &Point<T>) -> Option<(T, T, T)>;
+}
+
+impl<T: GeoFloat> Barycentric<T> for Triangle<T> {
+    fn barycentric(&self, point: &Point<T>) -> Option<(T, T, T)> {
+        if T::Ker::orient2d(self.0, self.1, self.2) == Orientation::Collinear {
+            return None;
+        }
+        // Each weight is the signed area of the triangle formed by the
+        // point and the opposite edge, relative to the whole.
+        let area = cross(self.0, self.1, self.2);
+        let p = point.0;
+        Some((
+            cross(p, self.1, self.2) / area,
+            cross(self.0, p, self.2) / area,
+            cross(self.0, self.1, p) / area,
+        ))
+    }
+}
+
+/// Twice the signed area of the triangle `abc`.
+fn cross<T: CoordFloat>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> T {
+    let (u, v) = (b - a, c - a);
+    u.x * v.y - u.y * v.x
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Contains};
+
+    #[test]
+    fn weights() {
+        let triangle = Triangle::from([(1., 1.), (5., 2.), (2., 6.)]);
+        let p = point!(x: 2.5, y: 3.);
+        let (w0, w1, w2) = triangle.barycentric(&p).unwrap();
+        assert_relative_eq!(w0 + w1 + w2, 1.);
+        assert!(w0 > 0. && w1 > 0. && w2 > 0.);
+        assert!(triangle.contains(&p));
+        // The weighted vertices are the point.
+        let x = w0 * triangle.0.x + w1 * triangle.1.x + w2 * triangle.2.x;
+        let y = w0 * triangle.0.y + w1 * triangle.1.y + w2 * triangle.2.y;
+        assert_relative_eq!(x, 2.5);
+        assert_relative_eq!(y, 3.);
+
+        // The vertices, and a point outside, beyond the first vertex.
+        assert_eq!(triangle.barycentric(&triangle.1.into()), Some((0., 1., 0.)));
+        let (w0, w1, w2) = triangle.barycentric(&point!(x: 0., y: 0.)).unwrap();
+        assert!(w0 > 1. && w1 < 0. && w2 < 0.);
+        assert_relative_eq!(w0 + w1 + w2, 1.);
+
+        // Clockwise, the weights are the same.
+        let reversed = Triangle(triangle.2, triangle.1, triangle.0);
+        let (v2, v1, v0) = reversed.barycentric(&p).unwrap();
+        let (w0, w1, w2) = triangle.barycentric(&p).unwrap();
+        assert_relative_eq!(v0, w0);
+        assert_relative_eq!(v1, w1);
+        assert_relative_eq!(v2, w2);
+    }
+
+    #[test]
+    fn degenerate() {
+        let origin = point!(x: 0., y: 0.);
+        let point = Triangle::from([(0., 0.), (0., 0.), (0., 0.)]);
+        assert!(point.barycentric(&origin).is_none());
+        let line = Triangle::from([(0., 0.), (1., 1.), (3., 3.)]);
+        assert!(line.barycentric(&origin).is_none());
+    }
+}
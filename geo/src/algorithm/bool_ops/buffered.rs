@@ -0,0 +1,322 @@
+use super::{
+    assemble,
+    depth::{add_polygon, sweep, Edge},
+    KeepOrDrop, Operand,
+};
+use crate::{
+    ConvexHull, Coordinate, CoordsIter, GeoFloat as Float, LineString, MultiPolygon, Polygon,
+};
+
+/// The number of segments of the polygons approximating the disks
+/// around the vertices.
+const DISK_SEGMENTS: usize = 32;
+
+/// The part of `a` farther than `distance` from `b`, i.e. the
+/// difference of `a` and the buffer of `b` by `distance`.
+///
+/// The buffer is not computed on its own: the polygons of `b`, with a
+/// rectangle along each of its edges and a disk around each of its
+/// vertices, are fed into the same sweep as `a`, which keeps the region
+/// covered by `a` and none of them. The disks are approximated by
+/// polygons of 32 segments, inscribed in the circles.
+///
+/// # Panics
+///
+/// If `distance` is negative or `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::difference_buffered;
+/// use geo::{polygon, Area, MultiPolygon};
+///
+/// let a: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+/// let b: MultiPolygon<f64> =
+///     polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)].into();
+///
+/// assert_eq!(difference_buffered(&a, &b, 0.).unsigned_area(), 96.);
+///
+/// // Less the rounded square of width 4 around `b`.
+/// let far = difference_buffered(&a, &b, 1.);
+/// assert!(84. < far.unsigned_area() && far.unsigned_area() < 85.);
+/// ```
+pub fn difference_buffered<T: Float>(
+    a: &MultiPolygon<T>,
+    b: &MultiPolygon<T>,
+    distance: T,
+) -> MultiPolygon<T> {
+    assert!(
+        distance >= T::zero(),
+        "buffer distance must be non-negative"
+    );
+    let mut edges = vec![];
+    for poly in a {
+        add_polygon(&mut edges, poly, Operand::Subject);
+    }
+    for poly in b {
+        add_polygon(&mut edges, poly, Operand::Clip);
+        if distance > T::zero() {
+            add_buffer_pieces(&mut edges, poly.exterior(), distance);
+            for hole in poly.interiors() {
+                add_buffer_pieces(&mut edges, hole, distance);
+            }
+        }
+    }
+    let rings = sweep(&edges, |depth| depth[0] > 0 && depth[1] <= 0);
+    assemble(rings, KeepOrDrop::Keep).into()
+}
+
+/// Add the rectangles along the edges, and the disks around the
+/// vertices of `ring` to the second operand.
+fn add_buffer_pieces<T: Float>(edges: &mut Vec<Edge<T>>, ring: &LineString<T>, distance: T) {
+    let mut add_piece = |coords: Vec<Coordinate<T>>| {
+        let mut ring = LineString::new(coords);
+        ring.close();
+        add_polygon(edges, &Polygon::new(ring, vec![]), Operand::Clip);
+    };
+    for line in ring.lines() {
+        let delta = line.delta();
+        let length = delta.x.hypot(delta.y);
+        if length == T::zero() {
+            continue;
+        }
+        let normal = Coordinate {
+            x: -delta.y,
+            y: delta.x,
+        } * (distance / length);
+        add_piece(vec![
+            line.start - normal,
+            line.end - normal,
+            line.end + normal,
+            line.start + normal,
+        ]);
+    }
+
+    // A closed ring repeats its first vertex at the end.
+    for center in ring.0.iter().skip(1) {
+        add_piece(disk(*center, distance).collect());
+    }
+}
+
+/// The vertices of the polygon approximating the disk of `radius` around
+/// `center`.
+///
+/// The vertices are computed in the first quadrant, and mirrored to the
+/// others: the vertices on the axes are then exact, and coincide with
+/// the corners of the rectangles along axis-aligned edges, instead of
+/// being off by a rounding error, which the sweep does not cope with.
+fn disk<T: Float>(center: Coordinate<T>, radius: T) -> impl Iterator<Item = Coordinate<T>> {
+    let quarter = DISK_SEGMENTS / 4;
+    let step = T::from(std::f64::consts::FRAC_PI_2 / quarter as f64).unwrap();
+    (0..DISK_SEGMENTS).map(move |i| {
+        let (sin, cos) = (step * T::from(i % quarter).unwrap()).sin_cos();
+        let (x, y) = match i / quarter {
+            0 => (cos, sin),
+            1 => (-sin, cos),
+            2 => (-cos, -sin),
+            _ => (sin, -cos),
+        };
+        center + Coordinate { x, y } * radius
+    })
+}
+
+/// The joins between the consecutive edges of a [`BufferVariable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BufferJoin<T: Float> {
+    /// Round joins, and round ends of a `LineString`.
+    Round,
+    /// The offsets of consecutive edges are joined by a straight segment.
+    Bevel,
+    /// The offsets of consecutive edges are extended to their
+    /// intersection, or bevelled if it is farther from the vertex than
+    /// the given multiple of the distance there.
+    Mitre(T),
+}
+
+/// Buffer a geometry by a distance that varies along it.
+///
+/// The distances are given per vertex, in the order of
+/// [`coords_iter`](crate::CoordsIter::coords_iter), and are interpolated
+/// linearly along the edges. The buffer is the union of the geometry,
+/// if it is areal, of the pieces along each edge, and of the pieces
+/// joining them at the vertices, as given by `join`. The union is
+/// computed by a single sweep, which also removes the
+/// self-intersections of the raw outline.
+///
+/// With [`BufferJoin::Round`], the piece along an edge is the convex
+/// hull of the disks at its ends, and these are approximated by
+/// polygons of 32 segments, inscribed in the circles. Otherwise, it is
+/// the quadrilateral between the offsets of the edge on either side,
+/// and the ends of a `LineString` are cut square to its end edges.
+///
+/// # Panics
+///
+/// If the number of distances is not the number of vertices, or if any
+/// of them is negative or `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::BufferJoin;
+/// use geo::{line_string, Area, BufferVariable};
+///
+/// // A corridor tapering from a width of 2 to a width of 6.
+/// let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let corridor = ls.buffer_variable(&[1., 3.], BufferJoin::Round);
+/// assert_eq!(corridor.0.len(), 1);
+/// assert!(corridor.unsigned_area() > 10. * (2. + 6.) / 2.);
+///
+/// // Without the round ends.
+/// let corridor = ls.buffer_variable(&[1., 3.], BufferJoin::Bevel);
+/// assert_eq!(corridor.unsigned_area(), 10. * (2. + 6.) / 2.);
+/// ```
+pub trait BufferVariable<T: Float> {
+    fn buffer_variable(&self, distances: &[T], join: BufferJoin<T>) -> MultiPolygon<T>;
+}
+
+impl<T: Float> BufferVariable<T> for LineString<T> {
+    fn buffer_variable(&self, distances: &[T], join: BufferJoin<T>) -> MultiPolygon<T> {
+        check_distances(self, distances);
+        let mut edges = vec![];
+        add_variable_pieces(&mut edges, self, distances, join);
+        union(&edges)
+    }
+}
+
+impl<T: Float> BufferVariable<T> for Polygon<T> {
+    fn buffer_variable(&self, distances: &[T], join: BufferJoin<T>) -> MultiPolygon<T> {
+        check_distances(self, distances);
+        let mut edges = vec![];
+        add_polygon_pieces(&mut edges, self, distances, join);
+        union(&edges)
+    }
+}
+
+impl<T: Float> BufferVariable<T> for MultiPolygon<T> {
+    fn buffer_variable(&self, distances: &[T], join: BufferJoin<T>) -> MultiPolygon<T> {
+        check_distances(self, distances);
+        let mut edges = vec![];
+        let mut start = 0;
+        for poly in self {
+            let end = start + poly.coords_count();
+            add_polygon_pieces(&mut edges, poly, &distances[start..end], join);
+            start = end;
+        }
+        union(&edges)
+    }
+}
+
+fn check_distances<'a, T: Float, G: CoordsIter<'a, Scalar = T>>(geom: &'a G, distances: &[T]) {
+    assert_eq!(
+        distances.len(),
+        geom.coords_count(),
+        "one buffer distance is needed per vertex"
+    );
+    assert!(
+        distances.iter().all(|d| *d >= T::zero()),
+        "buffer distances must be non-negative"
+    );
+}
+
+fn add_polygon_pieces<T: Float>(
+    edges: &mut Vec<Edge<T>>,
+    poly: &Polygon<T>,
+    distances: &[T],
+    join: BufferJoin<T>,
+) {
+    add_polygon(edges, poly, Operand::Subject);
+    let mut start = 0;
+    for ring in std::iter::once(poly.exterior()).chain(poly.interiors()) {
+        let end = start + ring.0.len();
+        add_variable_pieces(edges, ring, &distances[start..end], join);
+        start = end;
+    }
+}
+
+/// Add the pieces along each edge of `ls`, and joining them.
+fn add_variable_pieces<T: Float>(
+    edges: &mut Vec<Edge<T>>,
+    ls: &LineString<T>,
+    distances: &[T],
+    join: BufferJoin<T>,
+) {
+    if join == BufferJoin::Round {
+        // The convex hulls of the disks at the ends of each edge.
+        for (w, d) in ls.0.windows(2).zip(distances.windows(2)) {
+            let coords: Vec<_> = disk(w[0], d[0]).chain(disk(w[1], d[1])).collect();
+            let hull = LineString::new(coords).convex_hull();
+            add_polygon(edges, &hull, Operand::Subject);
+        }
+        return;
+    }
+
+    let mut add_piece = |coords: Vec<Coordinate<T>>| {
+        let mut ring = LineString::new(coords);
+        ring.close();
+        add_polygon(edges, &Polygon::new(ring, vec![]), Operand::Subject);
+    };
+    // The non-degenerate edges, with their offsets to the left at each
+    // end, and the distance at their end.
+    let sides: Vec<_> = ls
+        .lines()
+        .zip(distances.windows(2))
+        .filter_map(|(line, d)| {
+            let delta = line.delta();
+            let length = delta.x.hypot(delta.y);
+            if length == T::zero() {
+                return None;
+            }
+            let normal = Coordinate {
+                x: -delta.y,
+                y: delta.x,
+            } / length;
+            Some((line, normal * d[0], normal * d[1], d[1]))
+        })
+        .collect();
+    for &(line, n0, n1, _) in &sides {
+        add_piece(vec![
+            line.start - n0,
+            line.end - n1,
+            line.end + n1,
+            line.start + n0,
+        ]);
+    }
+
+    let cross = |a: Coordinate<T>, b: Coordinate<T>| a.x * b.y - a.y * b.x;
+    let closing = match (sides.first(), sides.last()) {
+        (Some(&first), Some(&last)) if ls.is_closed() && sides.len() > 1 => Some((last, first)),
+        _ => None,
+    };
+    let joined = sides.windows(2).map(|w| (w[0], w[1])).chain(closing);
+    for ((a, a0, a1, distance), (b, b0, b1, _)) in joined {
+        let turn = cross(a.delta(), b.delta());
+        if turn == T::zero() {
+            continue;
+        }
+        // The offsets on the outer side of the turn.
+        let side = if turn > T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        let (a0, a1, b0, b1) = (a0 * side, a1 * side, b0 * side, b1 * side);
+        let (from, to) = (a.end + a1, b.start + b0);
+        if let BufferJoin::Mitre(limit) = join {
+            // The intersection of the offsets of `a` and `b`.
+            let (r, s) = (a.delta() + a1 - a0, b.delta() + b1 - b0);
+            let t = cross(to - from, s) / cross(r, s);
+            let point = from + r * t;
+            let offset = point - a.end;
+            if t >= T::zero() && offset.x.hypot(offset.y) <= limit * distance {
+                add_piece(vec![a.end, from, point, to]);
+                continue;
+            }
+        }
+        add_piece(vec![a.end, from, to]);
+    }
+}
+
+fn union<T: Float>(edges: &[Edge<T>]) -> MultiPolygon<T> {
+    assemble(sweep(edges, |depth| depth[0] > 0), KeepOrDrop::Keep).into()
+}
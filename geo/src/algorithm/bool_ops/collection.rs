@@ -0,0 +1,293 @@
+use std::cmp::Ordering;
+
+use super::{unary_union, BooleanOps, OpType};
+use crate::{
+    coordinate_position::CoordPos, sweep::for_each_intersection, Coordinate, CoordinatePosition,
+    GeoFloat as Float, Geometry, GeometryCollection, Line, LineIntersection, LineString,
+    MultiPolygon, Point, Polygon,
+};
+
+/// The boolean operation `op` between two geometry collections, by the
+/// dimension of their members.
+///
+/// The areal members of each collection (polygons, rectangles and
+/// triangles) are first merged into their union, and the areal output is
+/// the operation on these unions, as by [`BooleanOps`]. The linear and
+/// point members are then clipped by the areal part of the other
+/// collection: a piece of a line of `a` is kept if `op` keeps the points
+/// that are in `a`, and either inside or outside of the areal part of
+/// `b`. The pieces and points covered by the areal output are dropped,
+/// as they are part of it. The boundaries of the areal parts count as
+/// inside them.
+///
+/// The lines of `a` and `b` are also split where they cross or overlap
+/// each other: the pieces shared by both collections are in both, and
+/// output once, and the points where they cross are output if `op` keeps
+/// them, but not the lines through them, as for an intersection. The
+/// points are not compared to the lines or points of the other
+/// collection.
+///
+/// The output holds the polygons, followed by the line strings, and the
+/// points, as by [`intersection_full`](BooleanOps::intersection_full).
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{collection_op, OpType};
+/// use geo::{line_string, polygon, Geometry, GeometryCollection};
+///
+/// let a = GeometryCollection::new_from(vec![
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into(),
+///     line_string![(x: 3., y: -1.), (x: 3., y: 5.)].into(),
+/// ]);
+/// let b = GeometryCollection::new_from(vec![
+///     polygon![(x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 1., y: 3.)].into(),
+/// ]);
+///
+/// let output = collection_op(&a, &b, OpType::Intersection);
+/// assert_eq!(output.0.len(), 2);
+/// assert_eq!(
+///     output.0[1],
+///     Geometry::LineString(line_string![(x: 3., y: 0.), (x: 3., y: 3.)])
+/// );
+/// ```
+pub fn collection_op<T: Float>(
+    a: &GeometryCollection<T>,
+    b: &GeometryCollection<T>,
+    op: OpType,
+) -> GeometryCollection<T> {
+    let a = Members::new(a);
+    let b = Members::new(b);
+    let area = a.area.boolean_op(&b.area, op);
+    let keep = |in_a: bool, in_b: bool| match op {
+        OpType::Intersection => in_a && in_b,
+        OpType::Union => in_a || in_b,
+        OpType::Difference => in_a && !in_b,
+        OpType::Xor => in_a != in_b,
+    };
+    let inside = |area: &MultiPolygon<T>, coord: Coordinate<T>| {
+        area.coordinate_position(&coord) != CoordPos::Outside
+    };
+    // Whether to keep a point of `a`, or of `b`.
+    let keep_a = |coord| keep(true, inside(&b.area, coord)) && !inside(&area, coord);
+    let keep_b = |coord| keep(inside(&a.area, coord), true) && !inside(&area, coord);
+    // The pieces of lines shared by `a` and `b` are kept, or not, with
+    // those of `a`.
+    let keep_piece = |of_a: bool, coord, shared: bool| match (of_a, shared) {
+        (true, true) => keep(true, true) && !inside(&area, coord),
+        (true, false) => keep_a(coord),
+        (false, true) => false,
+        (false, false) => keep_b(coord),
+    };
+
+    let mut output: Vec<Geometry<T>> = area.iter().cloned().map(Geometry::Polygon).collect();
+    let (lines, crossings) = clip_lines(&a.lines, &b.lines, &[&a.area, &b.area], keep_piece);
+    // The crossings of the lines of `a` and `b` are kept where the lines
+    // through them are not.
+    let mut vertices: Vec<_> = lines.iter().flat_map(|ls| ls.0.iter().copied()).collect();
+    vertices.sort_by(compare_coords);
+    let keep_crossing = |coord: &Coordinate<T>| {
+        keep(true, true)
+            && !keep(true, false)
+            && !keep(false, true)
+            && !inside(&a.area, *coord)
+            && !inside(&b.area, *coord)
+            && vertices
+                .binary_search_by(|v| compare_coords(v, coord))
+                .is_err()
+    };
+    output.extend(lines.into_iter().map(Geometry::LineString));
+    let points = a
+        .points
+        .iter()
+        .filter(|point| keep_a(point.0))
+        .chain(b.points.iter().filter(|point| keep_b(point.0)));
+    output.extend(points.copied().map(Geometry::Point));
+    let crossings = crossings.into_iter().filter(keep_crossing);
+    output.extend(crossings.map(|coord| Geometry::Point(coord.into())));
+    GeometryCollection::new_from(output)
+}
+
+/// The members of a collection, by dimension.
+struct Members<T: Float> {
+    /// The union of the areal members.
+    area: MultiPolygon<T>,
+    lines: Vec<LineString<T>>,
+    points: Vec<Point<T>>,
+}
+
+impl<T: Float> Members<T> {
+    fn new(collection: &GeometryCollection<T>) -> Self {
+        let mut polygons = vec![];
+        let mut lines = vec![];
+        let mut points = vec![];
+        for geom in collection {
+            sort_member(geom, &mut polygons, &mut lines, &mut points);
+        }
+        Members {
+            area: unary_union(polygons.iter()),
+            lines,
+            points,
+        }
+    }
+}
+
+/// Push `geom`, or its members, to the list of its dimension.
+fn sort_member<T: Float>(
+    geom: &Geometry<T>,
+    polygons: &mut Vec<Polygon<T>>,
+    lines: &mut Vec<LineString<T>>,
+    points: &mut Vec<Point<T>>,
+) {
+    match geom {
+        Geometry::Point(p) => points.push(*p),
+        Geometry::MultiPoint(mp) => points.extend(mp.iter().copied()),
+        Geometry::Line(l) => lines.push(LineString::new(vec![l.start, l.end])),
+        Geometry::LineString(ls) => lines.push(ls.clone()),
+        Geometry::MultiLineString(mls) => lines.extend(mls.iter().cloned()),
+        Geometry::Polygon(p) => polygons.push(p.clone()),
+        Geometry::MultiPolygon(mp) => polygons.extend(mp.iter().cloned()),
+        Geometry::Rect(r) => polygons.push(r.to_polygon()),
+        Geometry::Triangle(t) => polygons.push(t.to_polygon()),
+        Geometry::GeometryCollection(gc) => {
+            for geom in gc {
+                sort_member(geom, polygons, lines, points);
+            }
+        }
+    }
+}
+
+/// Where a segment of a line is split, and the parts of it shared with
+/// the lines of the other collection.
+#[derive(Clone)]
+struct Split<T: Float> {
+    points: Vec<Coordinate<T>>,
+    shared: Vec<Line<T>>,
+}
+
+/// Split the lines of `a` and `b` where they cross the boundaries of
+/// `areas`, and each other, and chain the pieces passing `keep` into
+/// line strings. `keep` is given whether the piece is of a line of `a`,
+/// its midpoint, and whether it is shared with a line of the other
+/// collection. Also return the distinct points where the lines of `a`
+/// and `b` cross.
+fn clip_lines<T: Float>(
+    a: &[LineString<T>],
+    b: &[LineString<T>],
+    areas: &[&MultiPolygon<T>],
+    keep: impl Fn(bool, Coordinate<T>, bool) -> bool,
+) -> (Vec<LineString<T>>, Vec<Coordinate<T>>) {
+    let segments: Vec<Line<T>> = a.iter().chain(b).flat_map(|ls| ls.lines()).collect();
+    let count_a: usize = a.iter().map(|ls| ls.lines().len()).sum();
+    let count = segments.len();
+    // The lines of `a`, of `b`, and the boundaries of the areas.
+    let source = |idx: usize| (idx >= count_a) as usize + (idx >= count) as usize;
+    let edges = areas
+        .iter()
+        .flat_map(|area| area.iter())
+        .flat_map(|poly| rings(poly).flat_map(|ring| ring.lines()));
+    let input = segments.iter().copied().chain(edges).enumerate();
+
+    let empty = Split {
+        points: vec![],
+        shared: vec![],
+    };
+    let mut splits = vec![empty; count];
+    let mut crossings = vec![];
+    for_each_intersection(
+        input,
+        |(i, _), (j, _)| source(*i) != source(*j),
+        |(i, _), (j, _), int| {
+            let both = i.max(j) < count;
+            for idx in [i, j] {
+                if idx >= count {
+                    continue;
+                }
+                let split = &mut splits[idx];
+                match int {
+                    LineIntersection::SinglePoint { intersection, .. } => {
+                        split.points.push(intersection)
+                    }
+                    LineIntersection::Collinear { intersection } => {
+                        split.points.extend([intersection.start, intersection.end]);
+                        if both {
+                            split.shared.push(intersection);
+                        }
+                    }
+                }
+            }
+            if let (true, LineIntersection::SinglePoint { intersection, .. }) = (both, int) {
+                crossings.push(intersection);
+            }
+        },
+    );
+    crossings.sort_by(compare_coords);
+    crossings.dedup();
+
+    let mut output = vec![];
+    let mut current: Vec<Coordinate<T>> = vec![];
+    let mut segments = segments.iter().zip(splits);
+    for (idx, ls) in a.iter().chain(b).enumerate() {
+        let of_a = idx < a.len();
+        for (segment, split) in segments.by_ref().take(ls.lines().len()) {
+            let start = segment.start;
+            let dist = |c: &Coordinate<T>| {
+                let d = *c - start;
+                d.x * d.x + d.y * d.y
+            };
+            let is_shared = |mid: &Coordinate<T>| {
+                split.shared.iter().any(|line| {
+                    let (d1, d2) = (dist(&line.start), dist(&line.end));
+                    d1.min(d2) <= dist(mid) && dist(mid) <= d1.max(d2)
+                })
+            };
+            let mut points = split.points.clone();
+            points.sort_by(|p, q| compare(dist(p), dist(q)));
+            points.push(segment.end);
+            let mut from = start;
+            for to in points {
+                if to == from {
+                    continue;
+                }
+                let mid = (from + to) / (T::one() + T::one());
+                if keep(of_a, mid, is_shared(&mid)) {
+                    if current.last() != Some(&from) {
+                        flush(&mut current, &mut output);
+                        current.push(from);
+                    }
+                    current.push(to);
+                } else {
+                    flush(&mut current, &mut output);
+                }
+                from = to;
+            }
+        }
+        flush(&mut current, &mut output);
+    }
+    (output, crossings)
+}
+
+/// Compare two values, ordering `NaN` after all others: unlike
+/// `partial_cmp`, this is a total order.
+fn compare<T: Float>(a: T, b: T) -> Ordering {
+    a.is_nan()
+        .cmp(&b.is_nan())
+        .then_with(|| a.partial_cmp(&b).unwrap_or(Ordering::Equal))
+}
+
+/// Compare two coordinates lexicographically, as [`compare`].
+fn compare_coords<T: Float>(a: &Coordinate<T>, b: &Coordinate<T>) -> Ordering {
+    compare(a.x, b.x).then_with(|| compare(a.y, b.y))
+}
+
+/// Move the coordinates chained so far to a line string of `output`.
+fn flush<T: Float>(current: &mut Vec<Coordinate<T>>, output: &mut Vec<LineString<T>>) {
+    if current.len() > 1 {
+        output.push(LineString::new(std::mem::take(current)));
+    }
+    current.clear();
+}
+
+fn rings<T: Float>(poly: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    std::iter::once(poly.exterior()).chain(poly.interiors())
+}
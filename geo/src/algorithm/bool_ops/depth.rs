@@ -0,0 +1,279 @@
+use std::{cmp::Ordering, collections::BTreeSet};
+
+use log::trace;
+
+use super::op::{self, compare_crossings, push_closed_ring};
+use super::{assemble, KeepOrDrop, Operand, Ring, Rings};
+use crate::{
+    sweep::{CrossingsIter, LineOrPoint, SweepPoint},
+    winding_order::WindingOrder,
+    BoundingRect, Coordinate, GeoFloat as Float, MultiPolygon, Polygon, Rect,
+};
+
+/// The number of polygons of each operand covering a region, indexed
+/// by `Operand as usize`: the subject, then the clip.
+type Depth = [isize; 2];
+
+/// An edge of an input polygon, and the depth of the region above it.
+pub(super) type Edge<T> = op::Edge<T, Depth>;
+
+/// The region covered by every one of the input polygons.
+///
+/// This runs a single sweep over the boundaries of all the inputs,
+/// tracking the number of inputs covering the region above each piece
+/// of the boundary, and keeps the regions covered by all of them. It
+/// does not fold pairwise intersections, so no intermediate output is
+/// computed.
+///
+/// The inputs are expected to be valid polygons. The result is empty if
+/// there are no inputs, or if any of them is disjoint from the common
+/// area of the others.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::unary_intersection;
+/// use geo::{polygon, Area};
+///
+/// let polygons = vec![
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     polygon![(x: 2., y: 0.), (x: 6., y: 0.), (x: 6., y: 4.), (x: 2., y: 4.)],
+///     polygon![(x: 0., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 0., y: 6.)],
+/// ];
+/// let common = unary_intersection(&polygons);
+/// assert_eq!(common.unsigned_area(), 4.);
+/// ```
+pub fn unary_intersection<'a, T, I>(polygons: I) -> MultiPolygon<T>
+where
+    T: Float + 'a,
+    I: IntoIterator<Item = &'a Polygon<T>>,
+{
+    let mut edges = vec![];
+    let mut count = 0;
+    for poly in polygons {
+        count += 1;
+        add_polygon(&mut edges, poly, Operand::Subject);
+    }
+    if count == 0 {
+        return MultiPolygon::new(vec![]);
+    }
+    assemble(sweep(&edges, |depth| depth[0] >= count), KeepOrDrop::Keep).into()
+}
+
+/// The region covered by any of the input polygons.
+///
+/// The inputs are first grouped into the connected components of the
+/// overlaps of their bounding rectangles. The components are disjoint,
+/// so each is swept on its own, including an input that overlaps no
+/// other. This is much faster than a single sweep when most of the
+/// inputs are disjoint, as each sweep only orders the segments of one
+/// component.
+///
+/// The inputs are expected to be valid polygons. The output contains
+/// the union of each component, in the order of their first input, and
+/// the disjoint inputs come out of the sweep as the other components
+/// do, with the same ring orientation and starting vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::unary_union;
+/// use geo::{polygon, Area};
+///
+/// let polygons = vec![
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     polygon![(x: 10., y: 0.), (x: 12., y: 0.), (x: 12., y: 2.), (x: 10., y: 2.)],
+///     polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)],
+/// ];
+/// let union = unary_union(&polygons);
+/// assert_eq!(union.0.len(), 2);
+/// assert_eq!(union.unsigned_area(), 16. + 4. + 16. - 4.);
+/// ```
+pub fn unary_union<'a, T, I>(polygons: I) -> MultiPolygon<T>
+where
+    T: Float + 'a,
+    I: IntoIterator<Item = &'a Polygon<T>>,
+{
+    let polygons: Vec<_> = polygons
+        .into_iter()
+        .filter_map(|poly| Some((poly, poly.bounding_rect()?)))
+        .collect();
+    let rects: Vec<_> = polygons.iter().map(|(_, rect)| *rect).collect();
+
+    let mut output = vec![];
+    for component in overlap_components(&rects) {
+        let mut edges = vec![];
+        for idx in component {
+            add_polygon(&mut edges, polygons[idx].0, Operand::Subject);
+        }
+        let rings = sweep(&edges, |depth| depth[0] > 0);
+        output.extend(MultiPolygon::from(assemble(rings, KeepOrDrop::Keep)));
+    }
+    MultiPolygon::new(output)
+}
+
+/// The indices of `rects` grouped into the connected components of their
+/// overlaps, including touching rectangles, ordered by their first index.
+fn overlap_components<T: Float>(rects: &[Rect<T>]) -> Vec<Vec<usize>> {
+    // A union-find over the indices, joined by a sweep along x.
+    let mut parent: Vec<usize> = (0..rects.len()).collect();
+    fn find(parent: &mut [usize], mut idx: usize) -> usize {
+        while parent[idx] != idx {
+            parent[idx] = parent[parent[idx]];
+            idx = parent[idx];
+        }
+        idx
+    }
+
+    let mut order: Vec<usize> = (0..rects.len()).collect();
+    order.sort_by(|&a, &b| rects[a].min().x.partial_cmp(&rects[b].min().x).unwrap());
+    let mut active: Vec<usize> = vec![];
+    for idx in order {
+        let rect = rects[idx];
+        active.retain(|&other| rects[other].max().x >= rect.min().x);
+        for &other in &active {
+            let other_rect = rects[other];
+            if other_rect.min().y <= rect.max().y && rect.min().y <= other_rect.max().y {
+                let (a, b) = (find(&mut parent, idx), find(&mut parent, other));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+        active.push(idx);
+    }
+
+    // Each root is the least index of its component.
+    let mut components: Vec<Vec<usize>> = vec![];
+    let mut component_of = vec![usize::MAX; rects.len()];
+    for idx in 0..rects.len() {
+        let root = find(&mut parent, idx);
+        if root == idx {
+            component_of[idx] = components.len();
+            components.push(vec![]);
+        }
+        components[component_of[root]].push(idx);
+    }
+    components
+}
+
+/// Add the edges of a polygon to `operand`.
+pub(super) fn add_polygon<T: Float>(edges: &mut Vec<Edge<T>>, poly: &Polygon<T>, operand: Operand) {
+    push_closed_ring(edges, poly.exterior(), operand, false);
+    for hole in poly.interiors() {
+        push_closed_ring(edges, hole, operand, true);
+    }
+}
+
+/// Sweep the edges, and chain the pieces bounding the region of the
+/// depths to `keep` into rings.
+pub(super) fn sweep<T: Float>(edges: &[Edge<T>], keep: impl Fn(Depth) -> bool) -> Vec<Ring<T>> {
+    sweep_classes(edges, 1, |depth| if keep(depth) { Some(0) } else { None })
+        .pop()
+        .unwrap()
+}
+
+/// Sweep the edges, and chain the pieces bounding the regions of each
+/// of `count` classes into rings, in a single pass. The `class` of a
+/// region is given by its depth; `None` drops the region.
+pub(super) fn sweep_classes<T: Float>(
+    edges: &[Edge<T>],
+    count: usize,
+    class: impl Fn(Depth) -> Option<usize>,
+) -> Vec<Vec<Ring<T>>> {
+    let mut rings: Vec<Rings<T>> = (0..count).map(|_| Rings::default()).collect();
+    let mut steiner: BTreeSet<SweepPoint<T>> = BTreeSet::new();
+    sweep_pieces(
+        edges,
+        |pt| {
+            steiner.insert(pt.into());
+        },
+        |piece, above, below| {
+            let (above, below) = (class(above), class(below));
+            if above == below {
+                return;
+            }
+            // Each region is on the left of its rings.
+            if let Some(above) = above {
+                rings[above].add_edge(piece, WindingOrder::CounterClockwise);
+            }
+            if let Some(below) = below {
+                rings[below].add_edge(piece, WindingOrder::Clockwise);
+            }
+        },
+    );
+    rings
+        .into_iter()
+        .map(|rings| rings.finish(&steiner))
+        .collect()
+}
+
+/// Sweep the edges, calling `on_steiner` at each intersection point that
+/// is not a vertex of the input, and `on_piece` with each piece of the
+/// noded edges, with the depths above and below it.
+pub(super) fn sweep_pieces<T: Float>(
+    edges: &[Edge<T>],
+    mut on_steiner: impl FnMut(Coordinate<T>),
+    mut on_piece: impl FnMut(LineOrPoint<T>, Depth, Depth),
+) {
+    let mut iter: CrossingsIter<_> = edges.iter().collect();
+
+    while let Some(pt) = iter.next() {
+        iter.intersections_mut().sort_unstable_by(compare_crossings);
+        let is_input_vertex = iter
+            .intersections()
+            .iter()
+            .any(|c| *c.cross.geom.left() == pt || *c.cross.geom.right() == pt);
+        if !is_input_vertex {
+            on_steiner(pt);
+        }
+
+        // Process all end-segments, from the top.
+        let mut idx = 0;
+        let mut below = None;
+        while idx < iter.intersections().len() {
+            let c = &iter.intersections()[idx];
+            if c.at_left {
+                break;
+            }
+            let above = c.cross.get_region(c.line);
+            let depth = below.get_or_insert(above);
+            depth[c.cross.operand as usize] -= c.cross.winding();
+            let has_overlap = (idx + 1) < iter.intersections().len()
+                && compare_crossings(c, &iter.intersections()[idx + 1]) == Ordering::Equal;
+            if !has_overlap {
+                let below = below.take().unwrap();
+                trace!("piece: {geom:?}: {above:?} -> {below:?}", geom = c.line);
+                on_piece(c.line, above, below);
+            }
+            idx += 1;
+        }
+
+        // Set the depths above the start-segments, from the bottom.
+        if idx < iter.intersections().len() {
+            let botmost_start_segment = iter.intersections()[idx].clone();
+            let mut depth = iter
+                .prev_active(&botmost_start_segment)
+                .map(|(g, c)| c.get_region(g))
+                .unwrap_or_default();
+            while idx < iter.intersections().len() {
+                let mut c = &iter.intersections()[idx];
+                let mut jdx = idx;
+                loop {
+                    depth[c.cross.operand as usize] += c.cross.winding();
+                    let has_overlap = (idx + 1) < iter.intersections().len()
+                        && compare_crossings(c, &iter.intersections()[idx + 1]) == Ordering::Equal;
+                    if !has_overlap {
+                        break;
+                    }
+                    idx += 1;
+                    c = &iter.intersections()[idx];
+                }
+                while jdx <= idx {
+                    let c = &iter.intersections()[jdx];
+                    c.cross.set_region(depth, c.line);
+                    jdx += 1;
+                }
+                idx += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{
+    assemble,
+    depth::{add_polygon, sweep, Edge},
+    KeepOrDrop, Operand, Ring,
+};
+use crate::{GeoFloat as Float, MultiPolygon, Polygon};
+
+/// The difference of a subject and the union of many masks, the "erase"
+/// of GIS tools.
+///
+/// The subject is given once, and the masks are then added one by one,
+/// e.g. while streaming them from a source, without collecting them in a
+/// `MultiPolygon` first. The masks may overlap each other: they are not
+/// unioned pairwise, but a single sweep over all the boundaries counts
+/// the masks covering each region, and keeps the part of the subject
+/// covered by none.
+///
+/// This is unlike the second operand of an [`Op`](super::Op) of
+/// [`OpType::Difference`](super::OpType::Difference), whose polygons
+/// must not overlap.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble, Erase, KeepOrDrop};
+/// use geo::{polygon, Area, MultiPolygon};
+///
+/// let subject: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+/// let mut erase = Erase::new(&subject);
+///
+/// // Two overlapping masks, and one partly outside the subject.
+/// erase.add_mask(&polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]);
+/// erase.add_mask(&polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]);
+/// erase.add_mask(&polygon![(x: 9., y: 9.), (x: 12., y: 9.), (x: 12., y: 12.), (x: 9., y: 12.)]);
+///
+/// let erased = MultiPolygon::new(assemble(erase.sweep(), KeepOrDrop::Keep));
+/// assert_eq!(erased.unsigned_area(), 100. - 7. - 1.);
+/// ```
+#[derive(Clone)]
+pub struct Erase<T: Float> {
+    edges: Vec<Edge<T>>,
+}
+
+impl<T: Float> Erase<T> {
+    /// Start erasing from `subject`.
+    pub fn new(subject: &MultiPolygon<T>) -> Self {
+        let mut edges = vec![];
+        for poly in subject {
+            add_polygon(&mut edges, poly, Operand::Subject);
+        }
+        Erase { edges }
+    }
+
+    /// Add a mask to erase from the subject.
+    pub fn add_mask(&mut self, mask: &Polygon<T>) {
+        add_polygon(&mut self.edges, mask, Operand::Clip);
+    }
+
+    /// Run the sweep, and return the rings bounding the part of the
+    /// subject not covered by any mask.
+    pub fn sweep(&self) -> Vec<Ring<T>> {
+        sweep(&self.edges, |depth| depth[0] > 0 && depth[1] <= 0)
+    }
+}
+
+impl<'a, T: Float> Extend<&'a Polygon<T>> for Erase<T> {
+    fn extend<I: IntoIterator<Item = &'a Polygon<T>>>(&mut self, masks: I) {
+        masks.into_iter().for_each(|mask| self.add_mask(mask));
+    }
+}
+
+/// The difference of `subject` and the union of `erasers`, computed in a
+/// single sweep by [`Erase`], without computing the union of the
+/// erasers. The erasers may overlap each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::difference_all;
+/// use geo::{polygon, Area, MultiPolygon};
+///
+/// let subject: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+/// let erasers = vec![
+///     polygon![(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 10.), (x: 0., y: 10.)],
+///     polygon![(x: 4., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.), (x: 4., y: 5.)],
+/// ];
+///
+/// let erased = difference_all(&subject, &erasers);
+/// assert_eq!(erased.unsigned_area(), 20.);
+/// ```
+pub fn difference_all<'a, T, I>(subject: &MultiPolygon<T>, erasers: I) -> MultiPolygon<T>
+where
+    T: Float + 'a,
+    I: IntoIterator<Item = &'a Polygon<T>>,
+{
+    let mut erase = Erase::new(subject);
+    erase.extend(erasers);
+    assemble(erase.sweep(), KeepOrDrop::Keep).into()
+}
@@ -0,0 +1,144 @@
+use super::BooleanOps;
+use crate::closest_point_index::project;
+use crate::{
+    BoundingRect, Coordinate, GeoFloat as Float, Line, MapCoords, MultiPolygon, Polygon, Rect,
+    SquaredEuclideanDistance,
+};
+
+/// The union of two polygonal geometries, closing the narrow gaps
+/// between their boundaries.
+///
+/// Adjacent polygons digitized separately rarely share their boundaries
+/// exactly: a plain [`union`](BooleanOps::union) keeps them apart, or
+/// leaves slivers between them. Here, the vertices of `other` within
+/// `gap_tolerance` of a vertex of `self` are first moved to the closest
+/// one, and otherwise to the closest point of an edge of `self` within
+/// the tolerance. The vertices of `self` are then snapped to the
+/// snapped `other` in the same way, before computing the union.
+///
+/// No vertex moves by more than `gap_tolerance`, so features farther
+/// apart are not merged. The snapping may however collapse the parts
+/// of either geometry narrower than the tolerance. A vertex snapped to
+/// the interior of an edge is rounded to the nearest coordinates, so
+/// gaps along slanted edges may leave slivers of the order of the
+/// rounding error.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, MultiPolygon, UnionGapClosing};
+///
+/// // Two squares, 0.5mm apart along x = 1.
+/// let a: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into();
+/// let b: MultiPolygon<f64> =
+///     polygon![(x: 1.0005, y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1.0005, y: 1.)].into();
+///
+/// let merged = a.union_gap_closing(&b, 0.001);
+/// assert_eq!(merged.0.len(), 1);
+/// assert_eq!(merged.unsigned_area(), 2.);
+///
+/// assert_eq!(a.union_gap_closing(&b, 0.0001).0.len(), 2);
+/// ```
+pub trait UnionGapClosing<T: Float> {
+    fn union_gap_closing(&self, other: &Self, gap_tolerance: T) -> MultiPolygon<T>;
+}
+
+impl<T: Float> UnionGapClosing<T> for MultiPolygon<T> {
+    fn union_gap_closing(&self, other: &Self, gap_tolerance: T) -> MultiPolygon<T> {
+        let other = Snap::new(self, gap_tolerance).apply(other);
+        let this = Snap::new(&other, gap_tolerance).apply(self);
+        this.union(&other)
+    }
+}
+
+impl<T: Float> UnionGapClosing<T> for Polygon<T> {
+    fn union_gap_closing(&self, other: &Self, gap_tolerance: T) -> MultiPolygon<T> {
+        MultiPolygon::from(self.clone())
+            .union_gap_closing(&MultiPolygon::from(other.clone()), gap_tolerance)
+    }
+}
+
+/// Snap coordinates to the boundary of a target within a tolerance.
+struct Snap<T: Float> {
+    /// The vertices of the target, sorted by x.
+    vertices: Vec<Coordinate<T>>,
+    /// The edges of the target, with their bounding rectangles, sorted
+    /// by their left ends.
+    edges: Vec<(Line<T>, Rect<T>)>,
+    /// The largest width of the edges.
+    max_width: T,
+    tolerance: T,
+}
+
+impl<T: Float> Snap<T> {
+    fn new(target: &MultiPolygon<T>, tolerance: T) -> Self {
+        let rings = target
+            .iter()
+            .flat_map(|poly| std::iter::once(poly.exterior()).chain(poly.interiors()));
+        let mut vertices = vec![];
+        let mut edges = vec![];
+        for ring in rings {
+            vertices.extend(ring.0.iter().copied());
+            edges.extend(ring.lines().map(|line| (line, line.bounding_rect())));
+        }
+        vertices.sort_by(|p, q| p.x.partial_cmp(&q.x).unwrap());
+        edges.sort_by(|(_, r1), (_, r2)| r1.min().x.partial_cmp(&r2.min().x).unwrap());
+        let max_width = edges
+            .iter()
+            .map(|(_, rect)| rect.width())
+            .fold(T::zero(), T::max);
+        Snap {
+            vertices,
+            edges,
+            max_width,
+            tolerance,
+        }
+    }
+
+    fn apply(&self, source: &MultiPolygon<T>) -> MultiPolygon<T> {
+        source.map_coords(|coord| self.snap(coord))
+    }
+
+    /// The closest vertex of the target within the tolerance of `coord`,
+    /// or else the closest point of an edge, or else `coord`.
+    fn snap(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let tolerance_2 = self.tolerance * self.tolerance;
+        let (left, right) = (coord.x - self.tolerance, coord.x + self.tolerance);
+        let from = self.vertices.partition_point(|v| v.x < left);
+        let to = self.vertices.partition_point(|v| v.x <= right);
+        let vertex = self.vertices[from..to]
+            .iter()
+            .map(|&v| (v, v.squared_euclidean_distance(&coord)))
+            .filter(|&(_, dist)| dist <= tolerance_2)
+            .min_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap());
+        if let Some((vertex, _)) = vertex {
+            return vertex;
+        }
+
+        // No edge ending right of `left` starts left of this.
+        let from = self
+            .edges
+            .partition_point(|(_, rect)| rect.min().x < left - self.max_width);
+        let to = self
+            .edges
+            .partition_point(|(_, rect)| rect.min().x <= right);
+        let on_edge = self.edges[from..to]
+            .iter()
+            .filter(|(_, rect)| {
+                rect.max().x >= left
+                    && rect.min().y <= coord.y + self.tolerance
+                    && rect.max().y >= coord.y - self.tolerance
+            })
+            .map(|(line, _)| {
+                let point = project(line.start, line.end, coord);
+                (point, point.squared_euclidean_distance(&coord))
+            })
+            .filter(|&(_, dist)| dist <= tolerance_2)
+            .min_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap());
+        match on_edge {
+            Some((point, _)) => point,
+            None => coord,
+        }
+    }
+}
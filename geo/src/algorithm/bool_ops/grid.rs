@@ -0,0 +1,143 @@
+use std::{collections::HashMap, error, fmt};
+
+use super::{
+    assemble,
+    depth::{add_polygon, sweep_classes},
+    KeepOrDrop, Operand,
+};
+use crate::{BoundingRect, CoordNum, Coordinate, GeoFloat as Float, MultiPolygon, Polygon, Rect};
+
+/// Clip a geometry to the cells of a regular grid.
+///
+/// The cell `(i, j)` is the square of side `cell_size` with its minimum
+/// corner at `origin + (i, j) * cell_size`. The result maps the index of
+/// each cell the geometry has area in to the part of the geometry in
+/// that cell; the other cells are omitted.
+///
+/// The cells are not clipped one by one: the cells covering the
+/// geometry are colored as a checkerboard, and a single sweep over the
+/// geometry and the cells of one color chains the boundaries of the
+/// geometry in the cells of either color. Each output polygon is then
+/// assigned to the cell containing it.
+///
+/// # Errors
+///
+/// If the index of a cell covered by the geometry does not fit in an
+/// `i64`, e.g. for a non-finite coordinate, or a cell size too small for
+/// coordinates far from `origin`.
+///
+/// # Panics
+///
+/// If `cell_size` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, Coordinate, GridClip, MultiPolygon};
+///
+/// let square: MultiPolygon<f64> =
+///     polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)].into();
+/// let cells = square.grid_clip(Coordinate { x: 0., y: 0. }, 2.).unwrap();
+///
+/// assert_eq!(cells.len(), 4);
+/// assert_eq!(cells[&(1, 1)].unsigned_area(), 1.);
+/// ```
+pub trait GridClip<T: Float> {
+    #[allow(clippy::type_complexity)]
+    fn grid_clip(
+        &self,
+        origin: Coordinate<T>,
+        cell_size: T,
+    ) -> Result<HashMap<(i64, i64), MultiPolygon<T>>, CellOutOfRange<T>>;
+}
+
+impl<T: Float> GridClip<T> for MultiPolygon<T> {
+    fn grid_clip(
+        &self,
+        origin: Coordinate<T>,
+        cell_size: T,
+    ) -> Result<HashMap<(i64, i64), MultiPolygon<T>>, CellOutOfRange<T>> {
+        assert!(cell_size > T::zero(), "cell size must be positive");
+        let mut cells = HashMap::new();
+        let rect = match self.bounding_rect() {
+            Some(rect) => rect,
+            None => return Ok(cells),
+        };
+        // The last index is excluded, so that the next one is valid too.
+        let index = |v: T| v.floor().to_i64().filter(|&i| i < i64::MAX);
+        let cell_of = |c: Coordinate<T>| {
+            let (i, j) = ((c - origin) / cell_size).x_y();
+            match (index(i), index(j)) {
+                (Some(i), Some(j)) => Ok((i, j)),
+                _ => Err(CellOutOfRange { coordinate: c }),
+            }
+        };
+        let corner = |i: i64, j: i64| {
+            origin
+                + Coordinate {
+                    x: T::from(i).unwrap(),
+                    y: T::from(j).unwrap(),
+                } * cell_size
+        };
+
+        let mut edges = vec![];
+        for poly in self {
+            add_polygon(&mut edges, poly, Operand::Subject);
+        }
+        let (min, max) = (cell_of(rect.min())?, cell_of(rect.max())?);
+        for i in min.0..=max.0 {
+            for j in min.1..=max.1 {
+                if i.rem_euclid(2) == j.rem_euclid(2) {
+                    let cell = Rect::new(corner(i, j), corner(i + 1, j + 1));
+                    add_polygon(&mut edges, &cell.into(), Operand::Clip);
+                }
+            }
+        }
+
+        let colors = sweep_classes(&edges, 2, |depth| {
+            (depth[0] > 0).then(|| if depth[1] > 0 { 0 } else { 1 })
+        });
+        for rings in colors {
+            for poly in assemble(rings, KeepOrDrop::Keep) {
+                // The polygon is in a single cell, and so is the center
+                // of its bounding rectangle.
+                let center = poly.bounding_rect().unwrap().center();
+                cells
+                    .entry(cell_of(center)?)
+                    .or_insert_with(|| MultiPolygon::new(vec![]))
+                    .0
+                    .push(poly);
+            }
+        }
+        Ok(cells)
+    }
+}
+
+impl<T: Float> GridClip<T> for Polygon<T> {
+    fn grid_clip(
+        &self,
+        origin: Coordinate<T>,
+        cell_size: T,
+    ) -> Result<HashMap<(i64, i64), MultiPolygon<T>>, CellOutOfRange<T>> {
+        MultiPolygon::new(vec![self.clone()]).grid_clip(origin, cell_size)
+    }
+}
+
+/// The error returned by [`GridClip::grid_clip`] when the index of the
+/// cell of `coordinate` does not fit in an `i64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellOutOfRange<T: CoordNum> {
+    pub coordinate: Coordinate<T>,
+}
+
+impl<T: CoordNum> fmt::Display for CellOutOfRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "grid cell index out of range at {:?}",
+            self.coordinate.x_y()
+        )
+    }
+}
+
+impl<T: CoordNum> error::Error for CellOutOfRange<T> {}
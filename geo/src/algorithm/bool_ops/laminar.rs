@@ -1,31 +1,470 @@
-use std::{cell::Cell, cmp::Ordering, iter::FromIterator};
+use std::{
+    cell::Cell,
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BinaryHeap},
+    rc::Rc,
+};
 
 use crate::GeoFloat as Float;
-use crate::{GeoFloat, Polygon};
+use crate::{Coordinate, GeoFloat, LineString, Polygon};
 use log::trace;
 
-use crate::sweep::{Cross, Crossing, CrossingsIter, LineOrPoint};
+use crate::sweep::{Cross, Crossing, CrossingsIter, LineOrPoint, SweepPoint};
 
 use super::Ring;
 
-pub fn assemble<T: Float>(rings: Vec<Ring<T>>) -> Vec<Polygon<T>> {
-    let mut parents = vec![0; rings.len()];
-    let edges: Vec<Edge<_>> = rings
-        .iter()
+/// Whether to keep the vertices of the output rings that lie in the
+/// middle of a straight edge.
+///
+/// The sweep splits the edges of the inputs at every intersection, and
+/// some of these points may end up in the middle of a straight output
+/// edge. Vertices of the inputs are always kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepOrDrop {
+    /// Keep all the vertices created by the sweep.
+    Keep,
+    /// Drop the vertices created by the sweep that are collinear with
+    /// their neighbours, as decided by the robust orientation predicate.
+    Drop,
+}
+
+/// Assemble the rings of a boolean operation into polygons, by nesting
+/// the holes in their exteriors.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble, KeepOrDrop, Op, OpType};
+/// use geo::{polygon, MultiPolygon};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+/// let b = MultiPolygon::new(vec![
+///     polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 3.), (x: 1., y: 3.)],
+///     polygon![(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 2., y: 3.)],
+/// ]);
+///
+/// let mut op = Op::new(OpType::Intersection, 12);
+/// op.add_polygon(&a, true);
+/// op.add_multi_polygon(&b, false);
+///
+/// // The sweep creates a vertex at (2, 2), in the middle of the top edge.
+/// let polygons = assemble(op.sweep(), KeepOrDrop::Keep);
+/// assert_eq!(polygons[0].exterior().0.len(), 7);
+///
+/// // The input vertex at (2, 1) is kept.
+/// let polygons = assemble(op.sweep(), KeepOrDrop::Drop);
+/// assert_eq!(polygons[0].exterior().0.len(), 6);
+/// ```
+pub fn assemble<T: Float>(rings: Vec<Ring<T>>, collinear_vertices: KeepOrDrop) -> Vec<Polygon<T>> {
+    assemble_ordered(rings, collinear_vertices, DrawOrder::Rings)
+}
+
+/// The order of the polygons output by [`assemble_ordered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawOrder {
+    /// In the order of their exteriors among the rings, as [`assemble`].
+    Rings,
+    /// By the depth of the polygons in the containment forest, shallowest
+    /// first, and then in the order of their exteriors among the rings.
+    ///
+    /// A polygon not inside any other has depth zero, and one in a hole
+    /// of a polygon has one more than its depth. Painting in this order
+    /// draws each polygon after the polygons around it.
+    OuterToInner,
+}
+
+/// Assemble the rings of a boolean operation into polygons, as
+/// [`assemble`], in the given order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble_ordered, DrawOrder, KeepOrDrop, Op, OpType};
+/// use geo::{polygon, BoundingRect, Polygon};
+///
+/// let frame = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+///     interiors: [[(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 5.), (x: 1., y: 5.)]],
+/// ];
+/// let island = polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)];
+///
+/// let mut op = Op::new(OpType::Union, 12);
+/// op.add_polygon(&island, true);
+/// op.add_polygon(&frame, false);
+///
+/// let polygons = assemble_ordered(op.sweep(), KeepOrDrop::Keep, DrawOrder::OuterToInner);
+/// let left = |p: &Polygon<f64>| p.bounding_rect().unwrap().min().x;
+/// assert_eq!(polygons.iter().map(left).collect::<Vec<_>>(), vec![0., 2.]);
+/// ```
+pub fn assemble_ordered<T: Float>(
+    rings: Vec<Ring<T>>,
+    collinear_vertices: KeepOrDrop,
+    order: DrawOrder,
+) -> Vec<Polygon<T>> {
+    let mut nesting = Nesting::new(rings, collinear_vertices);
+    while nesting.step().is_some() {}
+    let depths = match order {
+        DrawOrder::Rings => None,
+        DrawOrder::OuterToInner => Some(nesting.depths()),
+    };
+    let Nesting { rings, parents, .. } = nesting;
+
+    let mut polygons = vec![None; rings.len()];
+    rings.iter().enumerate().for_each(|(idx, r)| {
+        if r.is_hole() {
+            let p_idx = parents[idx];
+            if polygons[p_idx].is_none() {
+                polygons[p_idx] = Some(Polygon::new(rings[p_idx].coords().clone(), vec![]));
+            }
+            polygons[p_idx]
+                .as_mut()
+                .unwrap()
+                .interiors_push(r.coords().clone());
+        } else if polygons[idx].is_none() {
+            polygons[idx] = Some(Polygon::new(rings[idx].coords().clone(), vec![]));
+        }
+    });
+
+    let mut polygons: Vec<_> = polygons
+        .into_iter()
         .enumerate()
-        .flat_map(|(idx, ring)| {
-            debug_assert!(ring.coords().is_closed());
-            ring.coords().lines().map(move |l| (idx, l))
+        .filter_map(|(idx, p)| Some((idx, p?)))
+        .collect();
+    if let Some(depths) = depths {
+        // Stable, so ties stay in the order of the rings.
+        polygons.sort_by_key(|(idx, _)| depths[*idx]);
+    }
+    polygons.into_iter().map(|(_, p)| p).collect()
+}
+
+/// How [`assemble_pinched`] outputs the rings of the boundary of a
+/// region that touch at a single point.
+///
+/// A region may touch itself at a pinch point, e.g. an hourglass, or a
+/// hole touching the exterior. The sweep always splits its boundary
+/// there, into simple rings sharing the point, as in the OGC model.
+/// Some consumers expect a single "weakly simple" ring instead, that
+/// passes twice through the point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinchPolicy {
+    /// Join the rings touching at a point into a single ring.
+    Keep,
+    /// Output separate rings, as [`assemble`].
+    Split,
+}
+
+/// Assemble the rings of a boolean operation into polygons, as
+/// [`assemble`], joining the rings that touch at a point if `pinches`
+/// is [`PinchPolicy::Keep`].
+///
+/// With `Keep`, the exteriors of polygons sharing a vertex are joined
+/// into a single polygon, with the holes of both, whose exterior passes
+/// twice through the vertex. Within each polygon, the holes sharing a
+/// vertex with the exterior are joined to it, as an inward loop, and
+/// the holes sharing a vertex with each other into one hole. Rings
+/// sharing more than one vertex are joined at the first one found.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble_pinched, KeepOrDrop, Op, OpType, PinchPolicy};
+/// use geo::{coord, polygon};
+///
+/// // Two triangles touching at (1, 1): an hourglass.
+/// let lower = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 1.)];
+/// let upper = polygon![(x: 1., y: 1.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// let mut op = Op::new(OpType::Union, 6);
+/// op.add_polygon(&lower, true);
+/// op.add_polygon(&upper, false);
+///
+/// let split = assemble_pinched(op.sweep(), KeepOrDrop::Keep, PinchPolicy::Split);
+/// assert_eq!(split.len(), 2);
+///
+/// let kept = assemble_pinched(op.sweep(), KeepOrDrop::Keep, PinchPolicy::Keep);
+/// assert_eq!(kept.len(), 1);
+/// let exterior = &kept[0].exterior().0;
+/// assert_eq!(exterior.len(), 7);
+/// // The exterior passes twice through (1, 1), but for the closing vertex.
+/// let pinch = coord! { x: 1., y: 1. };
+/// assert_eq!(exterior[..6].iter().filter(|c| **c == pinch).count(), 2);
+/// ```
+pub fn assemble_pinched<T: Float>(
+    rings: Vec<Ring<T>>,
+    collinear_vertices: KeepOrDrop,
+    pinches: PinchPolicy,
+) -> Vec<Polygon<T>> {
+    let polygons = assemble(rings, collinear_vertices);
+    match pinches {
+        PinchPolicy::Split => polygons,
+        PinchPolicy::Keep => join_pinches(polygons),
+    }
+}
+
+/// The pinch points of assembled polygons: the vertices shared by two
+/// of their rings, or repeated within a ring, in lexicographic order.
+///
+/// These are the points where [`PinchPolicy::Split`] separates the
+/// rings, and [`PinchPolicy::Keep`] joins them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble_pinched, pinch_vertices, KeepOrDrop, Op, OpType, PinchPolicy};
+/// use geo::{coord, polygon};
+///
+/// // Two triangles touching at (1, 1): an hourglass.
+/// let lower = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 1.)];
+/// let upper = polygon![(x: 1., y: 1.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// let mut op = Op::new(OpType::Union, 6);
+/// op.add_polygon(&lower, true);
+/// op.add_polygon(&upper, false);
+///
+/// let split = assemble_pinched(op.sweep(), KeepOrDrop::Keep, PinchPolicy::Split);
+/// assert_eq!(pinch_vertices(&split), vec![coord! { x: 1., y: 1. }]);
+/// ```
+pub fn pinch_vertices<T: Float>(polygons: &[Polygon<T>]) -> Vec<Coordinate<T>> {
+    let mut counts = BTreeMap::new();
+    let rings = polygons
+        .iter()
+        .flat_map(|poly| std::iter::once(poly.exterior()).chain(poly.interiors()));
+    for ring in rings {
+        for vertex in &ring.0[..ring.0.len().saturating_sub(1)] {
+            *counts.entry(SweepPoint::from(*vertex)).or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(vertex, _)| *vertex)
+        .collect()
+}
+
+/// Join the polygons, and then the rings of each polygon, touching at
+/// a vertex.
+fn join_pinches<T: Float>(polygons: Vec<Polygon<T>>) -> Vec<Polygon<T>> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) =
+        polygons.into_iter().map(|poly| poly.into_inner()).unzip();
+    let (exteriors, roots) = join_touching(exteriors);
+    let mut all_holes: Vec<Vec<LineString<T>>> = vec![vec![]; exteriors.len()];
+    for (root, holes) in roots.into_iter().zip(holes) {
+        all_holes[root].extend(holes);
+    }
+    exteriors
+        .into_iter()
+        .zip(all_holes)
+        .filter_map(|(exterior, holes)| {
+            let mut rings = vec![exterior?];
+            rings.extend(holes);
+            let (rings, _) = join_touching(rings);
+            let mut rings = rings.into_iter().flatten();
+            let exterior = rings.next().unwrap();
+            Some(Polygon::new(exterior, rings.collect()))
         })
-        .map(|(ring_idx, line)| Edge {
-            geom: line.into(),
-            ring_idx,
-            region: None.into(),
+        .collect()
+}
+
+/// Splice each ring into the first ring sharing a vertex with it, in
+/// order. Returns the remaining rings, in their positions, and the
+/// index of the ring each one was spliced into.
+fn join_touching<T: Float>(rings: Vec<LineString<T>>) -> (Vec<Option<LineString<T>>>, Vec<usize>) {
+    let mut roots: Vec<usize> = (0..rings.len()).collect();
+    let find = |roots: &[usize], mut idx: usize| {
+        while roots[idx] != idx {
+            idx = roots[idx];
+        }
+        idx
+    };
+    let vertices: Vec<Vec<_>> = rings
+        .iter()
+        .map(|ring| ring.0[..ring.0.len().saturating_sub(1)].to_vec())
+        .collect();
+    let mut rings: Vec<_> = rings.into_iter().map(Some).collect();
+    let mut owners = BTreeMap::new();
+    for (idx, vertices) in vertices.into_iter().enumerate() {
+        for vertex in vertices {
+            let owner = *owners.entry(SweepPoint::from(vertex)).or_insert(idx);
+            let (owner, root) = (find(&roots, owner), find(&roots, idx));
+            if owner == root {
+                continue;
+            }
+            // Splice the later ring into the earlier one, to keep the
+            // exterior first.
+            let (into, from) = (owner.min(root), owner.max(root));
+            let spliced = rings[from].take().unwrap();
+            splice(rings[into].as_mut().unwrap(), &spliced, vertex.into());
+            roots[from] = into;
+        }
+    }
+    let roots = (0..roots.len()).map(|idx| find(&roots, idx)).collect();
+    (rings, roots)
+}
+
+/// Insert the closed ring `other` into the closed ring `ring` at their
+/// common vertex `at`.
+fn splice<T: Float>(ring: &mut LineString<T>, other: &LineString<T>, at: SweepPoint<T>) {
+    let position = |ring: &LineString<T>| {
+        ring.0
+            .iter()
+            .position(|c| SweepPoint::from(*c) == at)
+            .unwrap()
+    };
+    let (start, other_start) = (position(ring), position(other));
+    let n = other.0.len() - 1;
+    let lobe = (0..n).map(|k| other.0[(other_start + k) % n]);
+    ring.0.splice(start..start, lobe);
+}
+
+/// Assemble the rings of a boolean operation into polygons lazily.
+///
+/// This is like [`assemble`], but yields each polygon as soon as it is
+/// complete, instead of collecting all of them. The holes are nested in
+/// their exteriors by a sweep from left to right; a polygon is only
+/// complete once the sweep passes the rightmost point of its exterior,
+/// as all its holes have started by then. The polygons are thus yielded
+/// in the order of the rightmost points of their exteriors.
+///
+/// The coordinates of the rings are moved into the polygons yielded,
+/// and not copied. However, the sweep holds the edges of all the rings
+/// until it completes, so the memory used remains proportional to the
+/// size of the whole output, even if the polygons are dropped as they
+/// are yielded.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble_iter, KeepOrDrop, Op, OpType};
+/// use geo::{polygon, BoundingRect, MultiPolygon, Polygon};
+///
+/// let a = MultiPolygon::new(vec![
+///     polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.), (x: 4., y: 1.)],
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+/// ]);
+///
+/// let mut op = Op::new(OpType::Union, 8);
+/// op.add_multi_polygon(&a, true);
+///
+/// // The leftmost polygon is complete first.
+/// let mut polygons = assemble_iter(op.sweep(), KeepOrDrop::Keep);
+/// let right = |p: Polygon<f64>| p.bounding_rect().unwrap().max().x;
+/// assert_eq!(polygons.next().map(right), Some(1.));
+/// assert_eq!(polygons.next().map(right), Some(5.));
+/// assert_eq!(polygons.next(), None);
+/// ```
+pub fn assemble_iter<T: Float>(
+    rings: Vec<Ring<T>>,
+    collinear_vertices: KeepOrDrop,
+) -> AssembleIter<T> {
+    let nesting = Nesting::new(rings, collinear_vertices);
+    let pending = nesting
+        .rings
+        .iter()
+        .enumerate()
+        .filter(|(_, ring)| !ring.is_hole())
+        .filter_map(|(idx, ring)| {
+            let right = ring.coords().0.iter().map(|c| SweepPoint::from(*c)).max()?;
+            Some(Reverse((right, idx)))
         })
         .collect();
+    AssembleIter {
+        nesting,
+        pending,
+        swept: None,
+        done: false,
+    }
+}
+
+/// Iterator over the polygons assembled by [`assemble_iter`].
+pub struct AssembleIter<T: Float> {
+    nesting: Nesting<T>,
+    /// The exteriors not yielded yet, by their rightmost points.
+    pending: BinaryHeap<Reverse<(SweepPoint<T>, usize)>>,
+    /// The last point processed by the sweep.
+    swept: Option<SweepPoint<T>>,
+    done: bool,
+}
+
+impl<T: Float> Iterator for AssembleIter<T> {
+    type Item = Polygon<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let complete = match self.pending.peek() {
+                Some(Reverse((right, _))) => {
+                    self.done || matches!(self.swept, Some(pt) if *right <= pt)
+                }
+                None => return None,
+            };
+            if complete {
+                let Reverse((_, idx)) = self.pending.pop().unwrap();
+                // In the order of the rings, as in `assemble`.
+                let mut hole_idxs = std::mem::take(&mut self.nesting.holes[idx]);
+                hole_idxs.sort_unstable();
+                hole_idxs.dedup();
+                // The sweep only needs the kind of the rings from now on.
+                let rings = &mut self.nesting.rings;
+                let holes = hole_idxs
+                    .iter()
+                    .map(|&hole| rings[hole].take_coords())
+                    .collect();
+                return Some(Polygon::new(rings[idx].take_coords(), holes));
+            }
+            match self.nesting.step() {
+                Some(pt) => self.swept = Some(pt),
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+/// The sweep nesting the holes in their exteriors.
+struct Nesting<T: Float> {
+    rings: Vec<Ring<T>>,
+    /// The exterior of each hole.
+    parents: Vec<usize>,
+    /// The holes of each exterior, once for each of their edges found to
+    /// start above it.
+    holes: Vec<Vec<usize>>,
+    /// The hole around each exterior, if any.
+    enclosing: Vec<Option<usize>>,
+    sweep: CrossingsIter<Rc<Edge<T>>>,
+}
+
+impl<T: Float> Nesting<T> {
+    fn new(rings: Vec<Ring<T>>, collinear_vertices: KeepOrDrop) -> Self {
+        let rings: Vec<_> = match collinear_vertices {
+            KeepOrDrop::Keep => rings,
+            KeepOrDrop::Drop => rings.iter().map(Ring::without_collinear).collect(),
+        };
+        let sweep = rings
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, ring)| {
+                debug_assert!(ring.coords().is_closed());
+                ring.coords().lines().map(move |l| (idx, l))
+            })
+            .map(|(ring_idx, line)| {
+                Rc::new(Edge {
+                    geom: line.into(),
+                    ring_idx,
+                    region: Region::Outside(None).into(),
+                })
+            })
+            .collect();
+        Nesting {
+            parents: vec![0; rings.len()],
+            holes: vec![vec![]; rings.len()],
+            enclosing: vec![None; rings.len()],
+            rings,
+            sweep,
+        }
+    }
 
-    let mut sweep = CrossingsIter::from_iter(edges.iter());
-    while let Some(pt) = sweep.next() {
+    /// Process the next point of the sweep, and return it, or `None`
+    /// if the sweep is done.
+    fn step(&mut self) -> Option<SweepPoint<T>> {
+        let pt = self.sweep.next()?;
         fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Ordering {
             a.at_left.cmp(&b.at_left).reverse().then_with(|| {
                 if !a.at_left {
@@ -35,22 +474,29 @@ pub fn assemble<T: Float>(rings: Vec<Ring<T>>) -> Vec<Polygon<T>> {
                 }
             })
         }
-        sweep.intersections_mut().sort_by(compare_crossings);
+        self.sweep.intersections_mut().sort_by(compare_crossings);
 
         let mut below = {
-            let first = sweep.intersections().first().unwrap();
+            let first = self.sweep.intersections().first().unwrap();
             if !first.at_left {
-                continue;
+                return Some(pt.into());
             }
-            let active = sweep.prev_active(first);
+            let active = self.sweep.prev_active(first);
             trace!(
                 "active of {first_geom:?}: {active:?}",
                 first_geom = first.cross.geom
             );
-            active.map(|(_, b)| b.region.get()).unwrap_or(None)
+            active.map_or(Region::Outside(None), |(_, b)| b.region.get())
         };
         trace!("pt: {pt:?}\n\tbelow: {below:?}");
 
+        let Nesting {
+            rings,
+            parents,
+            holes,
+            enclosing,
+            sweep,
+        } = self;
         sweep.intersections().iter().position(|edge| {
             if !edge.at_left {
                 return true;
@@ -59,19 +505,22 @@ pub fn assemble<T: Float>(rings: Vec<Ring<T>>) -> Vec<Polygon<T>> {
             let ring_idx = edge.cross.ring_idx;
             let ring = &rings[ring_idx];
             below = match below {
-                Some(ext_idx) => {
+                Region::Inside(ext_idx) => {
                     if ring.is_hole() {
                         parents[ring_idx] = ext_idx;
+                        holes[ext_idx].push(ring_idx);
+                        Region::Outside(Some(ring_idx))
                     } else {
                         debug_assert_eq!(ring_idx, ext_idx, "matching idx");
+                        Region::Outside(enclosing[ring_idx])
                     }
-                    None
                 }
-                None => {
+                Region::Outside(hole_idx) => {
                     if ring.is_hole() {
-                        Some(parents[ring_idx])
+                        Region::Inside(parents[ring_idx])
                     } else {
-                        Some(ring_idx)
+                        enclosing[ring_idx] = hole_idx;
+                        Region::Inside(ring_idx)
                     }
                 }
             };
@@ -80,32 +529,52 @@ pub fn assemble<T: Float>(rings: Vec<Ring<T>>) -> Vec<Polygon<T>> {
 
             false
         });
+        Some(pt.into())
     }
 
-    let mut polygons = vec![None; rings.len()];
-    rings.iter().enumerate().for_each(|(idx, r)| {
-        if r.is_hole() {
-            let p_idx = parents[idx];
-            if polygons[p_idx].is_none() {
-                polygons[p_idx] = Some(Polygon::new(rings[p_idx].coords().clone(), vec![]));
+    /// The depth of each exterior in the containment forest.
+    fn depths(&self) -> Vec<usize> {
+        let mut depths = vec![None; self.rings.len()];
+        let mut chain = vec![];
+        for idx in 0..self.rings.len() {
+            if self.rings[idx].is_hole() {
+                continue;
+            }
+            // Walk up to an exterior of known depth, or a root.
+            let mut ext_idx = idx;
+            let mut depth = loop {
+                if let Some(depth) = depths[ext_idx] {
+                    break depth + 1;
+                }
+                chain.push(ext_idx);
+                match self.enclosing[ext_idx] {
+                    Some(hole_idx) => ext_idx = self.parents[hole_idx],
+                    None => break 0,
+                }
+            };
+            for ext_idx in chain.drain(..).rev() {
+                depths[ext_idx] = Some(depth);
+                depth += 1;
             }
-            polygons[p_idx]
-                .as_mut()
-                .unwrap()
-                .interiors_push(r.coords().clone());
-        } else if polygons[idx].is_none() {
-            polygons[idx] = Some(Polygon::new(rings[idx].coords().clone(), vec![]));
         }
-    });
+        depths.into_iter().map(|d| d.unwrap_or(0)).collect()
+    }
+}
 
-    polygons.into_iter().flatten().collect()
+/// The region above an edge of the sweep.
+#[derive(Debug, Clone, Copy)]
+enum Region {
+    /// Inside the polygon of this exterior.
+    Inside(usize),
+    /// Outside all polygons: in this hole, or unbounded.
+    Outside(Option<usize>),
 }
 
 #[derive(Debug, Clone)]
 struct Edge<T: GeoFloat> {
     geom: LineOrPoint<T>,
     ring_idx: usize,
-    region: Cell<Option<usize>>,
+    region: Cell<Region>,
 }
 
 impl<T: GeoFloat> Cross for Edge<T> {
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use super::{assemble, KeepOrDrop, LowerDim, Ring};
+use crate::sweep::{LineOrPoint, SweepPoint};
+use crate::{GeoFloat, Geometry, GeometryCollection, LineString, Point};
+
+/// Combine the areal and the lower-dimensional parts of an intersection
+/// into a `GeometryCollection`: first the polygons, then the line
+/// strings, and finally the points.
+pub(super) fn collect_full<T: GeoFloat>(
+    rings: Vec<Ring<T>>,
+    lower: LowerDim<T>,
+) -> GeometryCollection<T> {
+    let polygons = assemble(rings, KeepOrDrop::Keep)
+        .into_iter()
+        .map(Geometry::Polygon);
+    let lines = merge_lines(lower.lines)
+        .into_iter()
+        .map(Geometry::LineString);
+    let points = lower.points.into_iter().map(|c| Geometry::Point(Point(c)));
+    GeometryCollection::new_from(polygons.chain(lines).chain(points).collect())
+}
+
+/// Join noded segments into maximal line strings.
+///
+/// Segments are chained through end points shared by exactly two
+/// segments. Closed chains are returned as closed line strings.
+fn merge_lines<T: GeoFloat>(segments: Vec<LineOrPoint<T>>) -> Vec<LineString<T>> {
+    let mut incident: BTreeMap<SweepPoint<T>, Vec<usize>> = BTreeMap::new();
+    for (idx, seg) in segments.iter().enumerate() {
+        incident.entry(seg.left()).or_default().push(idx);
+        incident.entry(seg.right()).or_default().push(idx);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut output = vec![];
+    let mut walk = |start: SweepPoint<T>, first: usize, used: &mut Vec<bool>| {
+        let mut coords = vec![*start];
+        let (mut pt, mut idx) = (start, first);
+        loop {
+            used[idx] = true;
+            let seg = segments[idx];
+            pt = if seg.left() == pt {
+                seg.right()
+            } else {
+                seg.left()
+            };
+            coords.push(*pt);
+            let next = match incident[&pt].as_slice() {
+                [a, b] => {
+                    if *a == idx {
+                        *b
+                    } else {
+                        *a
+                    }
+                }
+                _ => break,
+            };
+            if used[next] {
+                break;
+            }
+            idx = next;
+        }
+        output.push(LineString::new(coords));
+    };
+
+    // Open chains start at points not shared by exactly two segments.
+    for (pt, segs) in incident.iter() {
+        if segs.len() == 2 {
+            continue;
+        }
+        for &idx in segs {
+            if !used[idx] {
+                walk(*pt, idx, &mut used);
+            }
+        }
+    }
+    // The remaining segments form closed chains.
+    for idx in 0..segments.len() {
+        if !used[idx] {
+            walk(segments[idx].left(), idx, &mut used);
+        }
+    }
+    output
+}
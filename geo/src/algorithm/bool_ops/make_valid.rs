@@ -0,0 +1,78 @@
+use std::borrow::Borrow;
+
+use super::{assemble, KeepOrDrop, Op, OpType};
+use crate::{CoordsIter, GeoFloat, MultiPolygon, Polygon};
+
+/// Repair polygons by sweeping them on their own.
+///
+/// The output is the union of the input with nothing: the boundaries
+/// are noded where they cross, and the regions covered an odd number of
+/// times are kept. This removes degenerate parts, and fixes invalid
+/// polygons as long as the interiors of the holes are contained within
+/// their exteriors; see [`BooleanOps`](super::BooleanOps#validity).
+///
+/// [`into_make_valid`](MakeValid::into_make_valid) consumes the input,
+/// and drops each polygon as soon as its edges are added to the sweep,
+/// instead of keeping the whole input alive while the sweep runs. Both
+/// methods give the same output.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, MakeValid, MultiPolygon};
+///
+/// // A bow-tie.
+/// let bowtie: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)].into();
+///
+/// let valid = bowtie.make_valid();
+/// assert_eq!(valid.0.len(), 2);
+/// assert_eq!(valid.unsigned_area(), 2.);
+/// assert_eq!(bowtie.into_make_valid(), valid);
+/// ```
+pub trait MakeValid: Sized {
+    type Scalar: GeoFloat;
+
+    fn make_valid(&self) -> MultiPolygon<Self::Scalar>;
+
+    fn into_make_valid(self) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: GeoFloat> MakeValid for Polygon<T> {
+    type Scalar = T;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        sweep_valid(std::iter::once(self), self.coords_count())
+    }
+
+    fn into_make_valid(self) -> MultiPolygon<T> {
+        let capacity = self.coords_count();
+        sweep_valid(std::iter::once(self), capacity)
+    }
+}
+
+impl<T: GeoFloat> MakeValid for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        sweep_valid(&self.0, self.coords_count())
+    }
+
+    fn into_make_valid(self) -> MultiPolygon<T> {
+        let capacity = self.coords_count();
+        sweep_valid(self.0, capacity)
+    }
+}
+
+/// Sweep the polygons on their own, adding each one to the sweep, and
+/// dropping it if owned, before taking the next.
+fn sweep_valid<T: GeoFloat, P: Borrow<Polygon<T>>>(
+    polygons: impl IntoIterator<Item = P>,
+    capacity: usize,
+) -> MultiPolygon<T> {
+    let mut bop = Op::new(OpType::Union, capacity);
+    for poly in polygons {
+        bop.add_polygon(poly.borrow(), true);
+    }
+    assemble(bop.sweep(), KeepOrDrop::Keep).into()
+}
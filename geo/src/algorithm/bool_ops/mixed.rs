@@ -0,0 +1,56 @@
+use super::{BooleanOps, OpType};
+use crate::{Convert, InvalidConversionError, MultiPolygon, Polygon, ToF32Validated};
+
+/// Boolean operations on `f32` geometries, computed in `f64`.
+///
+/// The inputs are converted exactly to `f64`, and the operation is run
+/// in `f64`, as by [`BooleanOps`]. The output is then rounded back to
+/// `f32`, and checked by [`ToF32Validated`]: rounding may merge nearby
+/// vertices of the output, or move them across nearby edges, in which
+/// case an error is returned instead.
+///
+/// This keeps the storage of the geometries in `f32`, with the
+/// robustness of the sweep in `f64`: the intersections of nearly
+/// parallel edges of `f32` inputs are often not representable closely
+/// enough in `f32` for the sweep to order the edges consistently.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, BooleanOpsMixedPrecision, OpType};
+///
+/// let a = polygon![(x: 0f32, y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 1f32, y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+///
+/// let union = a.boolean_op_f32_accumulate_f64(&b, OpType::Union).unwrap();
+/// assert_eq!(union.unsigned_area(), 7.);
+/// ```
+pub trait BooleanOpsMixedPrecision {
+    fn boolean_op_f32_accumulate_f64(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<f32>, InvalidConversionError>;
+}
+
+impl BooleanOpsMixedPrecision for Polygon<f32> {
+    fn boolean_op_f32_accumulate_f64(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<f32>, InvalidConversionError> {
+        let (a, b): (Polygon<f64>, Polygon<f64>) = (self.convert(), other.convert());
+        a.boolean_op(&b, op).to_f32_validated()
+    }
+}
+
+impl BooleanOpsMixedPrecision for MultiPolygon<f32> {
+    fn boolean_op_f32_accumulate_f64(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<f32>, InvalidConversionError> {
+        let (a, b): (MultiPolygon<f64>, MultiPolygon<f64>) = (self.convert(), other.convert());
+        a.boolean_op(&b, op).to_f32_validated()
+    }
+}
@@ -1,6 +1,11 @@
+use std::{error, fmt, str::FromStr};
+
 use geo_types::MultiPolygon;
 
-use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
+use crate::{
+    sweep::{Error, SweepStats},
+    CoordsIter, GeoFloat, GeoNum, GeometryCollection, Polygon,
+};
 
 /// Boolean Operations on geometry.
 ///
@@ -25,6 +30,32 @@ pub trait BooleanOps: Sized {
     type Scalar: GeoNum;
 
     fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar>;
+
+    /// The boolean operation `op`, like [`boolean_op`](BooleanOps::boolean_op),
+    /// but returning an error instead of panicking on a non-finite
+    /// coordinate.
+    ///
+    /// An empty result, e.g. the intersection of disjoint or empty
+    /// operands, is an empty `MultiPolygon` and not an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, BooleanOps, MultiPolygon, OpType};
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// let b = polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)];
+    /// assert_eq!(
+    ///     a.try_boolean_op(&b, OpType::Intersection),
+    ///     Ok(MultiPolygon::new(vec![]))
+    /// );
+    ///
+    /// let nan = polygon![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 0., y: 1.)];
+    /// assert!(a.try_boolean_op(&nan, OpType::Intersection).is_err());
+    /// ```
+    fn try_boolean_op(&self, other: &Self, op: OpType)
+        -> Result<MultiPolygon<Self::Scalar>, Error>;
+
     fn intersection(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
         self.boolean_op(other, OpType::Intersection)
     }
@@ -37,8 +68,104 @@ pub trait BooleanOps: Sized {
     fn difference(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
         self.boolean_op(other, OpType::Difference)
     }
+
+    /// The complete set-theoretic intersection, including its
+    /// lower-dimensional parts.
+    ///
+    /// [`intersection`](BooleanOps::intersection) only reports the
+    /// areal part of the output. However, the boundaries of the inputs
+    /// may also overlap along edges, or touch at points, without any
+    /// common area there. This returns a `GeometryCollection` of the
+    /// areal output as `Polygon`s, followed by the shared boundary
+    /// pieces as `LineString`s, and the isolated touching points as
+    /// `Point`s, as in the OGC semantics of the intersection.
+    ///
+    /// Each part of the intersection is reported once: the boundary
+    /// pieces are merged into maximal line strings that do not run along
+    /// the polygons, and the points are not on any polygon or line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, BooleanOps, Geometry, line_string};
+    ///
+    /// // Two squares sharing the edge x = 1.
+    /// let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// let b = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)];
+    ///
+    /// assert!(a.intersection(&b).0.is_empty());
+    /// let full = a.intersection_full(&b);
+    /// assert_eq!(
+    ///     full.0,
+    ///     vec![Geometry::LineString(line_string![(x: 1., y: 0.), (x: 1., y: 1.)])]
+    /// );
+    /// ```
+    fn intersection_full(&self, other: &Self) -> GeometryCollection<Self::Scalar>;
+
+    /// The area of the intersection, without computing the intersection.
+    ///
+    /// This is equal to the area of [`intersection`](BooleanOps::intersection),
+    /// but is computed directly from the boundary found by the sweep,
+    /// without allocating the output polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Area, BooleanOps};
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let b = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+    ///
+    /// assert_eq!(a.overlap_area(&b), 1.);
+    /// assert_eq!(a.overlap_area(&b), a.intersection(&b).unsigned_area());
+    /// ```
+    fn overlap_area(&self, other: &Self) -> Self::Scalar;
+
+    /// Split `self` by `clip`: the parts of `self` inside and outside of
+    /// `clip`.
+    ///
+    /// These are the [`intersection`](BooleanOps::intersection) and the
+    /// [`difference`](BooleanOps::difference) of `self` and `clip`, both
+    /// assembled from a single sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Area, BooleanOps};
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+    /// let clip = polygon![(x: 1., y: -1.), (x: 2., y: -1.), (x: 2., y: 3.), (x: 1., y: 3.)];
+    ///
+    /// let (inside, outside) = a.split_by(&clip);
+    /// assert_eq!(inside.unsigned_area(), 2.);
+    /// assert_eq!(outside.0.len(), 2);
+    /// assert_eq!(outside.unsigned_area(), 6.);
+    /// ```
+    fn split_by(&self, clip: &Self) -> (MultiPolygon<Self::Scalar>, MultiPolygon<Self::Scalar>);
 }
 
+/// The kind of a boolean operation.
+///
+/// Intersection, union and xor are commutative: swapping the operands
+/// gives the same region, though the order of the output polygons, and
+/// the starting vertices of their rings, may differ. Difference is not:
+/// the first operand is the one the second is subtracted from.
+///
+/// The operations are displayed, and parsed (ignoring ASCII case), by
+/// their lower-case names: `"intersection"`, `"union"`, `"difference"`
+/// and `"xor"`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::OpType;
+///
+/// let names: Vec<_> = OpType::all().iter().map(|ty| ty.to_string()).collect();
+/// assert_eq!(names, ["intersection", "union", "difference", "xor"]);
+///
+/// assert_eq!("Union".parse::<OpType>(), Ok(OpType::Union));
+/// assert!("merge".parse::<OpType>().is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OpType {
     Intersection,
@@ -47,6 +174,148 @@ pub enum OpType {
     Xor,
 }
 
+impl OpType {
+    /// All the operations, in declaration order.
+    pub fn all() -> &'static [OpType] {
+        &[
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ]
+    }
+
+    /// Whether swapping the operands gives the same region.
+    ///
+    /// ```
+    /// use geo::OpType;
+    ///
+    /// assert!(OpType::Union.is_commutative());
+    /// assert!(!OpType::Difference.is_commutative());
+    /// ```
+    pub fn is_commutative(&self) -> bool {
+        !matches!(self, OpType::Difference)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            OpType::Intersection => "intersection",
+            OpType::Union => "union",
+            OpType::Difference => "difference",
+            OpType::Xor => "xor",
+        }
+    }
+}
+
+impl fmt::Display for OpType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OpType {
+    type Err = ParseOpTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OpType::all()
+            .iter()
+            .find(|ty| ty.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| ParseOpTypeError(s.to_string()))
+    }
+}
+
+/// The error returned when parsing an unknown [`OpType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOpTypeError(String);
+
+impl fmt::Display for ParseOpTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown boolean operation: {:?}", self.0)
+    }
+}
+
+impl error::Error for ParseOpTypeError {}
+
+/// How [`Op`] rounds the coordinates of the intersections of the
+/// boundaries of its inputs, set by [`Op::with_rounding_mode`].
+///
+/// The exact intersection of two edges is usually not representable, so
+/// the output depends on how it is rounded. With `TowardInterior`, each
+/// intersection point in the interior of two edges is moved inside the
+/// region of the inputs that is to be kept, so that the output is
+/// contained in the exact one; with `TowardExterior`, outside of it, so
+/// that the output contains the exact one. That is, the inputs are
+/// shrunk, or grown, by less than a few units in the last place, and
+/// for a difference the second input the other way round.
+///
+/// The guarantee holds for [`OpType::Intersection`],
+/// [`OpType::Union`] and [`OpType::Difference`], for the output of the
+/// type of the operation, as long as the crossing edges are not so
+/// close to parallel that no coordinate is found strictly on the
+/// required sides of both within a few units in the last place; those
+/// intersections are rounded to the nearest. A xor is not monotonic in
+/// its inputs, and its intersections are always rounded to the nearest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest coordinates, the default.
+    Nearest,
+    /// Round so that the output is contained in the exact output.
+    TowardInterior,
+    /// Round so that the output contains the exact output.
+    TowardExterior,
+}
+
+/// The role of the polygons added to an [`Op`].
+///
+/// The edges of every operand are noded against each other, but only
+/// those of the `Subject` and the `Clip` bound the regions tested by the
+/// [`OpType`]. The edges of a `Reference` only split the other edges
+/// where they cross: they add vertices to the output, without changing
+/// the region it covers.
+///
+/// A `bool` converts to the first operand if `true`, and the second
+/// otherwise, as accepted by the `add_*` methods of `Op`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{assemble, KeepOrDrop, Op, OpType, Operand};
+/// use geo::{polygon, CoordsIter, MultiPolygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// // A triangle with an edge crossing the bottom and the right of the square.
+/// let reference = polygon![(x: 0., y: -1.), (x: 3., y: 2.), (x: 3., y: -1.)];
+///
+/// let mut op = Op::new(OpType::Union, 8);
+/// op.add_polygon(&square, Operand::Subject);
+/// op.add_polygon(&reference, Operand::Reference);
+/// let union = MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep));
+///
+/// // The same square, with a vertex at each crossing.
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union.0[0].exterior().coords_count(), 4 + 2 + 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    /// The first operand.
+    Subject,
+    /// The second operand.
+    Clip,
+    /// Edges used for noding only.
+    Reference,
+}
+
+impl From<bool> for Operand {
+    fn from(is_first: bool) -> Self {
+        if is_first {
+            Operand::Subject
+        } else {
+            Operand::Clip
+        }
+    }
+}
+
 impl<T: GeoFloat> BooleanOps for Polygon<T> {
     type Scalar = T;
 
@@ -55,7 +324,50 @@ impl<T: GeoFloat> BooleanOps for Polygon<T> {
         bop.add_polygon(self, true);
         bop.add_polygon(other, false);
         let rings = bop.sweep();
-        assemble(rings).into()
+        assemble(rings, KeepOrDrop::Keep).into()
+    }
+
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<Self::Scalar>, Error> {
+        let mut bop = Op::new(op, self.coords_count() + other.coords_count());
+        bop.try_add_polygon(self, true)?;
+        bop.try_add_polygon(other, false)?;
+        let rings = bop.try_sweep()?;
+        Ok(assemble(rings, KeepOrDrop::Keep).into())
+    }
+
+    fn intersection_full(&self, other: &Self) -> GeometryCollection<Self::Scalar> {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + other.coords_count(),
+        );
+        bop.add_polygon(self, true);
+        bop.add_polygon(other, false);
+        let (rings, lower) = bop.sweep_with_lower_dim();
+        collect_full(rings, lower)
+    }
+
+    fn overlap_area(&self, other: &Self) -> Self::Scalar {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + other.coords_count(),
+        );
+        bop.add_polygon(self, true);
+        bop.add_polygon(other, false);
+        bop.area_as(OpType::Intersection)
+    }
+
+    fn split_by(&self, clip: &Self) -> (MultiPolygon<Self::Scalar>, MultiPolygon<Self::Scalar>) {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + clip.coords_count(),
+        );
+        bop.add_polygon(self, true);
+        bop.add_polygon(clip, false);
+        split(&bop)
     }
 }
 impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
@@ -66,18 +378,147 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
         bop.add_multi_polygon(self, true);
         bop.add_multi_polygon(other, false);
         let rings = bop.sweep();
-        assemble(rings).into()
+        assemble(rings, KeepOrDrop::Keep).into()
+    }
+
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<Self::Scalar>, Error> {
+        let mut bop = Op::new(op, self.coords_count() + other.coords_count());
+        bop.try_add_multi_polygon(self, true)?;
+        bop.try_add_multi_polygon(other, false)?;
+        let rings = bop.try_sweep()?;
+        Ok(assemble(rings, KeepOrDrop::Keep).into())
+    }
+
+    fn intersection_full(&self, other: &Self) -> GeometryCollection<Self::Scalar> {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + other.coords_count(),
+        );
+        bop.add_multi_polygon(self, true);
+        bop.add_multi_polygon(other, false);
+        let (rings, lower) = bop.sweep_with_lower_dim();
+        collect_full(rings, lower)
+    }
+
+    fn overlap_area(&self, other: &Self) -> Self::Scalar {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + other.coords_count(),
+        );
+        bop.add_multi_polygon(self, true);
+        bop.add_multi_polygon(other, false);
+        bop.area_as(OpType::Intersection)
     }
+
+    fn split_by(&self, clip: &Self) -> (MultiPolygon<Self::Scalar>, MultiPolygon<Self::Scalar>) {
+        let mut bop = Op::new(
+            OpType::Intersection,
+            self.coords_count() + clip.coords_count(),
+        );
+        bop.add_multi_polygon(self, true);
+        bop.add_multi_polygon(clip, false);
+        split(&bop)
+    }
+}
+
+/// The boolean operation `ty` of `a` and `b`, as
+/// [`BooleanOps::boolean_op`], and the counters of its sweep.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::boolean_op_with_statistics;
+/// use geo::{polygon, MultiPolygon, OpType};
+///
+/// let a: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+/// let b: MultiPolygon<f64> =
+///     polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)].into();
+///
+/// let (union, stats) = boolean_op_with_statistics(&a, &b, OpType::Union);
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(stats.input_segments, 8);
+/// assert!(stats.intersections > 0);
+/// ```
+pub fn boolean_op_with_statistics<T: GeoFloat>(
+    a: &MultiPolygon<T>,
+    b: &MultiPolygon<T>,
+    ty: OpType,
+) -> (MultiPolygon<T>, SweepStats) {
+    let mut bop = Op::new(ty, a.coords_count() + b.coords_count());
+    bop.add_multi_polygon(a, true);
+    bop.add_multi_polygon(b, false);
+    let rings = bop.sweep();
+    (assemble(rings, KeepOrDrop::Keep).into(), bop.statistics())
+}
+
+/// The intersection and the difference of the inputs of `bop`, from
+/// the same sweep.
+fn split<T: GeoFloat>(bop: &Op<T>) -> (MultiPolygon<T>, MultiPolygon<T>) {
+    let inside = assemble(bop.assemble_as(OpType::Intersection), KeepOrDrop::Keep);
+    let outside = assemble(bop.assemble_as(OpType::Difference), KeepOrDrop::Keep);
+    (inside.into(), outside.into())
 }
 
 mod op;
-use op::*;
+use op::LowerDim;
+// `Op` and `Ring` are the internals of the boolean operations, used by
+// the low-level entry points of this module. They are unstable, and
+// hidden from the documentation: their statistics are exposed by
+// `boolean_op_with_statistics`.
+#[doc(hidden)]
+pub use op::Op;
 
 mod rings;
-use rings::{Ring, Rings};
+#[doc(hidden)]
+pub use rings::Ring;
+use rings::Rings;
 
 mod laminar;
-use laminar::*;
+pub use laminar::{
+    assemble, assemble_iter, assemble_ordered, assemble_pinched, pinch_vertices, AssembleIter,
+    DrawOrder, KeepOrDrop, PinchPolicy,
+};
+
+mod depth;
+pub use depth::{unary_intersection, unary_union};
+
+mod buffered;
+pub use buffered::{difference_buffered, BufferJoin, BufferVariable};
+
+mod erase;
+pub use erase::{difference_all, Erase};
+
+mod grid;
+pub use grid::{CellOutOfRange, GridClip};
+
+mod make_valid;
+pub use make_valid::MakeValid;
+
+mod similarity;
+pub use similarity::AreaSimilarity;
+
+mod venn;
+pub use venn::{venn, VennRegions};
+
+mod collection;
+pub use collection::collection_op;
+
+mod gap;
+pub use gap::UnionGapClosing;
+
+mod mixed;
+pub use mixed::BooleanOpsMixedPrecision;
+
+mod overlay_edges;
+pub use overlay_edges::{overlay_edges, FaceLabel};
+
+mod lower_dim;
+use lower_dim::collect_full;
 
 #[cfg(test)]
 mod tests;
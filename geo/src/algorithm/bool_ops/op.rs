@@ -1,88 +1,666 @@
-use std::{cell::Cell, cmp::Ordering, fmt::Debug};
+use std::{
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    collections::{BTreeSet, HashSet},
+    fmt::{Debug, Display},
+    io::{self, Write},
+};
 
 use super::*;
 use crate::{
-    sweep::{Cross, Crossing, CrossingsIter, LineOrPoint},
-    winding_order::WindingOrder,
-    CoordsIter, GeoFloat as Float, LineString, Polygon,
+    sweep::{
+        Cross, Crossing, CrossingsIter, Error, LineOrPoint, SweepPoint, SweepStats, TraceEvent,
+    },
+    winding_order::{Winding, WindingOrder},
+    Coordinate, CoordsIter, GeoFloat as Float, HasNonFinite, HashableCoord, Line, LineString,
+    MultiLineString, Orientation, Polygon, Rect, SnapToGrid,
 };
 
+/// A boolean operation between two sets of polygons.
+///
+/// This is the lower-level interface behind [`BooleanOps`]: add the
+/// polygons of both operands, and [`sweep`](Op::sweep) to compute the
+/// rings of the output.
+///
+/// The sweep itself does not depend on the [`OpType`]: it is only used
+/// to select the output edges at the end. The pieces of the boundary
+/// found by the last sweep are cached, and
+/// [`assemble_as`](Op::assemble_as) re-uses them to compute the rings
+/// of other operations on the same inputs.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{Op, OpType};
+/// use geo::polygon;
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+///
+/// let mut op = Op::new(OpType::Union, 8);
+/// op.add_polygon(&a, true);
+/// op.add_polygon(&b, false);
+/// let rings = op.sweep();
+/// assert_eq!(rings.len(), 1);
+///
+/// let stats = op.statistics();
+/// assert_eq!(stats.input_segments, 8);
+/// assert!(stats.intersections > 0);
+///
+/// // Re-use the sweep for the intersection.
+/// let rings = op.assemble_as(OpType::Intersection);
+/// assert_eq!(rings.len(), 1);
+/// ```
 #[derive(Debug, Clone)]
 pub struct Op<T: Float> {
     ty: OpType,
     edges: Vec<Edge<T>>,
+    segment_capacity: usize,
+    decimals: Option<u32>,
+    max_active: usize,
+    region: Option<Rect<T>>,
+    noded: bool,
+    normalize: bool,
+    /// The canonical polygons added so far, if deduplicating the inputs.
+    seen_polygons: Option<HashSet<PolygonKey<T>>>,
+    rounding: RoundingMode,
+    trace_len: Option<usize>,
+    stats: Cell<SweepStats>,
+    trace: RefCell<Vec<TraceEvent<T>>>,
+    swept: RefCell<Option<Swept<T>>>,
 }
 
 impl<T: Float> Op<T> {
+    /// Create an empty operation, reserving space for `capacity` edges.
+    ///
+    /// The event queue of the sweep is only built by [`sweep`](Op::sweep),
+    /// and sized from the edges added by then; see
+    /// [`with_capacity`](Op::with_capacity) to size it for more.
     pub fn new(ty: OpType, capacity: usize) -> Self {
         Op {
             ty,
             edges: Vec::with_capacity(capacity),
+            segment_capacity: 0,
+            decimals: None,
+            max_active: usize::MAX,
+            region: None,
+            noded: false,
+            normalize: false,
+            seen_polygons: None,
+            rounding: RoundingMode::Nearest,
+            trace_len: None,
+            stats: Default::default(),
+            trace: Default::default(),
+            swept: Default::default(),
         }
     }
 
-    // is_first -> whether it is from first input or second input
-    pub(crate) fn add_multi_polygon(&mut self, mp: &MultiPolygon<T>, is_first: bool) {
-        mp.0.iter().for_each(|p| self.add_polygon(p, is_first));
+    /// Size the event queue of the sweep for `segment_count` segments,
+    /// two events for each, and reserve space for as many edges.
+    ///
+    /// Every segment split at an intersection adds events to the queue
+    /// while sweeping, so a hint above the number of edges, counting the
+    /// expected splits, also saves the reallocations of the queue during
+    /// the sweep. The output does not depend on the hint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::{polygon, MultiPolygon};
+    ///
+    /// let a: MultiPolygon<f64> =
+    ///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+    /// let b: MultiPolygon<f64> =
+    ///     polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)].into();
+    ///
+    /// let mut op = Op::new(OpType::Union, 0).with_capacity(16);
+    /// op.add_multi_polygon(&a, true);
+    /// op.add_multi_polygon(&b, false);
+    /// assert_eq!(op.sweep().len(), 1);
+    /// ```
+    pub fn with_capacity(mut self, segment_count: usize) -> Self {
+        self.edges
+            .reserve(segment_count.saturating_sub(self.edges.len()));
+        self.segment_capacity = segment_count;
+        self
+    }
+
+    /// Limit the number of segments simultaneously active in the sweep
+    /// to `max_active`.
+    ///
+    /// Degenerate inputs may make the active set, and so the memory
+    /// used by the sweep, grow large. Once the limit is exceeded,
+    /// [`try_sweep`](Op::try_sweep) stops and returns
+    /// [`Error::ActiveSetTooLarge`]; the infallible methods panic.
+    pub fn with_max_active(mut self, max_active: usize) -> Self {
+        self.max_active = max_active;
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Only look for intersections of the boundaries within `region`.
+    ///
+    /// This is a hint, e.g. from an external spatial index, that the
+    /// boundaries of the inputs do not intersect outside `region`, other
+    /// than at their common vertices. The sweep then skips testing the
+    /// segments that could only intersect outside it, which saves work
+    /// on large inputs with a small area of interaction; the number of
+    /// skipped tests is reported as
+    /// [`SweepStats::skipped_checks`]. The output is wrong if the hint is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::{coord, polygon, Rect};
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// let b = polygon![(x: 8., y: 2.), (x: 20., y: 2.), (x: 20., y: 8.), (x: 8., y: 8.)];
+    ///
+    /// let region = Rect::new(coord! { x: 8., y: 2. }, coord! { x: 10., y: 8. });
+    /// let mut op = Op::new(OpType::Union, 8).with_intersection_region(region);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&b, false);
+    /// assert_eq!(op.sweep().len(), 1);
+    /// assert!(op.statistics().skipped_checks > 0);
+    /// ```
+    pub fn with_intersection_region(mut self, region: Rect<T>) -> Self {
+        self.region = Some(region);
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Assume the inputs are already noded, as by
+    /// [`noded_inputs`](Op::noded_inputs): their edges only meet at
+    /// common vertices, or overlap from end to end.
+    ///
+    /// The sweep then skips the intersection tests between adjacent
+    /// edges, other than those that coincide, and goes straight to
+    /// classifying them. This saves work in pipelines that node their
+    /// inputs once and then run many operations on them; the number of
+    /// skipped tests is reported as [`SweepStats::skipped_checks`]. The
+    /// output is wrong if the inputs are not noded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::polygon;
+    ///
+    /// // Two squares sharing an edge.
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let b = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+    ///
+    /// let mut op = Op::new(OpType::Union, 8).assume_noded(true);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&b, false);
+    /// assert_eq!(op.sweep().len(), 1);
+    /// assert_eq!(op.statistics().intersections, 1);
+    /// ```
+    pub fn assume_noded(mut self, noded: bool) -> Self {
+        self.noded = noded;
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Dissolve the overlaps between the polygons of each operand before
+    /// the operation.
+    ///
+    /// The sweep otherwise expects each operand to be a valid
+    /// multi-polygon, and gives wrong results where its parts overlap:
+    /// crossing the boundary of one part does not necessarily enter or
+    /// leave the operand. With `normalize`, the parts of each operand are
+    /// first merged into their union, by a sweep that counts how many of
+    /// them cover each region, as [`unary_union`]. This costs an extra
+    /// sweep over the edges of both operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{assemble, KeepOrDrop, Op, OpType};
+    /// use geo::{polygon, Area, MultiPolygon};
+    ///
+    /// // Two overlapping squares, in the same operand.
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let b = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+    /// let square = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)];
+    ///
+    /// let mut op = Op::new(OpType::Intersection, 12).normalize_operands(true);
+    /// op.add_multi_polygon(&MultiPolygon::new(vec![a, b]), true);
+    /// op.add_polygon(&square, false);
+    /// let output = MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep));
+    /// assert_eq!(output.unsigned_area(), 7.);
+    /// ```
+    pub fn normalize_operands(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Skip the polygons added afterwards that exactly duplicate a
+    /// polygon already added to the same operand.
+    ///
+    /// Duplicate features overlap themselves, which wastes work in the
+    /// sweep, and makes the operand invalid. The polygons are compared
+    /// by hashing the coordinates of their rings, after rotating them to
+    /// start at their smallest coordinate, and orienting them
+    /// counter-clockwise: the same polygon is detected whatever the
+    /// start and orientation of its rings, and the order of its holes.
+    /// A polygon is only skipped if both its exterior and all of its
+    /// holes match, so that polygons sharing an exterior but not their
+    /// holes are all kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::polygon;
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let again = polygon![(x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 0.), (x: 0., y: 2.)];
+    ///
+    /// let mut op = Op::new(OpType::Union, 8).dedup_inputs(true);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&again, true);
+    /// op.sweep();
+    /// assert_eq!(op.statistics().input_segments, 4);
+    /// ```
+    pub fn dedup_inputs(mut self, dedup: bool) -> Self {
+        self.seen_polygons = dedup.then(HashSet::new);
+        self
     }
 
-    // is_first -> whether it is from first input or second input
-    pub(crate) fn add_polygon(&mut self, poly: &Polygon<T>, is_first: bool) {
-        self.add_closed_ring(poly.exterior(), is_first, false);
+    /// Round the intersections of the boundaries of the inputs as by
+    /// `mode`, e.g. so that the output is contained in the exact one.
+    ///
+    /// See [`RoundingMode`] for the guarantees. The outputs of other
+    /// types assembled from the same sweep, e.g. by
+    /// [`assemble_as`](Op::assemble_as), do not have them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{assemble, KeepOrDrop, Op, OpType, RoundingMode};
+    /// use geo::{polygon, Contains};
+    ///
+    /// // Two triangles crossing at coordinates not representable exactly.
+    /// let a = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 3.)];
+    /// let b = polygon![(x: 1., y: 0.1), (x: 3., y: 0.1), (x: 3., y: 2.1)];
+    ///
+    /// let mut op = Op::new(OpType::Intersection, 8).with_rounding_mode(RoundingMode::TowardInterior);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&b, false);
+    /// let inside = assemble(op.sweep(), KeepOrDrop::Keep);
+    ///
+    /// assert_eq!(inside.len(), 1);
+    /// assert!(a.contains(&inside[0]) && b.contains(&inside[0]));
+    /// ```
+    pub fn with_rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Record the last `n` events processed by the sweep, for
+    /// diagnosis.
+    ///
+    /// The events are kept in a buffer of `n` entries, overwriting the
+    /// oldest ones, so that the memory used does not grow with the
+    /// input. After a sweep, including one stopped by an error, the
+    /// buffer is available from [`recorded_events`](Op::recorded_events):
+    /// the last events before an error are usually the ones to look at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::polygon;
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    ///
+    /// let mut op = Op::new(OpType::Union, 4).record_last(3);
+    /// op.add_polygon(&a, true);
+    /// op.sweep();
+    ///
+    /// let events = op.recorded_events();
+    /// assert_eq!(events.len(), 3);
+    /// assert_eq!(events[2].point, (2., 2.).into());
+    /// ```
+    pub fn record_last(mut self, n: usize) -> Self {
+        self.trace_len = Some(n);
+        *self.swept.get_mut() = None;
+        self
+    }
+
+    /// Add the polygons of a `MultiPolygon` to `operand`: a `bool`
+    /// selects the first operand if `true`, and the second otherwise.
+    pub fn add_multi_polygon(&mut self, mp: &MultiPolygon<T>, operand: impl Into<Operand>) {
+        let operand = operand.into();
+        mp.0.iter().for_each(|p| self.add_polygon(p, operand));
+    }
+
+    /// Add the polygons of a `MultiPolygon` like
+    /// [`add_multi_polygon`](Op::add_multi_polygon), from a source with
+    /// the precision of `decimals` decimal places.
+    ///
+    /// All the inputs of the operation, including those added without a
+    /// precision, are snapped to the grid of the coarsest precision
+    /// given before sweeping. Boundaries shared up to that precision
+    /// then coincide, instead of producing slivers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{assemble, KeepOrDrop, Op, OpType};
+    /// use geo::{polygon, MultiPolygon};
+    ///
+    /// let a: MultiPolygon<f64> =
+    ///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into();
+    /// let b: MultiPolygon<f64> = polygon![
+    ///     (x: 1.0000001, y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1.0000001, y: 1.)
+    /// ].into();
+    ///
+    /// let mut op = Op::new(OpType::Union, 8);
+    /// op.add_multi_polygon_with_precision(&a, true, 6);
+    /// op.add_multi_polygon(&b, false);
+    /// assert_eq!(assemble(op.sweep(), KeepOrDrop::Drop).len(), 1);
+    /// ```
+    pub fn add_multi_polygon_with_precision(
+        &mut self,
+        mp: &MultiPolygon<T>,
+        operand: impl Into<Operand>,
+        decimals: u32,
+    ) {
+        self.decimals = Some(self.decimals.map_or(decimals, |d| d.min(decimals)));
+        *self.swept.get_mut() = None;
+        self.add_multi_polygon(mp, operand);
+    }
+
+    /// Add a polygon to `operand`, as
+    /// [`add_multi_polygon`](Op::add_multi_polygon).
+    pub fn add_polygon(&mut self, poly: &Polygon<T>, operand: impl Into<Operand>) {
+        let operand = operand.into();
+        if let Some(seen) = &mut self.seen_polygons {
+            if poly.exterior().0.len() > 3 && !seen.insert(canonical_polygon(poly, operand)) {
+                return;
+            }
+        }
+        self.add_closed_ring(poly.exterior(), operand, false);
         for hole in poly.interiors() {
-            self.add_closed_ring(hole, is_first, true);
+            self.add_closed_ring(hole, operand, true);
         }
     }
-    // is_first -> whether it is from first input or second input
-    // _is_hole is not used rn; remove it once we fully handle fp issues
-    fn add_closed_ring(&mut self, ring: &LineString<T>, is_first: bool, _is_hole: bool) {
-        assert!(ring.is_closed());
-        if ring.coords_count() <= 3 {
-            return;
-        }
+    /// Add the polygons of a `MultiPolygon` like
+    /// [`add_multi_polygon`](Op::add_multi_polygon), but return an
+    /// error instead of panicking on a non-finite coordinate. Nothing is
+    /// added if there is an error.
+    pub fn try_add_multi_polygon(
+        &mut self,
+        mp: &MultiPolygon<T>,
+        operand: impl Into<Operand>,
+    ) -> Result<(), Error> {
+        check_finite(mp)?;
+        self.add_multi_polygon(mp, operand);
+        Ok(())
+    }
+
+    /// Add a polygon like [`add_polygon`](Op::add_polygon), but return an
+    /// error instead of panicking on a non-finite coordinate. Nothing is
+    /// added if there is an error.
+    pub fn try_add_polygon(
+        &mut self,
+        poly: &Polygon<T>,
+        operand: impl Into<Operand>,
+    ) -> Result<(), Error> {
+        check_finite(poly)?;
+        self.add_polygon(poly, operand);
+        Ok(())
+    }
+
+    fn add_closed_ring(&mut self, ring: &LineString<T>, operand: Operand, is_hole: bool) {
+        // Invalidate the result cached by the last sweep.
+        *self.swept.get_mut() = None;
+        push_closed_ring(&mut self.edges, ring, operand, is_hole);
+    }
+
+    /// Counters from the last call to [`sweep`](Op::sweep), or all zeros
+    /// if it has not been called yet.
+    pub fn statistics(&self) -> SweepStats {
+        self.stats.get()
+    }
+
+    /// The events recorded by the last sweep, oldest first, if enabled
+    /// by [`record_last`](Op::record_last).
+    pub fn recorded_events(&self) -> Vec<TraceEvent<T>> {
+        self.trace.borrow().clone()
+    }
 
-        for line in ring.lines() {
-            let lp: LineOrPoint<_> = line.into();
-            if !lp.is_line() {
-                continue;
+    /// Run the sweep, and return the rings bounding the output region.
+    ///
+    /// # Panics
+    ///
+    /// If the limit set by [`with_max_active`](Op::with_max_active) is
+    /// exceeded; use [`try_sweep`](Op::try_sweep) to handle it.
+    pub fn sweep(&self) -> Vec<Ring<T>> {
+        self.try_sweep().expect(MAX_ACTIVE_EXCEEDED)
+    }
+
+    /// Run the sweep like [`sweep`](Op::sweep), but return an error
+    /// if the limit set by [`with_max_active`](Op::with_max_active) is
+    /// exceeded.
+    pub fn try_sweep(&self) -> Result<Vec<Ring<T>>, Error> {
+        self.sweep_inner(None)?;
+        Ok(self.assemble_as(self.ty))
+    }
+
+    /// Run the sweep, and write the output as a WKT `MULTIPOLYGON` to
+    /// `out`.
+    ///
+    /// The rings of the output are first computed as by
+    /// [`try_sweep`](Op::try_sweep), and held in memory. They are then
+    /// assembled lazily into polygons, as by [`assemble_iter`], each
+    /// written as soon as it is complete: this saves building the output
+    /// `MultiPolygon`, not the memory of its rings. An empty output is
+    /// written as `MULTIPOLYGON EMPTY`. The error of
+    /// [`try_sweep`](Op::try_sweep) is reported as
+    /// [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::polygon;
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// let b = polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)];
+    ///
+    /// let mut op = Op::new(OpType::Union, 8);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&b, false);
+    /// let mut wkt = vec![];
+    /// op.write_wkt(&mut wkt).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(wkt).unwrap(),
+    ///     "MULTIPOLYGON(((1 0,0 0,0 1,1 1,1 0)),((3 0,2 0,2 1,3 1,3 0)))"
+    /// );
+    /// ```
+    pub fn write_wkt<W: Write>(&self, mut out: W) -> io::Result<()>
+    where
+        T: Display,
+    {
+        let mut polygons = assemble_iter(self.try_sweep()?, KeepOrDrop::Keep).peekable();
+        if polygons.peek().is_none() {
+            return out.write_all(b"MULTIPOLYGON EMPTY");
+        }
+        out.write_all(b"MULTIPOLYGON(")?;
+        for (i, poly) in polygons.enumerate() {
+            if i > 0 {
+                out.write_all(b",")?;
+            }
+            out.write_all(b"(")?;
+            let rings = std::iter::once(poly.exterior()).chain(poly.interiors());
+            for (j, ring) in rings.enumerate() {
+                if j > 0 {
+                    out.write_all(b",")?;
+                }
+                out.write_all(b"(")?;
+                for (k, coord) in ring.0.iter().enumerate() {
+                    if k > 0 {
+                        out.write_all(b",")?;
+                    }
+                    write!(out, "{} {}", coord.x, coord.y)?;
+                }
+                out.write_all(b")")?;
             }
+            out.write_all(b")")?;
+        }
+        out.write_all(b")")
+    }
+
+    /// Compute the rings bounding the output region of `ty` on the same
+    /// inputs.
+    ///
+    /// This re-uses the result of the last sweep, and only runs the
+    /// sweep if the inputs changed since (or it has not been run yet).
+    pub fn assemble_as(&self, ty: OpType) -> Vec<Ring<T>> {
+        self.with_swept(|swept| swept.assemble(ty))
+    }
 
-            debug!("processing: {lp:?}");
+    /// Compute the area of the output region of `ty`, directly from the
+    /// boundary found by the sweep, without assembling any rings or
+    /// polygons.
+    ///
+    /// Like [`assemble_as`](Op::assemble_as), this re-uses the result
+    /// of the last sweep if the inputs did not change since.
+    pub fn area_as(&self, ty: OpType) -> T {
+        self.with_swept(|swept| swept.area(ty))
+    }
 
-            let region = Region::infinity(self.ty);
-            self.edges.push(Edge {
-                geom: lp,
-                is_first,
-                _region: region.into(),
-                _region_2: region.into(),
-            });
+    /// The boundaries of both operands as noded by the last sweep: split
+    /// at all their intersections, before the pieces are selected for
+    /// the output.
+    ///
+    /// This is meant for debugging wrong outputs: if the noded inputs
+    /// look right, the problem is in the assembly of the rings, and
+    /// otherwise in the noding. Pieces shared by both operands are in
+    /// both. Like [`assemble_as`](Op::assemble_as), this re-uses the
+    /// result of the last sweep if the inputs did not change since.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::bool_ops::{Op, OpType};
+    /// use geo::polygon;
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let b = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+    ///
+    /// let mut op = Op::new(OpType::Union, 8);
+    /// op.add_polygon(&a, true);
+    /// op.add_polygon(&b, false);
+    ///
+    /// // Each square is split at the two crossings of the boundaries.
+    /// let (a, b) = op.noded_inputs();
+    /// assert_eq!(a.0.len(), 6);
+    /// assert_eq!(b.0.len(), 6);
+    /// ```
+    pub fn noded_inputs(&self) -> (MultiLineString<T>, MultiLineString<T>) {
+        self.with_swept(|swept| {
+            let (mut first, mut second) = (vec![], vec![]);
+            for piece in swept.pieces.iter().filter(|piece| piece.line.is_line()) {
+                let line = piece.line.line();
+                if piece.inputs.first {
+                    first.push(LineString::from(line));
+                }
+                if piece.inputs.second {
+                    second.push(LineString::from(line));
+                }
+            }
+            (MultiLineString::new(first), MultiLineString::new(second))
+        })
+    }
+
+    fn with_swept<R>(&self, f: impl FnOnce(&Swept<T>) -> R) -> R {
+        if self.swept.borrow().is_none() {
+            self.sweep_inner(None).expect(MAX_ACTIVE_EXCEEDED);
         }
+        f(self.swept.borrow().as_ref().unwrap())
     }
 
-    pub fn sweep(&self) -> Vec<Ring<T>> {
-        let mut iter = CrossingsIter::from_iter(self.edges.iter());
-        let mut rings = Rings::default();
+    /// Run the sweep, additionally collecting the parts of the output
+    /// that are lower dimensional: the overlapping boundary pieces that
+    /// do not bound the output region, and the isolated points where
+    /// the boundaries of the operands touch.
+    ///
+    /// This is only meaningful for [`OpType::Intersection`].
+    pub(crate) fn sweep_with_lower_dim(&self) -> (Vec<Ring<T>>, LowerDim<T>) {
+        let mut lower = LowerDim::default();
+        self.sweep_inner(Some(&mut lower))
+            .expect(MAX_ACTIVE_EXCEEDED);
+        (self.assemble_as(self.ty), lower)
+    }
+
+    /// Run the sweep, and cache its result.
+    fn sweep_inner(&self, mut lower: Option<&mut LowerDim<T>>) -> Result<(), Error> {
+        let snapped;
+        let edges = match self.decimals {
+            Some(decimals) => {
+                snapped = self.snapped_edges(decimals);
+                &snapped
+            }
+            None => &self.edges,
+        };
+        let normalized;
+        let edges = if self.normalize {
+            normalized = normalized_edges(edges);
+            &normalized
+        } else {
+            edges
+        };
+        let mut iter = CrossingsIter::with_capacity(edges.iter(), self.segment_capacity);
+        iter.set_max_active(self.max_active);
+        if let Some(region) = self.region {
+            iter.set_region(region);
+        }
+        iter.set_noded(self.noded);
+        for edge in edges {
+            edge.rounding_side.set(self.rounding_side(edge));
+        }
+        if let Some(len) = self.trace_len {
+            iter.set_trace_len(len);
+        }
+        let mut swept = Swept {
+            pieces: vec![],
+            steiner: BTreeSet::new(),
+        };
 
         while let Some(pt) = iter.next() {
             trace!(
                 "\n\nSweep point: {pt:?}, {n} intersection segments",
                 n = iter.intersections_mut().len()
             );
-            fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Ordering {
-                a.at_left.cmp(&b.at_left).then_with(|| {
-                    let ord = a.line.partial_cmp(&b.line).unwrap();
-                    if a.at_left {
-                        ord
-                    } else {
-                        ord.reverse()
-                    }
-                })
-            }
             iter.intersections_mut().sort_unstable_by(compare_crossings);
 
+            // Track whether both inputs meet at this point, and whether the
+            // point is already part of the output region or a linear piece.
+            let mut inputs = Inputs::default();
+            iter.intersections()
+                .iter()
+                .for_each(|c| inputs.add(c.cross.operand));
+            let mut covered = false;
+            let is_input_vertex = iter
+                .intersections()
+                .iter()
+                .any(|c| *c.cross.geom.left() == pt || *c.cross.geom.right() == pt);
+            if !is_input_vertex {
+                swept.steiner.insert(pt.into());
+            }
+
             // Process all end-segments.
             let mut idx = 0;
             let mut next_region = None;
+            let mut group = Inputs::default();
             while idx < iter.intersections().len() {
                 let c = &iter.intersections()[idx];
                 // If we hit a start-segment, we are done.
@@ -98,7 +676,8 @@ impl<T: Float> Op<T> {
                         geom = c.line,
                     );
                 }
-                next_region.as_mut().unwrap().cross(cross.is_first);
+                next_region.as_mut().unwrap().cross(cross.operand);
+                group.add(cross.operand);
                 let has_overlap = (idx + 1) < iter.intersections().len()
                     && compare_crossings(c, &iter.intersections()[idx + 1]) == Ordering::Equal;
                 if !has_overlap {
@@ -108,80 +687,382 @@ impl<T: Float> Op<T> {
                         geom = c.line,
                         next_region = next_region.unwrap()
                     );
+                    swept.pieces.push(Piece {
+                        line: c.line,
+                        prev: prev_region,
+                        next: next_region.unwrap(),
+                        inputs: group,
+                    });
+                    let prev_is_ty = prev_region.is_ty(self.ty);
                     let next_is_ty = next_region.unwrap().is_ty(self.ty);
-                    if prev_region.is_ty(self.ty) ^ next_is_ty {
-                        trace!("\tfull_geom: {geom:?}", geom = c.cross.geom);
-                        rings.add_edge(
-                            c.line,
-                            if !next_is_ty {
-                                WindingOrder::CounterClockwise
-                            } else {
-                                WindingOrder::Clockwise
-                            },
-                        )
+                    covered |= prev_is_ty || next_is_ty;
+                    if group.both() && !prev_is_ty && !next_is_ty {
+                        if let Some(lower) = lower.as_deref_mut() {
+                            lower.lines.push(c.line);
+                        }
+                        covered = true;
                     }
                     next_region = None;
+                    group = Inputs::default();
                 }
                 idx += 1;
             }
 
-            if idx >= iter.intersections_mut().len() {
-                continue;
-            }
-            let botmost_start_segment = iter.intersections_mut()[idx].clone();
-            debug_assert!(botmost_start_segment.at_left);
+            if idx < iter.intersections().len() {
+                let botmost_start_segment = iter.intersections_mut()[idx].clone();
+                debug_assert!(botmost_start_segment.at_left);
 
-            trace!(
-                "Bottom most start-edge: {botmost:?}",
-                botmost = botmost_start_segment.line,
-            );
+                trace!(
+                    "Bottom most start-edge: {botmost:?}",
+                    botmost = botmost_start_segment.line,
+                );
 
-            let prev = iter.prev_active(&botmost_start_segment);
-            trace!(
-                "prev-active(bot-most): {prev:?}",
-                prev = prev.map(|(_, p)| p.geom)
-            );
+                let prev = iter.prev_active(&botmost_start_segment);
+                trace!(
+                    "prev-active(bot-most): {prev:?}",
+                    prev = prev.map(|(_, p)| p.geom)
+                );
 
-            let mut region = prev
-                .as_ref()
-                .map(|(g, c)| c.get_region(*g))
-                .unwrap_or_else(|| Region::infinity(self.ty));
-            trace!("bot region: {region:?}");
+                let mut region = prev
+                    .as_ref()
+                    .map(|(g, c)| c.get_region(*g))
+                    .unwrap_or_else(Region::infinity);
+                trace!("bot region: {region:?}");
 
-            while idx < iter.intersections().len() {
-                let mut c = &iter.intersections()[idx];
-                let mut jdx = idx;
-                loop {
-                    region.cross(c.cross.is_first);
-                    let has_overlap = (idx + 1) < iter.intersections().len()
-                        && compare_crossings(c, &iter.intersections()[idx + 1]) == Ordering::Equal;
-                    if !has_overlap {
-                        break;
+                while idx < iter.intersections().len() {
+                    let mut c = &iter.intersections()[idx];
+                    let mut jdx = idx;
+                    let below_is_ty = region.is_ty(self.ty);
+                    let mut group = Inputs::default();
+                    loop {
+                        region.cross(c.cross.operand);
+                        group.add(c.cross.operand);
+                        let has_overlap = (idx + 1) < iter.intersections().len()
+                            && compare_crossings(c, &iter.intersections()[idx + 1])
+                                == Ordering::Equal;
+                        if !has_overlap {
+                            break;
+                        }
+                        idx += 1;
+                        c = &iter.intersections()[idx];
+                    }
+                    covered |= below_is_ty || region.is_ty(self.ty) || group.both();
+                    trace!(
+                        "set_region: {geom:?} / {geom2:?} => {region:?} ({c} counts)",
+                        geom2 = c.cross.geom,
+                        geom = iter.intersections_mut()[jdx].line,
+                        c = idx - jdx + 1,
+                    );
+                    while jdx <= idx {
+                        let gpiece = iter.intersections()[jdx].line;
+                        iter.intersections()[jdx].cross.set_region(region, gpiece);
+                        jdx += 1;
                     }
                     idx += 1;
-                    c = &iter.intersections()[idx];
                 }
-                trace!(
-                    "set_region: {geom:?} / {geom2:?} => {region:?} ({c} counts)",
-                    geom2 = c.cross.geom,
-                    geom = iter.intersections_mut()[jdx].line,
-                    c = idx - jdx + 1,
-                );
-                while jdx <= idx {
-                    let gpiece = iter.intersections()[jdx].line;
-                    iter.intersections()[jdx].cross.set_region(region, gpiece);
-                    jdx += 1;
+            }
+
+            if inputs.both() && !covered {
+                if let Some(lower) = lower.as_deref_mut() {
+                    trace!("touch point: {pt:?}");
+                    lower.points.push(pt);
                 }
-                idx += 1;
             }
         }
 
-        rings.finish()
+        self.stats.set(iter.statistics());
+        self.trace.replace(iter.trace());
+        if let Some(err) = iter.error() {
+            return Err(err);
+        }
+        self.swept.replace(Some(swept));
+        Ok(())
+    }
+
+    /// The side of `edge` toward which its intersections are rounded.
+    fn rounding_side(&self, edge: &Edge<T>) -> Option<Orientation> {
+        // Whether rounding toward the interior of the operand of `edge`
+        // shrinks the output.
+        let shrinks = match self.ty {
+            OpType::Intersection | OpType::Union => true,
+            OpType::Difference => edge.operand == Operand::Subject,
+            OpType::Xor => return None,
+        };
+        if edge.operand == Operand::Reference {
+            return None;
+        }
+        let toward_interior = match self.rounding {
+            RoundingMode::Nearest => return None,
+            RoundingMode::TowardInterior => shrinks,
+            RoundingMode::TowardExterior => !shrinks,
+        };
+        Some(if toward_interior == edge.interior_above {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        })
+    }
+
+    /// The edges snapped to the grid of `decimals` decimal places,
+    /// without those collapsed to a point.
+    fn snapped_edges(&self, decimals: u32) -> Vec<Edge<T>> {
+        let cell_size = T::from(10.).unwrap().powi(-(decimals as i32));
+        self.edges
+            .iter()
+            .filter_map(|edge| {
+                let line = edge.geom.line();
+                let geom: LineOrPoint<_> = Line::new(
+                    line.start.snap_to_grid(cell_size),
+                    line.end.snap_to_grid(cell_size),
+                )
+                .into();
+                geom.is_line()
+                    .then(|| Edge::new(geom, edge.operand, edge.interior_above))
+            })
+            .collect()
+    }
+}
+
+/// Push the edges of a closed ring of a polygon of `operand`.
+pub(super) fn push_closed_ring<T: Float, R: Copy + Default>(
+    edges: &mut Vec<Edge<T, R>>,
+    ring: &LineString<T>,
+    operand: Operand,
+    is_hole: bool,
+) {
+    assert!(ring.is_closed());
+    if ring.coords_count() <= 3 {
+        return;
+    }
+    // The interior of the polygon is on the left of its
+    // counter-clockwise exterior, and of its clockwise holes.
+    let interior_left = match ring.winding_order() {
+        Some(WindingOrder::CounterClockwise) => !is_hole,
+        Some(WindingOrder::Clockwise) => is_hole,
+        None => true,
+    };
+
+    for line in ring.lines() {
+        let lp: LineOrPoint<_> = line.into();
+        if !lp.is_line() {
+            continue;
+        }
+
+        debug!("processing: {lp:?}");
+
+        let interior_above = interior_left == (line.start == *lp.left());
+        edges.push(Edge::new(lp, operand, interior_above));
+    }
+}
+
+/// Order the crossings at a point of the sweep: the segments ending
+/// there first, from the top, and then those starting there, from the
+/// bottom. Overlapping segments compare equal.
+pub(super) fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Ordering {
+    a.at_left.cmp(&b.at_left).then_with(|| {
+        let ord = a.line.partial_cmp(&b.line).unwrap();
+        if a.at_left {
+            ord
+        } else {
+            ord.reverse()
+        }
+    })
+}
+
+/// A polygon of an operand, as compared by [`Op::dedup_inputs`]: its
+/// operand, and the canonical coordinates of its exterior and of its
+/// holes, in order.
+type PolygonKey<T> = (Operand, Vec<HashableCoord<T>>, Vec<Vec<HashableCoord<T>>>);
+
+fn canonical_polygon<T: Float>(poly: &Polygon<T>, operand: Operand) -> PolygonKey<T> {
+    let mut holes: Vec<_> = poly
+        .interiors()
+        .iter()
+        .filter(|hole| hole.0.len() > 3)
+        .map(canonical_ring)
+        .collect();
+    // Holes in any order are the same polygon.
+    holes.sort_by(|h1, h2| {
+        let h2 = h2.iter().map(|&c| SweepPoint::from(c));
+        h1.iter().map(|&c| SweepPoint::from(c)).cmp(h2)
+    });
+    let hashable = |ring: Vec<Coordinate<T>>| {
+        ring.into_iter()
+            .map(|coord| HashableCoord::new(coord, T::zero()))
+            .collect()
+    };
+    (
+        operand,
+        hashable(canonical_ring(poly.exterior())),
+        holes.into_iter().map(hashable).collect(),
+    )
+}
+
+/// The coordinates of a closed ring oriented counter-clockwise, starting
+/// from the smallest one, without repeating it at the end.
+fn canonical_ring<T: Float>(ring: &LineString<T>) -> Vec<Coordinate<T>> {
+    let mut coords = ring.0[..ring.0.len() - 1].to_vec();
+    if ring.winding_order() == Some(WindingOrder::Clockwise) {
+        coords.reverse();
+    }
+    let start = (0..coords.len())
+        .min_by_key(|&idx| SweepPoint::from(coords[idx]))
+        .unwrap_or(0);
+    coords.rotate_left(start);
+    coords
+}
+
+/// The edges of the union of the polygons of each of the subject and
+/// the clip, in place of their possibly overlapping polygons.
+fn normalized_edges<T: Float>(edges: &[Edge<T>]) -> Vec<Edge<T>> {
+    let mut normalized: Vec<_> = edges
+        .iter()
+        .filter(|edge| edge.operand == Operand::Reference)
+        .cloned()
+        .collect();
+    for operand in [Operand::Subject, Operand::Clip] {
+        let depth_edges: Vec<_> = edges
+            .iter()
+            .filter(|edge| edge.operand == operand)
+            .map(|edge| depth::Edge::new(edge.geom, Operand::Subject, edge.interior_above))
+            .collect();
+        let rings = depth::sweep(&depth_edges, |depth| depth[0] > 0);
+        for poly in assemble(rings, KeepOrDrop::Keep) {
+            push_closed_ring(&mut normalized, poly.exterior(), operand, false);
+            for hole in poly.interiors() {
+                push_closed_ring(&mut normalized, hole, operand, true);
+            }
+        }
+    }
+    normalized
+}
+
+const MAX_ACTIVE_EXCEEDED: &str = "active set exceeded the limit of `Op::with_max_active`";
+
+/// Return the first non-finite coordinate as an error.
+fn check_finite<T: Float>(geom: &impl HasNonFinite<T>) -> Result<(), Error> {
+    match geom.first_non_finite() {
+        Some(c) => Err(Error::NonFiniteCoordinate(Coordinate {
+            x: c.x.to_f64().unwrap_or(f64::NAN),
+            y: c.y.to_f64().unwrap_or(f64::NAN),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// A noded piece of the input boundary, with the regions above (`prev`)
+/// and below (`next`) it.
+#[derive(Debug, Clone)]
+struct Piece<T: Float> {
+    line: LineOrPoint<T>,
+    prev: Region,
+    next: Region,
+    /// The inputs whose boundaries contain the piece.
+    inputs: Inputs,
+}
+
+/// The result of a sweep, independent of the [`OpType`].
+#[derive(Debug, Clone)]
+struct Swept<T: Float> {
+    /// The noded boundary pieces, in sweep order.
+    pieces: Vec<Piece<T>>,
+    /// The sweep points that are not vertices of the input.
+    steiner: BTreeSet<SweepPoint<T>>,
+}
+
+impl<T: Float> Swept<T> {
+    /// Chain the pieces that separate the output region of `ty` from
+    /// its complement into rings.
+    fn assemble(&self, ty: OpType) -> Vec<Ring<T>> {
+        let mut rings = Rings::default();
+        for piece in &self.pieces {
+            let prev_is_ty = piece.prev.is_ty(ty);
+            let next_is_ty = piece.next.is_ty(ty);
+            if prev_is_ty ^ next_is_ty {
+                trace!("\tadd_edge: {geom:?}", geom = piece.line);
+                rings.add_edge(
+                    piece.line,
+                    if !next_is_ty {
+                        WindingOrder::CounterClockwise
+                    } else {
+                        WindingOrder::Clockwise
+                    },
+                )
+            }
+        }
+        rings.finish(&self.steiner)
+    }
+
+    /// The area of the output region of `ty`: the sum of the shoelace
+    /// terms of its boundary pieces, each oriented with the region on
+    /// its left.
+    fn area(&self, ty: OpType) -> T {
+        let origin = match self.pieces.first() {
+            Some(piece) => *piece.line.left(),
+            None => return T::zero(),
+        };
+        let twice_area = self
+            .pieces
+            .iter()
+            .filter(|piece| piece.prev.is_ty(ty) ^ piece.next.is_ty(ty))
+            .fold(T::zero(), |acc, piece| {
+                let left = *piece.line.left() - origin;
+                let right = *piece.line.right() - origin;
+                let cross = left.x * right.y - right.x * left.y;
+                // Left to right has the region above on its left.
+                if piece.prev.is_ty(ty) {
+                    acc + cross
+                } else {
+                    acc - cross
+                }
+            });
+        twice_area / (T::one() + T::one())
+    }
+}
+
+/// Lower-dimensional parts of the output collected by
+/// [`Op::sweep_with_lower_dim`].
+#[derive(Debug, Clone)]
+pub(crate) struct LowerDim<T: Float> {
+    /// Noded boundary pieces shared by both inputs, that do not bound
+    /// the output region.
+    pub(crate) lines: Vec<LineOrPoint<T>>,
+    /// Points where the boundaries of the inputs touch, and that are
+    /// not part of any other output.
+    pub(crate) points: Vec<Coordinate<T>>,
+}
+
+impl<T: Float> Default for LowerDim<T> {
+    fn default() -> Self {
+        Self {
+            lines: vec![],
+            points: vec![],
+        }
+    }
+}
+
+/// Which of the inputs contributed to a set of segments.
+#[derive(Debug, Default, Clone, Copy)]
+struct Inputs {
+    first: bool,
+    second: bool,
+}
+
+impl Inputs {
+    fn add(&mut self, operand: Operand) {
+        match operand {
+            Operand::Subject => self.first = true,
+            Operand::Clip => self.second = true,
+            Operand::Reference => {}
+        }
+    }
+    fn both(&self) -> bool {
+        self.first && self.second
     }
 }
 
 #[derive(Clone, Copy)]
-struct Region {
+pub(super) struct Region {
     is_first: bool,
     is_second: bool,
 }
@@ -196,39 +1077,75 @@ impl Debug for Region {
     }
 }
 
+impl Default for Region {
+    fn default() -> Self {
+        Region::infinity()
+    }
+}
+
 impl Region {
-    fn infinity(ty: OpType) -> Self {
+    fn infinity() -> Self {
         Region {
             is_first: false,
-            is_second: matches!(ty, OpType::Difference),
+            is_second: false,
         }
     }
-    fn cross(&mut self, first: bool) {
-        if first {
-            self.is_first = !self.is_first;
-        } else {
-            self.is_second = !self.is_second;
+    fn cross(&mut self, operand: Operand) {
+        match operand {
+            Operand::Subject => self.is_first = !self.is_first,
+            Operand::Clip => self.is_second = !self.is_second,
+            Operand::Reference => {}
         }
     }
     fn is_ty(&self, ty: OpType) -> bool {
         match ty {
-            OpType::Intersection | OpType::Difference => self.is_first && self.is_second,
+            OpType::Intersection => self.is_first && self.is_second,
+            OpType::Difference => self.is_first && !self.is_second,
             OpType::Union => self.is_first || self.is_second,
             OpType::Xor => self.is_first ^ self.is_second,
         }
     }
 }
 
+/// An edge of a polygon of `operand`, and the region above it, as
+/// tracked by the sweep: the inputs covering it for an [`Op`], or their
+/// depths for the sweeps of [`depth`].
 #[derive(Clone)]
-struct Edge<T: Float> {
-    geom: LineOrPoint<T>,
-    is_first: bool,
-    _region: Cell<Region>,
-    _region_2: Cell<Region>,
+pub(super) struct Edge<T: Float, R: Copy = Region> {
+    pub(super) geom: LineOrPoint<T>,
+    pub(super) operand: Operand,
+    /// Whether the interior of the polygon is above the edge.
+    pub(super) interior_above: bool,
+    rounding_side: Cell<Option<Orientation>>,
+    _region: Cell<R>,
+    _region_2: Cell<R>,
 }
 
-impl<T: Float> Edge<T> {
-    fn get_region(&self, piece: LineOrPoint<T>) -> Region {
+impl<T: Float, R: Copy + Default> Edge<T, R> {
+    pub(super) fn new(geom: LineOrPoint<T>, operand: Operand, interior_above: bool) -> Self {
+        Edge {
+            geom,
+            operand,
+            interior_above,
+            rounding_side: Cell::new(None),
+            _region: Default::default(),
+            _region_2: Default::default(),
+        }
+    }
+}
+
+impl<T: Float, R: Copy> Edge<T, R> {
+    /// The change in the number of polygons of the operand covering the
+    /// region, when crossing the edge upwards.
+    pub(super) fn winding(&self) -> isize {
+        if self.interior_above {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub(super) fn get_region(&self, piece: LineOrPoint<T>) -> R {
         // Note: This is related to the ordering of intersection
         // with respect to the complete geometry. Due to
         // finite-precision errors, intersection points might lie
@@ -250,7 +1167,7 @@ impl<T: Float> Edge<T> {
             self._region_2.get()
         }
     }
-    fn set_region(&self, region: Region, piece: LineOrPoint<T>) {
+    pub(super) fn set_region(&self, region: R, piece: LineOrPoint<T>) {
         if piece.left() < self.geom.right() {
             self._region.set(region);
         } else {
@@ -260,7 +1177,7 @@ impl<T: Float> Edge<T> {
     }
 }
 
-impl<T: Float> std::fmt::Debug for Edge<T> {
+impl<T: Float, R: Copy + Debug> std::fmt::Debug for Edge<T, R> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let line = self.geom.line();
         f.debug_struct("Edge")
@@ -271,16 +1188,20 @@ impl<T: Float> std::fmt::Debug for Edge<T> {
                     line.start.x, line.start.y, line.end.x, line.end.y
                 ),
             )
-            .field("is_first", &self.is_first)
+            .field("operand", &self.operand)
             .field("region", &self._region)
             .finish()
     }
 }
 
-impl<T: Float> Cross for Edge<T> {
+impl<T: Float, R: Copy + Debug> Cross for Edge<T, R> {
     type Scalar = T;
 
     fn line(&self) -> LineOrPoint<Self::Scalar> {
         self.geom
     }
+
+    fn rounding_side(&self) -> Option<Orientation> {
+        self.rounding_side.get()
+    }
 }
@@ -0,0 +1,87 @@
+use super::depth::{add_polygon, sweep_pieces};
+use super::Operand;
+use crate::{GeoFloat as Float, Line, MultiPolygon};
+
+/// The region of the overlay of two geometries on one side of an edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FaceLabel {
+    /// Outside both geometries.
+    Neither,
+    /// Inside the first geometry only.
+    OnlyA,
+    /// Inside the second geometry only.
+    OnlyB,
+    /// Inside both geometries.
+    Both,
+}
+
+impl FaceLabel {
+    fn new(in_a: bool, in_b: bool) -> Self {
+        match (in_a, in_b) {
+            (false, false) => FaceLabel::Neither,
+            (true, false) => FaceLabel::OnlyA,
+            (false, true) => FaceLabel::OnlyB,
+            (true, true) => FaceLabel::Both,
+        }
+    }
+}
+
+/// The edges of the overlay of `a` and `b`, labeled with the regions on
+/// their left and right.
+///
+/// The boundaries of the inputs are noded at their intersections, and
+/// each piece is reported once, even if it is shared by both inputs. The
+/// edges are oriented from their lexicographically smallest end, as in
+/// the sweep, and are in the order the sweep completes them; the left of
+/// an edge is then the region above it. Pieces with the same region on
+/// both sides are dropped.
+///
+/// Unlike [`assemble`](super::assemble), this does not chain the edges
+/// into rings: it is the raw material of a planar graph, such as a
+/// topology table, with the faces to be built downstream.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::{overlay_edges, FaceLabel};
+/// use geo::{polygon, Coordinate, MultiPolygon};
+///
+/// let a: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+/// let b: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 4.), (x: 0., y: 4.)].into();
+///
+/// // The shared edge has `a` below, and `b` above.
+/// let edges = overlay_edges(&a, &b);
+/// let shared = edges
+///     .iter()
+///     .find(|(line, _, _)| line.start == Coordinate { x: 0., y: 2. } && line.end.y == 2.)
+///     .unwrap();
+/// assert_eq!((shared.1, shared.2), (FaceLabel::OnlyB, FaceLabel::OnlyA));
+/// ```
+pub fn overlay_edges<T: Float>(
+    a: &MultiPolygon<T>,
+    b: &MultiPolygon<T>,
+) -> Vec<(Line<T>, FaceLabel, FaceLabel)> {
+    let mut edges = vec![];
+    for poly in a {
+        add_polygon(&mut edges, poly, Operand::Subject);
+    }
+    for poly in b {
+        add_polygon(&mut edges, poly, Operand::Clip);
+    }
+
+    let mut output = vec![];
+    sweep_pieces(
+        &edges,
+        |_| {},
+        |piece, above, below| {
+            let left = FaceLabel::new(above[0] > 0, above[1] > 0);
+            let right = FaceLabel::new(below[0] > 0, below[1] > 0);
+            if left != right {
+                output.push((piece.line(), left, right));
+            }
+        },
+    );
+    output
+}
@@ -1,11 +1,11 @@
 use crate::utils::EitherIter as Either;
 use crate::{
     winding_order::{Winding, WindingOrder},
-    Coordinate, GeoFloat, GeoNum, LineString,
+    Coordinate, GeoFloat, GeoNum, Kernel, LineString, Orientation,
 };
 use log::{debug, trace};
 use std::{
-    collections::{BTreeMap, VecDeque},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     ops::Deref,
 };
 
@@ -26,7 +26,9 @@ impl<T: GeoNum> Default for Rings<T> {
     }
 }
 impl<T: GeoFloat> Rings<T> {
-    pub fn finish(mut self) -> Vec<Ring<T>> {
+    /// Chain the edges into rings. The `steiner` points are the
+    /// vertices created by the sweep, that are not input vertices.
+    pub fn finish(mut self, steiner: &BTreeSet<SweepPoint<T>>) -> Vec<Ring<T>> {
         let mut output = vec![];
         let mut curr_chain_idx = self.chains.len();
         trace!("finalizing {n} chains", n = curr_chain_idx);
@@ -70,7 +72,7 @@ impl<T: GeoFloat> Rings<T> {
                                 history.remove(&((*pt).into())).unwrap();
                             })
                             .collect();
-                        output.push(Ring::from_coords(new_ls, &winding));
+                        output.push(Ring::from_coords(new_ls, &winding, steiner));
                     }
                     history.insert(*pt, ls.len());
                     trace!("insert: {pt:?} @ idx {ln}", ln = ls.len());
@@ -91,7 +93,7 @@ impl<T: GeoFloat> Rings<T> {
             }
             trace!("ring {idx}:", idx = output.len());
             trace!("\t{ls:?}");
-            output.push(Ring::from_coords(ls, &winding));
+            output.push(Ring::from_coords(ls, &winding, steiner));
         }
         output
     }
@@ -181,23 +183,69 @@ impl<T: GeoFloat> Rings<T> {
     }
 }
 
+/// A closed ring in the output of a boolean operation, and whether it
+/// bounds a hole.
 #[derive(Debug, Clone)]
 pub struct Ring<T: GeoNum> {
     coords: LineString<T>,
     is_hole: bool,
+    /// Whether each vertex of `coords` (but the closing one) was created
+    /// at an intersection by the sweep.
+    steiner: Vec<bool>,
 }
 
 impl<T: GeoNum> Ring<T> {
-    fn from_coords(coords: Vec<Coordinate<T>>, region_winding: &WindingOrder) -> Self {
+    fn from_coords(
+        coords: Vec<Coordinate<T>>,
+        region_winding: &WindingOrder,
+        steiner: &BTreeSet<SweepPoint<T>>,
+    ) -> Self {
         debug!("new ring:");
         debug!("\tregion_winding: {region_winding:?}");
         debug!("\tcoords: {coords:?}");
+        let steiner = coords
+            .iter()
+            .map(|c| steiner.contains(&SweepPoint::from(*c)))
+            .collect();
         let mut ls = LineString(coords);
         ls.close();
         let winding = ls.winding_order().expect("ring must have a winding order");
         Self {
             coords: ls,
             is_hole: &winding != region_winding,
+            steiner,
+        }
+    }
+
+    /// A copy of the ring without the vertices created by the sweep
+    /// that lie in the middle of a straight edge. Input vertices are
+    /// always kept.
+    pub(crate) fn without_collinear(&self) -> Self {
+        let n = self.steiner.len();
+        let coords = &self.coords.0;
+        let is_collinear = |idx: usize| {
+            let prev = coords[(idx + n - 1) % n];
+            let curr = coords[idx];
+            let next = coords[(idx + 1) % n];
+            T::Ker::orient2d(prev, curr, next) == Orientation::Collinear
+                && T::Ker::dot_product_sign(curr - prev, next - curr)
+                    == Orientation::CounterClockwise
+        };
+        // Vertices are tested against their original neighbours: a run
+        // of removed vertices all lie on the line through its ends.
+        let mut kept: Vec<_> = (0..n)
+            .filter(|&idx| !(self.steiner[idx] && is_collinear(idx)))
+            .map(|idx| (coords[idx], self.steiner[idx]))
+            .collect();
+        if kept.len() < 3 {
+            return self.clone();
+        }
+        let steiner = kept.iter().map(|(_, s)| *s).collect();
+        kept.push(kept[0]);
+        Self {
+            coords: kept.into_iter().map(|(c, _)| c).collect(),
+            is_hole: self.is_hole,
+            steiner,
         }
     }
 
@@ -212,6 +260,12 @@ impl<T: GeoNum> Ring<T> {
     pub fn coords(&self) -> &LineString<T> {
         &self.coords
     }
+
+    /// Move the coordinates out of the ring, leaving it empty.
+    pub(super) fn take_coords(&mut self) -> LineString<T> {
+        self.steiner = vec![];
+        std::mem::replace(&mut self.coords, LineString::new(vec![]))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,61 @@
+use super::{Op, OpType};
+use crate::{CoordsIter, GeoFloat, MultiPolygon, Polygon};
+
+/// The similarity of the areas of two polygonal geometries: one less the
+/// area of their symmetric difference relative to the area of their
+/// union.
+///
+/// This is `1` for identical geometries, and `0` for disjoint ones.
+/// Both areas are computed from a single sweep. Two empty geometries are
+/// identical.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, AreaSimilarity};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 1., y: 0.), (x: 3., y: 0.), (x: 3., y: 2.), (x: 1., y: 2.)];
+///
+/// assert_eq!(a.area_similarity(&a), 1.);
+/// // The union has area 6, of which 4 are covered by only one.
+/// let similarity: f64 = a.area_similarity(&b);
+/// assert!((similarity - 1. / 3.).abs() < 1e-12);
+/// ```
+pub trait AreaSimilarity {
+    type Scalar: GeoFloat;
+
+    fn area_similarity(&self, other: &Self) -> Self::Scalar;
+}
+
+impl<T: GeoFloat> AreaSimilarity for Polygon<T> {
+    type Scalar = T;
+
+    fn area_similarity(&self, other: &Self) -> T {
+        let mut bop = Op::new(OpType::Xor, self.coords_count() + other.coords_count());
+        bop.add_polygon(self, true);
+        bop.add_polygon(other, false);
+        similarity(&bop)
+    }
+}
+
+impl<T: GeoFloat> AreaSimilarity for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn area_similarity(&self, other: &Self) -> T {
+        let mut bop = Op::new(OpType::Xor, self.coords_count() + other.coords_count());
+        bop.add_multi_polygon(self, true);
+        bop.add_multi_polygon(other, false);
+        similarity(&bop)
+    }
+}
+
+/// One less the area of the symmetric difference of the inputs of
+/// `bop`, relative to the area of their union.
+fn similarity<T: GeoFloat>(bop: &Op<T>) -> T {
+    let union = bop.area_as(OpType::Union);
+    if union == T::zero() {
+        return T::one();
+    }
+    T::one() - bop.area_as(OpType::Xor) / union
+}
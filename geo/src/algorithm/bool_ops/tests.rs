@@ -1,4 +1,4 @@
-use crate::{MultiPolygon, Polygon};
+use crate::{Area, EuclideanLength, LineString, MultiPolygon, Point, Polygon, PolygonBuilder};
 
 use log::{error, info};
 
@@ -19,6 +19,32 @@ pub(super) fn init_log() {
 use super::*;
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
+/// Eight thin slivers from x = 0 to 2, all of them active at x = 1.
+fn slivers() -> Result<MultiPolygon<f64>> {
+    let slivers = (0..8)
+        .map(|i| {
+            let y = i as f64;
+            Polygon::<f64>::try_from_wkt_str(&format!(
+                "POLYGON((0 {y}, 2 {y}, 2 {y1}, 0 {y1}, 0 {y}))",
+                y1 = y + 0.5
+            ))
+        })
+        .collect::<std::result::Result<_, _>>()?;
+    Ok(MultiPolygon::new(slivers))
+}
+
+/// A regular polygon of `n` vertices on the circle of center `(x, y)`
+/// and of the given `radius`, the first one at the angle `phase`.
+fn regular_polygon(n: usize, x: f64, y: f64, radius: f64, phase: f64) -> Polygon<f64> {
+    let ring: LineString<f64> = (0..n)
+        .map(|i| {
+            let angle = phase + i as f64 * std::f64::consts::TAU / n as f64;
+            (x + radius * angle.cos(), y + radius * angle.sin())
+        })
+        .collect();
+    Polygon::new(ring, vec![])
+}
+
 #[test]
 fn test_rect_overlapping() -> Result<()> {
     // Two rects that overlap
@@ -79,7 +105,7 @@ fn check_sweep(wkt1: &str, wkt2: &str, ty: OpType) -> Result<MultiPolygon<f64>>
         );
     }
 
-    let polygons = assemble(rings);
+    let polygons = assemble(rings, KeepOrDrop::Keep);
     info!("got {n} output polygons", n = polygons.len());
     for p in polygons.iter() {
         info!("\t{wkt}", wkt = p.to_wkt());
@@ -119,3 +145,1405 @@ fn test_complex_rects1() -> Result<()> {
     check_sweep(wkt1, wkt2, OpType::Union)?;
     Ok(())
 }
+
+#[test]
+fn test_intersection_full() -> Result<()> {
+    use crate::{Area, Geometry};
+
+    init_log();
+    // `b` overlaps `a` in [1, 2] x [0, 1], shares the edge x = 2 for y in
+    // [1.5, 2], and touches it at the corner (0, 2).
+    let a = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)))")?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1 -1,3 -1,3 1,1 1,1 -1)),((2 1.5,3 1.5,3 2,2 2,2 1.5)),((-1 2,0 2,0 3,-1 3,-1 2)))",
+    )?;
+
+    let full = a.intersection_full(&b);
+    let (mut polygons, mut lines, mut points) = (vec![], vec![], vec![]);
+    for geom in full {
+        match geom {
+            Geometry::Polygon(p) => polygons.push(p),
+            Geometry::LineString(l) => lines.push(l),
+            Geometry::Point(p) => points.push(p),
+            g => panic!("unexpected geometry: {g:?}"),
+        }
+    }
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].unsigned_area(), 1.);
+    assert_eq!(lines, vec![LineString::from(vec![(2., 1.5), (2., 2.)])]);
+    assert_eq!(points, vec![Point::new(0., 2.)]);
+
+    // Operand order does not matter.
+    assert_eq!(b.intersection_full(&a).0.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_intersection_full_merges_lines() -> Result<()> {
+    // Two L-shapes sharing a bent boundary made of two edges.
+    let a = PolygonBuilder::new()
+        .exterior([(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.)])
+        .build()?;
+    let b = PolygonBuilder::new()
+        .exterior([(2., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 2.), (1., 1.)])
+        .build()?;
+    let full = a.intersection_full(&b);
+    assert_eq!(
+        full.0,
+        vec![crate::Geometry::LineString(LineString::from(vec![
+            (1., 2.),
+            (1., 1.),
+            (2., 1.)
+        ]))]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_intersection_is_areal_part() -> Result<()> {
+    use crate::{line_string, polygon, Geometry};
+
+    let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    // Sharing the edge x = 2, and touching at the corner (2, 2).
+    let edge = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+    let corner = polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)];
+
+    assert!(a.intersection(&edge).0.is_empty());
+    assert_eq!(
+        a.intersection_full(&edge).0,
+        vec![Geometry::LineString(
+            line_string![(x: 2., y: 0.), (x: 2., y: 2.)]
+        )]
+    );
+    assert!(a.intersection(&corner).0.is_empty());
+    assert_eq!(
+        a.intersection_full(&corner).0,
+        vec![Geometry::Point(Point::new(2., 2.))]
+    );
+
+    // The same as the intersection by `boolean_op`, with all the
+    // dimensions in order.
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1 -1,3 -1,3 1,1 1,1 -1)),((2 1.5,3 1.5,3 2,2 2,2 1.5)),((-1 2,0 2,0 3,-1 3,-1 2)))",
+    )?;
+    let a = MultiPolygon::new(vec![a]);
+    let full = a.intersection_full(&b);
+    let dims: Vec<_> = full
+        .iter()
+        .map(|geom| match geom {
+            Geometry::Polygon(_) => 2,
+            Geometry::LineString(_) => 1,
+            Geometry::Point(_) => 0,
+            g => panic!("unexpected geometry: {g:?}"),
+        })
+        .collect();
+    assert_eq!(dims, [2, 1, 0]);
+    assert_eq!(a.intersection(&b), a.boolean_op(&b, OpType::Intersection));
+    assert_eq!(a.intersection(&b), full.just_polygons());
+    Ok(())
+}
+
+#[test]
+fn test_assemble_as() -> Result<()> {
+    let wkt1 = "POLYGON((0 0, 40 0, 40 40, 0 40, 0 0), (10 10, 30 10, 30 30, 10 30, 10 10))";
+    let wkt2 = "POLYGON((5 5, 20 5, 20 35, 5 35, 5 5))";
+    let poly1 = Polygon::<f64>::try_from_wkt_str(wkt1)?;
+    let poly2 = Polygon::<f64>::try_from_wkt_str(wkt2)?;
+
+    let mut bop = Op::new(OpType::Xor, 0);
+    bop.add_polygon(&poly1, true);
+    bop.add_polygon(&poly2, false);
+    let xor = MultiPolygon::new(assemble(bop.sweep(), KeepOrDrop::Keep));
+    assert_eq!(xor, check_sweep(wkt1, wkt2, OpType::Xor)?);
+
+    for ty in [OpType::Union, OpType::Intersection, OpType::Difference] {
+        let output = MultiPolygon::new(assemble(bop.assemble_as(ty), KeepOrDrop::Keep));
+        assert_eq!(output, check_sweep(wkt1, wkt2, ty)?, "{ty:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assemble_drop_collinear() -> Result<()> {
+    // The shared edge of the second operand crosses the top edge of the
+    // first at (2 2), which is a vertex of neither input.
+    let a = PolygonBuilder::new()
+        .exterior([(0., 0.), (4., 0.), (4., 2.), (0., 2.)])
+        .build()?;
+    let b = MultiPolygon::new(vec![
+        PolygonBuilder::new()
+            .exterior([(1., 1.), (2., 1.), (2., 3.), (1., 3.)])
+            .build()?,
+        PolygonBuilder::new()
+            .exterior([(2., 1.), (3., 1.), (3., 3.), (2., 3.)])
+            .build()?,
+    ]);
+    let mut bop = Op::new(OpType::Intersection, 0);
+    bop.add_polygon(&a, true);
+    bop.add_multi_polygon(&b, false);
+
+    let kept = assemble(bop.sweep(), KeepOrDrop::Keep);
+    let dropped = assemble(bop.sweep(), KeepOrDrop::Drop);
+    assert_eq!(kept.len(), 1);
+    assert_eq!(dropped.len(), 1);
+    assert!(kept[0].exterior().0.contains(&(2., 2.).into()));
+    assert!(!dropped[0].exterior().0.contains(&(2., 2.).into()));
+    // (2 1) is also collinear, but is an input vertex.
+    assert!(dropped[0].exterior().0.contains(&(2., 1.).into()));
+    assert_eq!(
+        dropped[0].exterior().0.len(),
+        kept[0].exterior().0.len() - 1
+    );
+    assert_eq!(dropped[0].unsigned_area(), kept[0].unsigned_area());
+    Ok(())
+}
+
+#[test]
+fn test_non_finite_error() -> Result<()> {
+    let a = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,1 0,1 1,0 1,0 0))")?;
+    let mut b = a.clone();
+    b.exterior_mut(|ext| ext.0[2].y = f64::NAN);
+
+    let mut bop = Op::new(OpType::Union, 0);
+    bop.try_add_polygon(&a, true)?;
+    let err = bop.try_add_polygon(&b, false).unwrap_err();
+    assert!(
+        matches!(err, crate::sweep::Error::NonFiniteCoordinate(c) if c.x == 1. && c.y.is_nan())
+    );
+    assert_eq!(err.to_string(), "non-finite coordinate in input: (1, NaN)");
+    assert_eq!(
+        std::io::Error::from(err).kind(),
+        std::io::ErrorKind::InvalidData
+    );
+
+    // The invalid polygon was not added.
+    assert_eq!(bop.sweep().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_op_type_round_trip() {
+    assert_eq!(OpType::all().len(), 4);
+    for ty in OpType::all() {
+        assert_eq!(ty.to_string().parse::<OpType>(), Ok(*ty));
+        assert_eq!(ty.to_string().to_uppercase().parse::<OpType>(), Ok(*ty));
+    }
+    assert_eq!("xor".parse::<OpType>(), Ok(OpType::Xor));
+    assert_eq!(
+        "sym_difference".parse::<OpType>().unwrap_err().to_string(),
+        "unknown boolean operation: \"sym_difference\""
+    );
+}
+
+#[test]
+fn test_overlap_area() -> Result<()> {
+    let cases = [
+        (
+            "POLYGON((0 0, 40 0, 40 40, 0 40, 0 0), (10 10, 30 10, 30 30, 10 30, 10 10))",
+            "POLYGON((5 5, 20 5, 20 35, 5 35, 5 5))",
+        ),
+        (
+            "MULTIPOLYGON(((0 0,10 3,20 0,17 10,20 20,10 17,0 20,3 10,0 0),(5 5,15 5,10 12,5 5)),((30 0,40 0,40 10,30 0)))",
+            "MULTIPOLYGON(((2 -1,35 4,38 12,12 18,2 -1)))",
+        ),
+        (
+            "POLYGON((0 0,1 0,1 1,0 1,0 0))",
+            "POLYGON((2 2,3 2,3 3,2 3,2 2))",
+        ),
+    ];
+    for (wkt1, wkt2) in cases {
+        let a = MultiPolygon::<f64>::try_from_wkt_str(wkt1)
+            .or_else(|_| Polygon::<f64>::try_from_wkt_str(wkt1).map(MultiPolygon::from))?;
+        let b = MultiPolygon::<f64>::try_from_wkt_str(wkt2)
+            .or_else(|_| Polygon::<f64>::try_from_wkt_str(wkt2).map(MultiPolygon::from))?;
+        let expected = a.intersection(&b).unsigned_area();
+        assert_relative_eq!(a.overlap_area(&b), expected, epsilon = 1e-9);
+        assert_relative_eq!(b.overlap_area(&a), expected, epsilon = 1e-9);
+
+        // The other operations, from the same sweep.
+        let mut bop = Op::new(OpType::Intersection, 0);
+        bop.add_multi_polygon(&a, true);
+        bop.add_multi_polygon(&b, false);
+        for ty in OpType::all() {
+            let expected = a.boolean_op(&b, *ty).unsigned_area();
+            assert_relative_eq!(bop.area_as(*ty), expected, epsilon = 1e-9);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_unary_intersection() {
+    let circle = |x, y| regular_polygon(64, x, y, 2., 0.);
+    let circles = [circle(0., 0.), circle(2., 0.), circle(1., 1.5)];
+
+    let expected = circles[0]
+        .intersection(&circles[1])
+        .intersection(&circles[2].clone().into());
+    let common = unary_intersection(&circles);
+    assert_eq!(common.0.len(), 1);
+    assert!(common.unsigned_area() > 0.);
+    assert_relative_eq!(
+        common.unsigned_area(),
+        expected.unsigned_area(),
+        epsilon = 1e-9
+    );
+
+    // A clockwise input covers the same region.
+    let mut reversed = circles.clone();
+    reversed[1].exterior_mut(|ring| ring.0.reverse());
+    assert_relative_eq!(
+        unary_intersection(&reversed).unsigned_area(),
+        expected.unsigned_area(),
+        epsilon = 1e-9
+    );
+
+    // Any disjoint input empties the result.
+    let far = circle(10., 10.);
+    assert!(unary_intersection(circles.iter().chain([&far]))
+        .0
+        .is_empty());
+    assert!(unary_intersection(&[] as &[Polygon<f64>]).0.is_empty());
+}
+
+#[test]
+fn test_unary_union() {
+    let square = |x: f64, y: f64, size: f64| {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+            ]),
+            vec![],
+        )
+    };
+    // A grid of disjoint squares, two chains of overlapping ones, and a
+    // square touching another at a corner.
+    let mut polygons: Vec<_> = (0..10)
+        .flat_map(|i| (0..10).map(move |j| square(3. * i as f64, 3. * j as f64, 1.)))
+        .collect();
+    polygons.extend((0..5).map(|i| square(0.5 + 0.5 * i as f64, 0.5, 1.)));
+    polygons.extend((0..3).map(|i| square(40. + i as f64, 40. + i as f64, 1.5)));
+    polygons.push(square(28., 28., 1.));
+
+    let union = unary_union(&polygons);
+    let expected = polygons
+        .iter()
+        .fold(MultiPolygon::new(vec![]), |union, poly| {
+            union.union(&poly.clone().into())
+        });
+    assert_relative_eq!(
+        union.unsigned_area(),
+        expected.unsigned_area(),
+        epsilon = 1e-9
+    );
+    assert_eq!(union.0.len(), 98 + 1 + 1 + 1);
+
+    // The disjoint inputs are normalized as the swept ones, in order.
+    let mut reversed = polygons[1].clone();
+    reversed.exterior_mut(|ring| ring.0.reverse());
+    let swept = unary_union(&[polygons[1].clone(), reversed]);
+    assert_eq!(union.0[1], swept.0[0]);
+    assert_ne!(union.0[1], polygons[1]);
+    assert!(unary_union(&[] as &[Polygon<f64>]).0.is_empty());
+
+    // Rows of squares overlapping along their top and bottom edges.
+    for n in 2..20 {
+        let row: Vec<_> = (0..n).map(|k| square(0.25 * k as f64, 0., 2.)).collect();
+        let union = unary_union(&row);
+        assert_eq!(union.0.len(), 1);
+        assert_relative_eq!(union.unsigned_area(), 2. * (2. + 0.25 * (n - 1) as f64));
+    }
+}
+
+#[test]
+fn test_venn() {
+    let circle = |x, y| MultiPolygon::from(regular_polygon(64, x, y, 2., 0.));
+    let (a, b) = (circle(0., 0.), circle(1.5, 0.5));
+    let regions = venn(&a, &b);
+    let parts = [&regions.a_only, &regions.b_only, &regions.both];
+    for part in parts {
+        assert!(part.unsigned_area() > 0.);
+    }
+    assert_same_region(&regions.a_only, &a.difference(&b));
+    assert_same_region(&regions.b_only, &b.difference(&a));
+    assert_same_region(&regions.both, &a.intersection(&b));
+
+    // Disjoint regions, covering the union.
+    for (i, p) in parts.iter().enumerate() {
+        for q in &parts[i + 1..] {
+            assert!(p.intersection(q).unsigned_area() < 1e-9);
+        }
+    }
+    let total: f64 = parts.iter().map(|part| part.unsigned_area()).sum();
+    assert_relative_eq!(total, a.union(&b).unsigned_area(), epsilon = 1e-9);
+
+    // Swapping the inputs swaps the differences.
+    let swapped = venn(&b, &a);
+    assert_same_region(&swapped.a_only, &regions.b_only);
+    assert_same_region(&swapped.both, &regions.both);
+}
+
+#[test]
+fn test_max_active() -> Result<()> {
+    let slivers = slivers()?;
+
+    let mut bop = Op::new(OpType::Union, 0).with_max_active(4);
+    bop.add_multi_polygon(&slivers, true);
+    assert_eq!(
+        bop.try_sweep().unwrap_err(),
+        crate::sweep::Error::ActiveSetTooLarge
+    );
+
+    let mut bop = Op::new(OpType::Union, 0).with_max_active(32);
+    bop.add_multi_polygon(&slivers, true);
+    assert_eq!(bop.try_sweep()?.len(), 8);
+    Ok(())
+}
+
+#[test]
+fn test_record_last() -> Result<()> {
+    let slivers = slivers()?;
+
+    let recorded = |n| {
+        let mut bop = Op::new(OpType::Union, 0).with_max_active(4).record_last(n);
+        bop.add_multi_polygon(&slivers, true);
+        assert!(bop.try_sweep().is_err());
+        (bop.recorded_events(), bop.statistics())
+    };
+    let (all, stats) = recorded(1000);
+    assert_eq!(all.len(), stats.events);
+
+    // The buffer keeps the final events, up to the one that failed: the
+    // start of a segment, at the left of the slivers.
+    let (last, _) = recorded(3);
+    assert_eq!(last, all[all.len() - 3..]);
+    assert_eq!(last[2].kind, crate::sweep::TraceEventKind::LineStart);
+    assert_eq!(last[2].point.x, 0.);
+
+    // Nothing is recorded unless asked for.
+    let mut bop = Op::new(OpType::Union, 0).with_max_active(4);
+    bop.add_multi_polygon(&slivers, true);
+    assert!(bop.try_sweep().is_err());
+    assert!(bop.recorded_events().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_difference_buffered() -> Result<()> {
+    let a = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((20 0,30 0,30 10,20 10,20 0)))",
+    )?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((4 4,6 4,6 6,4 6,4 4)))")?;
+
+    // Without a buffer, this is the plain difference.
+    assert_relative_eq!(
+        difference_buffered(&a, &b, 0.).unsigned_area(),
+        a.difference(&b).unsigned_area(),
+        epsilon = 1e-9
+    );
+
+    // The buffer is the square, four 2x1 rectangles, and the four
+    // quarters of a 32-gon inscribed in the unit circle at the corners.
+    let n = 32.;
+    let disk = n / 2. * (std::f64::consts::TAU / n).sin();
+    let output = difference_buffered(&a, &b, 1.);
+    assert_eq!(output.0.len(), 2);
+    assert_relative_eq!(
+        output.unsigned_area(),
+        200. - (4. + 8. + disk),
+        epsilon = 1e-9
+    );
+
+    // The corners of the first square, beyond the rounded corners of the
+    // buffer, and the second square remain.
+    assert_eq!(difference_buffered(&a, &b, 5.).0.len(), 5);
+
+    // Buffering to cover `a` entirely.
+    assert!(difference_buffered(&a, &b, 30.).0.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_erase() {
+    use crate::{polygon, Translate};
+    let subject: MultiPolygon<f64> =
+        polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 20.), (x: 0., y: 20.)].into();
+    let mut erase = Erase::new(&subject);
+
+    // A dozen separate masks: a row of disjoint squares, and a row of
+    // pairs overlapping by a quarter.
+    let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let disjoint: Vec<_> = (0..6)
+        .map(|i| square.translate(1. + 3. * i as f64, 2.))
+        .collect();
+    for mask in &disjoint {
+        erase.add_mask(mask);
+    }
+    let overlapping: Vec<_> = (0..3)
+        .flat_map(|i| {
+            let x = 1. + 6. * i as f64;
+            [square.translate(x, 10.), square.translate(x + 1., 11.)]
+        })
+        .collect();
+    erase.extend(&overlapping);
+
+    let erased = MultiPolygon::new(assemble(erase.sweep(), KeepOrDrop::Keep));
+    assert_relative_eq!(erased.unsigned_area(), 400. - 6. * 4. - 3. * 7.);
+    // The subject with the 9 punched holes.
+    assert_eq!(erased.0.len(), 1);
+    assert_eq!(erased.0[0].interiors().len(), 9);
+
+    // As the difference with the union of the masks.
+    let union = disjoint
+        .into_iter()
+        .chain(overlapping)
+        .fold(MultiPolygon::new(vec![]), |union, mask| {
+            union.union(&mask.into())
+        });
+    assert_same_region(&erased, &subject.difference(&union));
+}
+
+#[test]
+fn test_difference_all() {
+    use crate::{polygon, Translate};
+    // A large subject with a hole, and 50 overlapping erasers scattered
+    // over it, some partly outside or over the hole.
+    let subject: MultiPolygon<f64> = polygon![
+        exterior: [(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 100.), (x: 0., y: 100.)],
+        interiors: [[(x: 40., y: 40.), (x: 60., y: 40.), (x: 60., y: 60.), (x: 40., y: 60.)]],
+    ]
+    .into();
+    let square = polygon![(x: 0., y: 0.), (x: 12., y: 0.), (x: 12., y: 12.), (x: 0., y: 12.)];
+    let erasers: Vec<_> = (0..50)
+        .map(|i| square.translate((i * 37 % 101) as f64 - 5., (i * 59 % 97) as f64 - 5.))
+        .collect();
+
+    let erased = difference_all(&subject, &erasers);
+    let union = erasers
+        .iter()
+        .fold(MultiPolygon::new(vec![]), |union, eraser| {
+            union.union(&eraser.clone().into())
+        });
+    assert_same_region(&erased, &subject.difference(&union));
+    assert!(erased.unsigned_area() < subject.unsigned_area());
+
+    assert_same_region(&difference_all(&subject, &[]), &subject);
+}
+
+#[test]
+fn test_area_similarity() -> Result<()> {
+    let a = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,2 0,2 2,0 2,0 0))")?;
+    let half = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,1 0,1 2,0 2,0 0))")?;
+    let far = Polygon::<f64>::try_from_wkt_str("POLYGON((5 5,6 5,6 6,5 6,5 5))")?;
+
+    assert_eq!(a.area_similarity(&a), 1.);
+    assert_eq!(a.area_similarity(&far), 0.);
+    // `half` covers half of `a`.
+    assert_relative_eq!(a.area_similarity(&half), 0.5, epsilon = 1e-9);
+    assert_relative_eq!(half.area_similarity(&a), 0.5, epsilon = 1e-9);
+
+    let a = MultiPolygon::from(a);
+    assert_relative_eq!(
+        a.area_similarity(&MultiPolygon::new(vec![half, far])),
+        0.4,
+        epsilon = 1e-9
+    );
+    let empty = MultiPolygon::<f64>::new(vec![]);
+    assert_eq!(empty.area_similarity(&empty), 1.);
+    Ok(())
+}
+
+#[test]
+fn test_assemble_iter() -> Result<()> {
+    // Rings inside holes inside exteriors.
+    let wkt1 = "POLYGON((0 0, 40 0, 40 40, 0 40, 0 0), (10 10, 30 10, 30 30, 10 30, 10 10))";
+    let wkt2 = "POLYGON((11 11, 29 11, 29 29, 11 29, 11 11), (15 15, 25 15, 25 25, 15 25, 15 15))";
+    let poly1 = Polygon::<f64>::try_from_wkt_str(wkt1)?;
+    let poly2 = Polygon::<f64>::try_from_wkt_str(wkt2)?;
+    let mut bop = Op::new(OpType::Union, 0);
+    bop.add_polygon(&poly1, true);
+    bop.add_polygon(&poly2, false);
+
+    let expected = assemble(bop.sweep(), KeepOrDrop::Keep);
+    let lazy: Vec<_> = assemble_iter(bop.sweep(), KeepOrDrop::Keep).collect();
+    assert_eq!(lazy.len(), 2);
+    assert_eq!(lazy.len(), expected.len());
+    for p in &lazy {
+        assert!(expected.contains(p));
+    }
+    // The inner polygon ends first.
+    use crate::BoundingRect;
+    assert_eq!(lazy[0].bounding_rect().unwrap().max().x, 29.);
+    assert_relative_eq!(
+        MultiPolygon::new(lazy).unsigned_area(),
+        poly1.unsigned_area() + poly2.unsigned_area()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_assemble_ordered() -> Result<()> {
+    use crate::BoundingRect;
+    // An island in a hole of an island in a hole of an exterior, and a
+    // second exterior on the side.
+    let wkt1 = "MULTIPOLYGON(((0 0, 40 0, 40 40, 0 40, 0 0), (10 10, 30 10, 30 30, 10 30, 10 10)),
+        ((18 18, 22 18, 22 22, 18 22, 18 18)))";
+    let wkt2 =
+        "MULTIPOLYGON(((11 11, 29 11, 29 29, 11 29, 11 11), (15 15, 25 15, 25 25, 15 25, 15 15)),
+        ((50 0, 60 0, 60 10, 50 10, 50 0)))";
+    let poly1 = MultiPolygon::<f64>::try_from_wkt_str(wkt1)?;
+    let poly2 = MultiPolygon::<f64>::try_from_wkt_str(wkt2)?;
+    let mut bop = Op::new(OpType::Union, 0);
+    bop.add_multi_polygon(&poly1, true);
+    bop.add_multi_polygon(&poly2, false);
+
+    let unordered = assemble(bop.sweep(), KeepOrDrop::Keep);
+    assert_eq!(
+        assemble_ordered(bop.sweep(), KeepOrDrop::Keep, DrawOrder::Rings),
+        unordered
+    );
+
+    let ordered = assemble_ordered(bop.sweep(), KeepOrDrop::Keep, DrawOrder::OuterToInner);
+    assert_eq!(ordered.len(), unordered.len());
+    let left = |p: &Polygon<f64>| p.bounding_rect().unwrap().min().x;
+    let lefts: Vec<_> = ordered.iter().map(left).collect();
+    // The exteriors first, in the order of `assemble`, then the islands
+    // from the outside in.
+    let roots: Vec<_> = unordered
+        .iter()
+        .map(left)
+        .filter(|x| *x == 0. || *x == 50.)
+        .collect();
+    assert_eq!(lefts[..2], roots[..]);
+    assert_eq!(lefts[2..], [11., 18.]);
+    Ok(())
+}
+
+#[test]
+fn test_noded_inputs() {
+    use crate::{coord, polygon, Coordinate};
+    let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    let b = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+    let mut bop = Op::new(OpType::Union, 8);
+    bop.add_polygon(&a, true);
+    bop.add_polygon(&b, false);
+
+    let (noded_a, noded_b) = bop.noded_inputs();
+    let crossings = [coord! { x: 4., y: 2. }, coord! { x: 2., y: 4. }];
+    for (noded, input) in [(&noded_a, &a), (&noded_b, &b)] {
+        // Two edges split in two, and the pieces cover the input.
+        assert_eq!(noded.0.len(), 6);
+        assert_relative_eq!(
+            noded.euclidean_length(),
+            input.exterior().euclidean_length()
+        );
+        let vertices: Vec<Coordinate<f64>> = noded.0.iter().flat_map(|ls| ls.0.clone()).collect();
+        for c in &crossings {
+            assert_eq!(vertices.iter().filter(|v| *v == c).count(), 2);
+        }
+    }
+}
+
+#[test]
+fn test_add_with_precision() -> Result<()> {
+    let a = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,1 0,1 1,0 1,0 0)))")?;
+    // Overlaps `a` by 1e-7, below its precision.
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0.9999999 0,2 0,2 1,0.9999999 1,0.9999999 0)))",
+    )?;
+
+    // Without snapping, the intersection is a sliver.
+    let sliver = a.intersection(&b);
+    assert_eq!(sliver.0.len(), 1);
+    assert!(sliver.unsigned_area() < 1e-6);
+
+    // Snapped, the squares share an edge.
+    let mut bop = Op::new(OpType::Intersection, 0);
+    bop.add_multi_polygon_with_precision(&a, true, 6);
+    bop.add_multi_polygon(&b, false);
+    assert!(bop.sweep().is_empty());
+    let union = assemble(bop.assemble_as(OpType::Union), KeepOrDrop::Drop);
+    assert_eq!(union.len(), 1);
+    // The ends of the shared edge are vertices of the inputs, and kept.
+    assert_eq!(union[0].exterior().0.len(), 7);
+    assert_relative_eq!(union[0].unsigned_area(), 2.);
+
+    // The coarsest precision wins.
+    let mut bop = Op::new(OpType::Intersection, 0);
+    bop.add_multi_polygon_with_precision(&a, true, 9);
+    bop.add_multi_polygon_with_precision(&b, false, 6);
+    assert!(bop.sweep().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_overlay_edges() -> Result<()> {
+    use crate::Line;
+    use FaceLabel::*;
+
+    let a = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)))")?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((1 1,3 1,3 3,1 3,1 1)))")?;
+    let edges = overlay_edges(&a, &b);
+
+    let edge = |x1, y1, x2, y2, left, right| (Line::from([(x1, y1), (x2, y2)]), left, right);
+    let expected = vec![
+        // The boundary of `a`, split by `b`.
+        edge(0., 0., 2., 0., OnlyA, Neither),
+        edge(0., 0., 0., 2., Neither, OnlyA),
+        edge(0., 2., 1., 2., Neither, OnlyA),
+        edge(1., 2., 2., 2., OnlyB, Both),
+        edge(2., 0., 2., 1., OnlyA, Neither),
+        edge(2., 1., 2., 2., Both, OnlyB),
+        // The boundary of `b`, split by `a`.
+        edge(1., 1., 2., 1., Both, OnlyA),
+        edge(2., 1., 3., 1., OnlyB, Neither),
+        edge(1., 1., 1., 2., OnlyA, Both),
+        edge(1., 2., 1., 3., Neither, OnlyB),
+        edge(1., 3., 3., 3., Neither, OnlyB),
+        edge(3., 1., 3., 3., OnlyB, Neither),
+    ];
+    assert_eq!(edges.len(), expected.len());
+    for e in &expected {
+        assert!(edges.contains(e), "missing edge: {e:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_buffer_variable() -> Result<()> {
+    use crate::Contains;
+
+    // Distances ramping from 1 to 5 along the line.
+    let ls = LineString::<f64>::try_from_wkt_str("LINESTRING(0 0,10 0,20 0,30 0,40 0)")?;
+    let corridor = ls.buffer_variable(&[1., 2., 3., 4., 5.], BufferJoin::Round);
+    assert_eq!(corridor.0.len(), 1);
+    assert!(corridor.0[0].interiors().is_empty());
+    // About the area of the trapezoid, and of the half disks at the ends.
+    let area = corridor.unsigned_area();
+    assert!(270. < area && area < 290., "area: {area}");
+
+    // The ring closes at the first vertex, with the last distance.
+    let poly = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,10 0,10 10,0 10,0 0))")?;
+    let buffer = poly.buffer_variable(&[1., 2., 3., 4., 5.], BufferJoin::Round);
+    assert_eq!(buffer.0.len(), 1);
+    assert!(buffer.0[0].interiors().is_empty());
+    for (x, y, inside) in [
+        // Distance 1.6 along the bottom.
+        (6., -1.5, true),
+        (6., -1.7, false),
+        // Distance 2.5 along the right.
+        (12.4, 5., true),
+        (12.6, 5., false),
+        // Distance 4.5 along the left.
+        (-4.4, 5., true),
+        (-4.6, 5., false),
+        (5., 5., true),
+    ] {
+        assert_eq!(buffer.contains(&Point::new(x, y)), inside, "({x}, {y})");
+    }
+
+    // The ends are cut square, and the joins of a square by a constant
+    // distance are its corners, or cut at 45°.
+    let corridor = ls.buffer_variable(&[1., 2., 3., 4., 5.], BufferJoin::Bevel);
+    assert_relative_eq!(corridor.unsigned_area(), 40. * (2. + 10.) / 2.);
+    let distances = [1.; 5];
+    let area = |join| poly.buffer_variable(&distances, join).unsigned_area();
+    assert_relative_eq!(area(BufferJoin::Mitre(2.)), 144.);
+    assert_relative_eq!(area(BufferJoin::Bevel), 144. - 4. * 0.5);
+    // The mitres are farther than 1.2 from the corners, at √2.
+    assert_relative_eq!(area(BufferJoin::Mitre(1.2)), 144. - 4. * 0.5);
+    let round = area(BufferJoin::Round);
+    assert!(142. < round && round < 144., "area: {round}");
+    let cw = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,0 10,10 10,10 0,0 0))")?;
+    let mitred = cw.buffer_variable(&distances, BufferJoin::Mitre(2.));
+    assert_relative_eq!(mitred.unsigned_area(), 144.);
+    Ok(())
+}
+
+/// Assert that `a` and `b` cover the same region, up to floating point
+/// errors: the area of their symmetric difference is negligible.
+fn assert_same_region(a: &MultiPolygon<f64>, b: &MultiPolygon<f64>) {
+    assert_eq!(a.0.len(), b.0.len(), "{a:?} vs {b:?}");
+    let union = a.union(b).unsigned_area();
+    assert!(
+        a.xor(b).unsigned_area() <= 1e-9 * union.max(1.),
+        "{a:?} vs {b:?}"
+    );
+}
+
+#[test]
+fn test_commutative() -> Result<()> {
+    let pairs = [
+        (
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 1,0 0)))",
+            "MULTIPOLYGON(((0.5 1,2 1,2 2,0.5 2,0.5 1)))",
+        ),
+        (
+            "MULTIPOLYGON(((0 0,40 0,40 40,0 40,0 0),(10 10,30 10,30 30,10 30,10 10)))",
+            "MULTIPOLYGON(((11 11,29 11,29 29,11 29,11 11),(15 15,25 15,25 25,15 25,15 15)))",
+        ),
+        (
+            "MULTIPOLYGON(((0 0,4 0,4 4,0 4,0 0)),((6 0,8 0,8 2,6 2,6 0)))",
+            "MULTIPOLYGON(((2 -1,7 -1,7 3,2 3,2 -1)))",
+        ),
+        // Shared edges, and a vertex on an edge.
+        (
+            "MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)))",
+            "MULTIPOLYGON(((2 0,4 0,4 2,2 2,2 1,2 0)))",
+        ),
+        (
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 1,0 0)))",
+            "MULTIPOLYGON EMPTY",
+        ),
+    ];
+    for (wkt1, wkt2) in pairs {
+        let a = MultiPolygon::<f64>::try_from_wkt_str(wkt1)?;
+        let b = MultiPolygon::<f64>::try_from_wkt_str(wkt2)?;
+        for ty in OpType::all().iter().filter(|ty| ty.is_commutative()) {
+            info!("{ty}: {wkt1} / {wkt2}");
+            assert_same_region(&a.boolean_op(&b, *ty), &b.boolean_op(&a, *ty));
+        }
+    }
+
+    // Difference is not commutative.
+    let a = MultiPolygon::<f64>::try_from_wkt_str(pairs[0].0)?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str(pairs[0].1)?;
+    assert_relative_eq!(a.difference(&b).unsigned_area(), 1.);
+    assert_relative_eq!(b.difference(&a).unsigned_area(), 1.5);
+    Ok(())
+}
+
+#[test]
+fn test_grid_clip() -> Result<()> {
+    use crate::Coordinate;
+
+    // A band of width 2 along the diagonal of a 4x4 grid.
+    let band =
+        MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,1 0,4 3,4 4,3 4,0 1,0 0)))")?;
+    let cells = band.grid_clip(Coordinate { x: 0., y: 0. }, 1.)?;
+
+    let mut expected = vec![];
+    for i in 0..4 {
+        expected.push(((i, i), 1.));
+        if i < 3 {
+            expected.push(((i + 1, i), 0.5));
+            expected.push(((i, i + 1), 0.5));
+        }
+    }
+    assert_eq!(cells.len(), expected.len());
+    for (cell, area) in expected {
+        let clipped = &cells[&cell];
+        assert_eq!(clipped.0.len(), 1, "cell {cell:?}");
+        assert_relative_eq!(clipped.unsigned_area(), area, epsilon = 1e-9);
+    }
+
+    // Far from the origin, with cell indices beyond `i32`.
+    let far = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1e10 1e10,1.00000000000001e10 1e10,1.00000000000001e10 1.00000000000001e10,1e10 1.00000000000001e10,1e10 1e10)))",
+    )?;
+    let cells = far.grid_clip(Coordinate { x: 0., y: 0. }, 1e-5)?;
+    assert!(!cells.is_empty());
+    assert!(cells
+        .keys()
+        .all(|&(i, j)| i > i32::MAX as i64 && j > i32::MAX as i64));
+    let total: f64 = cells.values().map(|cells| cells.unsigned_area()).sum();
+    assert_relative_eq!(total, far.unsigned_area(), max_relative = 1e-6);
+
+    // Beyond `i64`.
+    let error = far
+        .grid_clip(Coordinate { x: 0., y: 0. }, 1e-10)
+        .unwrap_err();
+    assert_eq!(error.coordinate, Coordinate { x: 1e10, y: 1e10 });
+    Ok(())
+}
+
+#[test]
+fn test_intersection_region() -> Result<()> {
+    use crate::{coord, Rect};
+
+    // The squares on the left only interact with `b` around x = 10.
+    let a = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 2,1 1)),((-5 0,-1 0,-1 4,-5 4,-5 0)))",
+    )?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((8 2,20 2,20 8,8 8,8 2)))")?;
+    let region = Rect::new(coord! { x: 8., y: 2. }, coord! { x: 10., y: 8. });
+
+    for ty in OpType::all() {
+        let mut plain = Op::new(*ty, 0);
+        plain.add_multi_polygon(&a, true);
+        plain.add_multi_polygon(&b, false);
+        let mut hinted = Op::new(*ty, 0).with_intersection_region(region);
+        hinted.add_multi_polygon(&a, true);
+        hinted.add_multi_polygon(&b, false);
+
+        let expected = MultiPolygon::from(assemble(plain.sweep(), KeepOrDrop::Keep));
+        let output = MultiPolygon::from(assemble(hinted.sweep(), KeepOrDrop::Keep));
+        assert_same_region(&expected, &output);
+        assert_eq!(plain.statistics().skipped_checks, 0);
+        assert!(hinted.statistics().skipped_checks > 0);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assume_noded() -> Result<()> {
+    // Two overlapping squares with vertices at their crossings, and a
+    // third sharing an edge with the first.
+    let a = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,2 0,2 1,2 2,1 2,0 2,0 0)))")?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1 1,2 1,3 1,3 3,1 3,1 2,1 1)),((0 -2,2 -2,2 0,0 0,0 -2)))",
+    )?;
+
+    for ty in OpType::all() {
+        let mut plain = Op::new(*ty, 0);
+        plain.add_multi_polygon(&a, true);
+        plain.add_multi_polygon(&b, false);
+        let mut noded = Op::new(*ty, 0).assume_noded(true);
+        noded.add_multi_polygon(&a, true);
+        noded.add_multi_polygon(&b, false);
+
+        let expected = MultiPolygon::from(assemble(plain.sweep(), KeepOrDrop::Keep));
+        let output = MultiPolygon::from(assemble(noded.sweep(), KeepOrDrop::Keep));
+        assert_eq!(output, expected);
+        assert_eq!(plain.statistics().skipped_checks, 0);
+        assert!(noded.statistics().skipped_checks > 0);
+        assert_eq!(noded.statistics().splits, 0);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_rounding_mode() {
+    use crate::coordinate_position::{CoordPos, CoordinatePosition};
+    use crate::{polygon, Coordinate, CoordsIter};
+
+    // Two regular polygons, crossing at coordinates not representable
+    // exactly.
+    let a = regular_polygon(7, 0., 0., 1., 0.1);
+    let b = regular_polygon(9, 0.3, 0.2, 1., 0.7);
+    let vertices: Vec<Coordinate<f64>> = a.coords_iter().chain(b.coords_iter()).collect();
+
+    let output = |ty: OpType, mode: RoundingMode| {
+        let mut op = Op::new(ty, 0).with_rounding_mode(mode);
+        op.add_polygon(&a, true);
+        op.add_polygon(&b, false);
+        MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep))
+    };
+    for ty in [OpType::Intersection, OpType::Union, OpType::Difference] {
+        let nearest = output(ty, RoundingMode::Nearest);
+        let inner = output(ty, RoundingMode::TowardInterior);
+        let outer = output(ty, RoundingMode::TowardExterior);
+        assert_same_region(&inner, &nearest);
+        assert_same_region(&outer, &nearest);
+        assert!(inner.unsigned_area() <= nearest.unsigned_area());
+        assert!(outer.unsigned_area() >= nearest.unsigned_area());
+
+        // The intersections are strictly inside the inputs kept, and
+        // outside the one subtracted, or the other way round.
+        let (in_b, out_b) = match ty {
+            OpType::Difference => (CoordPos::Outside, CoordPos::Inside),
+            _ => (CoordPos::Inside, CoordPos::Outside),
+        };
+        for (output, expected) in [
+            (&inner, (CoordPos::Inside, in_b)),
+            (&outer, (CoordPos::Outside, out_b)),
+        ] {
+            let crossings: Vec<_> = output
+                .coords_iter()
+                .filter(|c| !vertices.contains(c))
+                .map(|c| (a.coordinate_position(&c), b.coordinate_position(&c)))
+                .collect();
+            assert!(!crossings.is_empty());
+            assert!(
+                crossings.iter().all(|pos| *pos == expected),
+                "{ty}: {crossings:?}"
+            );
+        }
+    }
+
+    // A xor is rounded to the nearest.
+    assert_eq!(
+        output(OpType::Xor, RoundingMode::TowardInterior),
+        output(OpType::Xor, RoundingMode::Nearest)
+    );
+
+    // A square and a triangle crossing at (3, 2), (8/3, 3), (1/2, 3),
+    // (0, 7/3), (0, 3/5) and (3/2, 0): their exact intersection has an
+    // area of 493/60.
+    let square = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)];
+    let triangle = polygon![(x: -1., y: 1.), (x: 4., y: -1.), (x: 2., y: 5.)];
+    let exact = 493. / 60.;
+    let intersection = |mode: RoundingMode| {
+        let mut op = Op::new(OpType::Intersection, 0).with_rounding_mode(mode);
+        op.add_polygon(&square, true);
+        op.add_polygon(&triangle, false);
+        MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep)).unsigned_area()
+    };
+    let inner = intersection(RoundingMode::TowardInterior);
+    let outer = intersection(RoundingMode::TowardExterior);
+    assert!(inner <= exact, "{inner} > {exact}");
+    assert!(outer >= exact, "{outer} < {exact}");
+    assert_relative_eq!(inner, exact, epsilon = 1e-12);
+    assert_relative_eq!(outer, exact, epsilon = 1e-12);
+}
+
+#[test]
+fn test_make_valid() -> Result<()> {
+    // A loop crossing itself, and an overlapping square.
+    let invalid = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,2 2,0 4,-2 2,0 0,1 2,0 3,-1 2,0 0)),((0 1,3 1,3 3,0 3,0 1)))",
+    )?;
+    let valid = invalid.make_valid();
+    assert!(!valid.0.is_empty());
+    // Repairing a valid output leaves it as it is.
+    assert_eq!(valid.make_valid(), valid);
+    assert_eq!(invalid.into_make_valid(), valid);
+    Ok(())
+}
+
+#[test]
+fn test_empty_results() -> Result<()> {
+    let empty = MultiPolygon::<f64>::new(vec![]);
+    let square = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)))")?;
+    let disjoint = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((3 0,4 0,4 1,3 1,3 0)))")?;
+    let touching = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((2 0,3 0,3 2,2 2,2 0)))")?;
+    // A polygon with an empty exterior, and a degenerate one.
+    let hollow = Polygon::new(LineString::new(vec![]), vec![]);
+    let flat = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,1 1,2 2,0 0)))")?;
+
+    let cases = [
+        (&empty, &empty, OpType::Intersection),
+        (&empty, &empty, OpType::Union),
+        (&empty, &empty, OpType::Difference),
+        (&empty, &empty, OpType::Xor),
+        (&empty, &square, OpType::Intersection),
+        (&square, &empty, OpType::Intersection),
+        (&empty, &square, OpType::Difference),
+        (&square, &disjoint, OpType::Intersection),
+        (&square, &touching, OpType::Intersection),
+        (&square, &square, OpType::Difference),
+        (&square, &square, OpType::Xor),
+        (&flat, &flat, OpType::Union),
+        (&flat, &square, OpType::Intersection),
+    ];
+    for (a, b, ty) in cases {
+        assert_eq!(a.try_boolean_op(b, ty)?, empty, "{ty} of {a:?} and {b:?}");
+        assert_eq!(a.boolean_op(b, ty), empty);
+    }
+    let hollow_mp = MultiPolygon::new(vec![hollow.clone()]);
+    assert_eq!(hollow.try_boolean_op(&hollow, OpType::Union)?, empty);
+    assert_eq!(
+        hollow_mp.try_boolean_op(&square, OpType::Intersection)?,
+        empty
+    );
+
+    // The same through `Op`.
+    let mut bop = Op::new(OpType::Intersection, 0);
+    bop.try_add_multi_polygon(&square, true)?;
+    bop.try_add_multi_polygon(&disjoint, false)?;
+    assert!(bop.try_sweep()?.is_empty());
+    assert!(assemble(bop.try_sweep()?, KeepOrDrop::Keep).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_split_by() -> Result<()> {
+    let a = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,4 2,4 4,2 4,2 2)),((12 0,14 0,14 2,12 2,12 0)))",
+    )?;
+    let clip = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((3 -1,13 -1,13 5,3 5,3 -1)),((20 20,21 20,21 21,20 21,20 20)))",
+    )?;
+    let (inside, outside) = a.split_by(&clip);
+    assert_eq!(inside, a.intersection(&clip));
+    assert_eq!(outside, a.difference(&clip));
+
+    // The parts cover `a`, without overlapping.
+    assert_eq!(inside.overlap_area(&outside), 0.);
+    assert_eq!(
+        inside.unsigned_area() + outside.unsigned_area(),
+        a.unsigned_area()
+    );
+    assert_eq!(inside.union(&outside).xor(&a).unsigned_area(), 0.);
+
+    let (inside, outside) = a.0[0].split_by(&a.0[0]);
+    assert_eq!(inside.unsigned_area(), a.0[0].unsigned_area());
+    assert!(outside.0.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_reference_operand() {
+    use crate::{coord, polygon, CoordsIter};
+    let a: MultiPolygon<f64> =
+        polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)].into();
+    let b: MultiPolygon<f64> =
+        polygon![(x: 2., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 2., y: 3.)].into();
+    // Only the first edge crosses the boundary of the union, twice.
+    let reference = polygon![(x: 1., y: -1.), (x: 5., y: 4.), (x: 8., y: 4.), (x: 8., y: -1.)];
+
+    let union = |reference: Option<&Polygon<f64>>| {
+        let mut op = Op::new(OpType::Union, 16);
+        op.add_multi_polygon(&a, Operand::Subject);
+        op.add_multi_polygon(&b, Operand::Clip);
+        if let Some(reference) = reference {
+            op.add_polygon(reference, Operand::Reference);
+        }
+        MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep))
+    };
+    let plain = union(None);
+    assert_same_region(&plain, &a.union(&b));
+
+    let noded = union(Some(&reference));
+    assert_same_region(&noded, &plain);
+    assert_eq!(noded.coords_count(), plain.coords_count() + 2);
+    let has_vertex = |x, y| noded.coords_iter().any(|c| c == coord! { x: x, y: y });
+    assert!(has_vertex(1.8, 0.));
+    assert!(has_vertex(4.2, 3.));
+}
+
+#[test]
+fn test_normalize_operands() {
+    use crate::polygon;
+    let squares = MultiPolygon::new(vec![
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+        polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+    ]);
+    let other = polygon![(x: 2.5, y: 0.), (x: 5., y: 0.), (x: 5., y: 2.), (x: 2.5, y: 2.)];
+
+    let run = |ty, normalize| {
+        let mut op = Op::new(ty, 12).normalize_operands(normalize);
+        op.add_multi_polygon(&squares, Operand::Subject);
+        op.add_polygon(&other, Operand::Clip);
+        MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep))
+    };
+    let union = run(OpType::Union, true);
+    assert_same_region(&union, &unary_union(squares.iter().chain([&other])));
+    assert_eq!(union.unsigned_area(), 7. + 5. - 0.5);
+
+    // The overlap of the squares is lost without normalizing.
+    assert!(run(OpType::Union, false).unsigned_area() < union.unsigned_area());
+
+    let intersection = run(OpType::Intersection, true);
+    assert_same_region(
+        &intersection,
+        &polygon![(x: 2.5, y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2.5, y: 2.)].into(),
+    );
+}
+
+#[test]
+fn test_write_wkt() {
+    let a: MultiPolygon<f64> = MultiPolygon::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,8 2,8 8,2 8,2 2)),((20 0,30 0,30 5,20 5,20 0)))",
+    )
+    .unwrap();
+    let b: MultiPolygon<f64> =
+        MultiPolygon::try_from_wkt_str("MULTIPOLYGON(((5 -1,25 -1,25 1,5 1,5 -1)))").unwrap();
+
+    let write = |ty| {
+        let mut op = Op::new(ty, 32);
+        op.add_multi_polygon(&a, Operand::Subject);
+        op.add_multi_polygon(&b, Operand::Clip);
+        let mut out = vec![];
+        op.write_wkt(&mut out).unwrap();
+        (String::from_utf8(out).unwrap(), op)
+    };
+    let (wkt, op) = write(OpType::Union);
+    let parsed = MultiPolygon::<f64>::try_from_wkt_str(&wkt).unwrap();
+    assert_eq!(
+        parsed,
+        MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep))
+    );
+    assert_same_region(&parsed, &a.union(&b));
+
+    let (wkt, _) = write(OpType::Intersection);
+    let parsed = MultiPolygon::<f64>::try_from_wkt_str(&wkt).unwrap();
+    assert_same_region(&parsed, &a.intersection(&b));
+
+    let mut op = Op::<f64>::new(OpType::Union, 0);
+    let mut out = vec![];
+    op.add_multi_polygon(&MultiPolygon::new(vec![]), Operand::Subject);
+    op.write_wkt(&mut out).unwrap();
+    assert_eq!(out, b"MULTIPOLYGON EMPTY");
+}
+
+#[test]
+fn test_dedup_inputs() {
+    use crate::polygon;
+    let frame = polygon![
+        exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+        interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+    ];
+    // The frame again, starting elsewhere and in the other orientation.
+    let again = polygon![
+        exterior: [(x: 6., y: 6.), (x: 6., y: 0.), (x: 0., y: 0.), (x: 0., y: 6.)],
+        interiors: [[(x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.), (x: 4., y: 2.)]],
+    ];
+    // Its exterior is the hole of the frame, so it is kept.
+    let plug = polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)];
+    let other = polygon![(x: 5., y: 1.), (x: 8., y: 1.), (x: 8., y: 3.), (x: 5., y: 3.)];
+
+    let run = |subject: &[&Polygon<f64>]| {
+        let mut op = Op::new(OpType::Union, 32).dedup_inputs(true);
+        for poly in subject {
+            op.add_polygon(poly, Operand::Subject);
+        }
+        op.add_polygon(&other, Operand::Clip);
+        let output = MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep));
+        (output, op.statistics().input_segments)
+    };
+    let (once, segments) = run(&[&frame]);
+    assert_eq!(run(&[&frame, &frame, &again]), (once.clone(), segments));
+    assert_same_region(
+        &once,
+        &MultiPolygon::from(frame.clone()).union(&other.clone().into()),
+    );
+
+    let (filled, _) = run(&[&frame, &plug, &again]);
+    assert_eq!(filled.0.len(), 1);
+    assert!(filled.0[0].interiors().is_empty());
+
+    // The same polygon in both operands is kept.
+    let mut op = Op::new(OpType::Intersection, 16).dedup_inputs(true);
+    op.add_polygon(&frame, Operand::Subject);
+    op.add_polygon(&again, Operand::Clip);
+    let both = MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep));
+    assert_same_region(&both, &frame.clone().into());
+
+    // The frame with another hole is another polygon: its exterior is
+    // kept with its hole, and only the overlap of the holes is empty.
+    let shifted = polygon![
+        exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+        interiors: [[(x: 3., y: 3.), (x: 5., y: 3.), (x: 5., y: 5.), (x: 3., y: 5.)]],
+    ];
+    let mut op = Op::new(OpType::Union, 16)
+        .dedup_inputs(true)
+        .normalize_operands(true);
+    op.add_polygon(&frame, Operand::Subject);
+    op.add_polygon(&shifted, Operand::Subject);
+    op.add_polygon(&frame, Operand::Subject);
+    let output = MultiPolygon::from(assemble(op.sweep(), KeepOrDrop::Keep));
+    let overlap = polygon![
+        exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+        interiors: [[(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.), (x: 3., y: 4.)]],
+    ];
+    assert_same_region(&output, &overlap.into());
+}
+
+#[test]
+fn test_collection_op() {
+    use crate::{line_string, point, polygon, Geometry, GeometryCollection};
+    let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let clip = polygon![(x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 1., y: 3.)];
+    let a = GeometryCollection::new_from(vec![
+        square.clone().into(),
+        // Crossing the clip, and running into the square.
+        line_string![(x: 3., y: -1.), (x: 3., y: 5.)].into(),
+        line_string![(x: -1., y: 1.), (x: 1., y: 1.)].into(),
+        point!(x: 3., y: 1.).into(),
+        point!(x: 5., y: 1.).into(),
+    ]);
+    let b = GeometryCollection::new_from(vec![clip.clone().into()]);
+
+    let polygons = |gc: &GeometryCollection<f64>| -> MultiPolygon<f64> {
+        gc.iter()
+            .filter_map(|g| match g {
+                Geometry::Polygon(p) => Some(p.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+    let others = |gc: &GeometryCollection<f64>| -> Vec<Geometry<f64>> {
+        gc.iter()
+            .filter(|g| !matches!(g, Geometry::Polygon(_)))
+            .cloned()
+            .collect()
+    };
+
+    let output = collection_op(&a, &b, OpType::Intersection);
+    assert_same_region(&polygons(&output), &square.intersection(&clip));
+    assert_eq!(
+        others(&output),
+        vec![
+            line_string![(x: 3., y: 0.), (x: 3., y: 3.)].into(),
+            point!(x: 3., y: 1.).into(),
+        ]
+    );
+
+    let output = collection_op(&a, &b, OpType::Difference);
+    assert_same_region(&polygons(&output), &square.difference(&clip));
+    assert_eq!(
+        others(&output),
+        vec![
+            line_string![(x: 3., y: -1.), (x: 3., y: 0.)].into(),
+            line_string![(x: 3., y: 3.), (x: 3., y: 5.)].into(),
+            line_string![(x: -1., y: 1.), (x: 0., y: 1.)].into(),
+            point!(x: 5., y: 1.).into(),
+        ]
+    );
+
+    // The pieces covered by the union are part of it.
+    let output = collection_op(&a, &b, OpType::Union);
+    assert_same_region(&polygons(&output), &square.union(&clip));
+    assert_eq!(
+        others(&output),
+        vec![
+            line_string![(x: 3., y: -1.), (x: 3., y: 0.)].into(),
+            line_string![(x: 3., y: 3.), (x: 3., y: 5.)].into(),
+            line_string![(x: -1., y: 1.), (x: 0., y: 1.)].into(),
+            point!(x: 5., y: 1.).into(),
+        ]
+    );
+}
+
+#[test]
+fn test_collection_op_lines() {
+    use crate::{line_string, point, Geometry, GeometryCollection};
+    // Overlapping along [2, 4] on the x-axis, and crossing at (1, 0).
+    let a = GeometryCollection::new_from(vec![line_string![(x: 0., y: 0.), (x: 4., y: 0.)].into()]);
+    let b = GeometryCollection::new_from(vec![
+        line_string![(x: 2., y: 0.), (x: 6., y: 0.)].into(),
+        line_string![(x: 1., y: -1.), (x: 1., y: 1.)].into(),
+    ]);
+    let output = |op| collection_op(&a, &b, op).0;
+
+    let intersection: Vec<Geometry<f64>> = vec![
+        line_string![(x: 2., y: 0.), (x: 4., y: 0.)].into(),
+        point!(x: 1., y: 0.).into(),
+    ];
+    assert_eq!(output(OpType::Intersection), intersection);
+    // The shared piece is output once.
+    let union: Vec<Geometry<f64>> = vec![
+        line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)].into(),
+        line_string![(x: 4., y: 0.), (x: 6., y: 0.)].into(),
+        line_string![(x: 1., y: -1.), (x: 1., y: 0.), (x: 1., y: 1.)].into(),
+    ];
+    assert_eq!(output(OpType::Union), union);
+    let difference: Vec<Geometry<f64>> =
+        vec![line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)].into()];
+    assert_eq!(output(OpType::Difference), difference);
+    let xor: Vec<Geometry<f64>> = vec![
+        line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)].into(),
+        line_string![(x: 4., y: 0.), (x: 6., y: 0.)].into(),
+        line_string![(x: 1., y: -1.), (x: 1., y: 0.), (x: 1., y: 1.)].into(),
+    ];
+    assert_eq!(output(OpType::Xor), xor);
+}
+
+#[test]
+fn test_union_gap_closing() -> Result<()> {
+    // Two squares with a gap of 0.5mm along x = 1.
+    let a = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,1 0,1 1,0 1,0 0)))")?;
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1.0005 0,2 0,2 1,1.0005 1,1.0005 0)))",
+    )?;
+    assert_eq!(a.union(&b).0.len(), 2);
+
+    let merged = a.union_gap_closing(&b, 0.001);
+    assert_eq!(merged.0.len(), 1);
+    assert_eq!(merged.0[0].interiors().len(), 0);
+    assert_relative_eq!(merged.unsigned_area(), 2.);
+
+    let apart = a.union_gap_closing(&b, 0.0001);
+    assert_eq!(apart.0.len(), 2);
+    assert_relative_eq!(apart.unsigned_area(), a.union(&b).unsigned_area());
+
+    // A smaller square, snapped to the middle of an edge of `a`.
+    let c = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((1.0005 0.25,1.5 0.25,1.5 0.75,1.0005 0.75,1.0005 0.25)))",
+    )?;
+    let merged = a.union_gap_closing(&c, 0.001);
+    assert_eq!(merged.0.len(), 1);
+    assert_relative_eq!(merged.unsigned_area(), 1.25);
+    assert_eq!(c.union_gap_closing(&a, 0.001).0.len(), 1);
+
+    // Snapped to the middle of an edge of `a` starting far to the left.
+    let d = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0.4 -0.5,0.6 -0.5,0.6 -0.0005,0.4 -0.0005,0.4 -0.5)))",
+    )?;
+    let merged = a.union_gap_closing(&d, 0.001);
+    assert_eq!(merged.0.len(), 1);
+    assert_relative_eq!(merged.unsigned_area(), 1.1);
+    Ok(())
+}
+
+#[test]
+fn test_pinch_policy() -> Result<()> {
+    // An hourglass: two triangles touching at (1, 1).
+    let a = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,2 0,1 1,0 0)),((1 1,2 2,0 2,1 1)))",
+    )?;
+    let mut bop = Op::new(OpType::Union, 0);
+    bop.add_multi_polygon(&a, true);
+
+    let split = assemble_pinched(bop.sweep(), KeepOrDrop::Keep, PinchPolicy::Split);
+    assert_eq!(split.len(), 2);
+    for triangle in &split {
+        assert_eq!(triangle.exterior().0.len(), 4);
+        assert_relative_eq!(triangle.unsigned_area(), 1.);
+    }
+
+    let kept = assemble_pinched(bop.sweep(), KeepOrDrop::Keep, PinchPolicy::Keep);
+    assert_eq!(kept.len(), 1);
+    let exterior = kept[0].exterior();
+    assert!(exterior.is_closed());
+    assert_eq!(exterior.0.len(), 7);
+    assert_relative_eq!(kept[0].unsigned_area(), 2.);
+    let pinch = vec![crate::coord! { x: 1., y: 1. }];
+    assert_eq!(pinch_vertices(&split), pinch);
+    assert_eq!(pinch_vertices(&kept), pinch);
+
+    // A hole touching the exterior at (0, 1) is joined to it as an
+    // inward loop, and the other hole is kept.
+    let b = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,4 0,4 4,0 4,0 0),(0 1,2 0.5,2 1.5,0 1),(3 3,3.5 3,3.5 3.5,3 3)))",
+    )?;
+    let mut bop = Op::new(OpType::Union, 0);
+    bop.add_multi_polygon(&b, true);
+    let split = assemble_pinched(bop.sweep(), KeepOrDrop::Keep, PinchPolicy::Split);
+    assert_eq!(split.len(), 1);
+    assert_eq!(split[0].interiors().len(), 2);
+
+    let kept = assemble_pinched(bop.sweep(), KeepOrDrop::Keep, PinchPolicy::Keep);
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].interiors().len(), 1);
+    // The exterior has a vertex at (0, 1) from the sweep, and the hole
+    // three more.
+    assert_eq!(kept[0].exterior().0.len(), 6 + 3);
+    assert_relative_eq!(kept[0].unsigned_area(), split[0].unsigned_area());
+    let pinch = vec![crate::coord! { x: 0., y: 1. }];
+    assert_eq!(pinch_vertices(&split), pinch);
+    assert_eq!(pinch_vertices(&kept), pinch);
+    Ok(())
+}
+
+#[test]
+fn test_boolean_op_f32_accumulate_f64() {
+    use crate::{coord, polygon, Convert, IsValidReason, MapCoords};
+
+    // Two triangles with nearly parallel edges, a few units in the last
+    // place of `f32` apart.
+    let a: MultiPolygon<f32> = polygon![
+        (x: 1000.0065, y: 1000.00964),
+        (x: 1000.00696, y: 1000.00726),
+        (x: 1000.0091, y: 1000.00525),
+    ]
+    .into();
+    let b: MultiPolygon<f32> = polygon![
+        (x: 1000.0044, y: 1000.0051),
+        (x: 1000.0089, y: 1000.00867),
+        (x: 1000.00415, y: 1000.00525),
+    ]
+    .into();
+
+    let union = a.boolean_op_f32_accumulate_f64(&b, OpType::Union).unwrap();
+    assert_eq!(union.is_valid_reason(), None);
+    let (a64, b64): (MultiPolygon<f64>, MultiPolygon<f64>) = (a.convert(), b.convert());
+    // The union in `f64`, rounded to `f32`.
+    let exact = a64.union(&b64);
+    let rounded = exact.map_coords(|c| coord! { x: c.x as f32, y: c.y as f32 });
+    assert_eq!(union, rounded);
+}
@@ -0,0 +1,69 @@
+use super::{
+    assemble,
+    depth::{add_polygon, sweep_classes},
+    KeepOrDrop, Operand,
+};
+use crate::{GeoFloat as Float, MultiPolygon};
+
+/// The regions of a Venn diagram of two polygonal geometries: the parts
+/// covered by only one of them, and by both.
+///
+/// The regions are disjoint, and their union is the union of the
+/// inputs. They may only share boundaries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VennRegions<T: Float> {
+    /// The part of `a` outside of `b`.
+    pub a_only: MultiPolygon<T>,
+    /// The part of `b` outside of `a`.
+    pub b_only: MultiPolygon<T>,
+    /// The intersection of `a` and `b`.
+    pub both: MultiPolygon<T>,
+}
+
+/// Split two polygonal geometries into the regions of their Venn
+/// diagram, from a single sweep.
+///
+/// The three regions are the differences and the intersection of the
+/// inputs, as computed by [`BooleanOps`](super::BooleanOps), but the
+/// sweep tracks the number of polygons of each input covering a region,
+/// and assembles the boundaries of all three at once. The polygons of
+/// an input may overlap each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::bool_ops::venn;
+/// use geo::{polygon, Area, MultiPolygon};
+///
+/// let a: MultiPolygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)].into();
+/// let b: MultiPolygon<f64> =
+///     polygon![(x: 3., y: 0.), (x: 5., y: 0.), (x: 5., y: 2.), (x: 3., y: 2.)].into();
+///
+/// let regions = venn(&a, &b);
+/// assert_eq!(regions.a_only.unsigned_area(), 6.);
+/// assert_eq!(regions.b_only.unsigned_area(), 2.);
+/// assert_eq!(regions.both.unsigned_area(), 2.);
+/// ```
+pub fn venn<T: Float>(a: &MultiPolygon<T>, b: &MultiPolygon<T>) -> VennRegions<T> {
+    let mut edges = vec![];
+    for poly in a {
+        add_polygon(&mut edges, poly, Operand::Subject);
+    }
+    for poly in b {
+        add_polygon(&mut edges, poly, Operand::Clip);
+    }
+    let mut classes = sweep_classes(&edges, 3, |depth| match (depth[0] > 0, depth[1] > 0) {
+        (true, false) => Some(0),
+        (false, true) => Some(1),
+        (true, true) => Some(2),
+        (false, false) => None,
+    })
+    .into_iter()
+    .map(|rings| MultiPolygon::from(assemble(rings, KeepOrDrop::Keep)));
+    VennRegions {
+        a_only: classes.next().unwrap(),
+        b_only: classes.next().unwrap(),
+        both: classes.next().unwrap(),
+    }
+}
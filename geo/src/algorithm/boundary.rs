@@ -0,0 +1,110 @@
+use crate::{CoordNum, LineString, MultiLineString, MultiPolygon, Polygon, Rect, Triangle};
+
+/// The boundary of an areal geometry as a [`MultiLineString`].
+///
+/// Every ring, exterior and interior, is emitted as a closed
+/// `LineString`: first the exterior of each polygon, followed by its
+/// holes. This differs from [`Polygon::exterior`], which drops the
+/// holes. Empty rings are skipped, so the boundary of an empty geometry
+/// is an empty `MultiLineString`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Boundary};
+///
+/// let poly = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+/// ];
+///
+/// let boundary = poly.boundary();
+/// assert_eq!(boundary.0.len(), 2);
+/// assert_eq!(&boundary.0[0], poly.exterior());
+/// assert_eq!(&boundary.0[1], &poly.interiors()[0]);
+/// ```
+pub trait Boundary<T: CoordNum> {
+    fn boundary(&self) -> MultiLineString<T>;
+}
+
+fn push_rings<T: CoordNum>(poly: &Polygon<T>, output: &mut Vec<LineString<T>>) {
+    output.extend(
+        std::iter::once(poly.exterior())
+            .chain(poly.interiors())
+            .filter(|ring| !ring.0.is_empty())
+            .cloned(),
+    );
+}
+
+impl<T: CoordNum> Boundary<T> for Polygon<T> {
+    fn boundary(&self) -> MultiLineString<T> {
+        let mut rings = Vec::with_capacity(1 + self.interiors().len());
+        push_rings(self, &mut rings);
+        MultiLineString::new(rings)
+    }
+}
+
+impl<T: CoordNum> Boundary<T> for MultiPolygon<T> {
+    fn boundary(&self) -> MultiLineString<T> {
+        let mut rings = vec![];
+        self.iter().for_each(|poly| push_rings(poly, &mut rings));
+        MultiLineString::new(rings)
+    }
+}
+
+impl<T: CoordNum> Boundary<T> for Rect<T> {
+    fn boundary(&self) -> MultiLineString<T> {
+        self.to_polygon().boundary()
+    }
+}
+
+impl<T: CoordNum> Boundary<T> for Triangle<T> {
+    fn boundary(&self) -> MultiLineString<T> {
+        self.to_polygon().boundary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, polygon};
+
+    #[test]
+    fn test_multi_polygon_boundary() {
+        let a = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let b = polygon![(x: 5., y: 0.), (x: 6., y: 0.), (x: 6., y: 1.)];
+        let mp = MultiPolygon::new(vec![a.clone(), b.clone()]);
+
+        let boundary = mp.boundary();
+        assert_eq!(
+            boundary.0,
+            vec![
+                a.exterior().clone(),
+                a.interiors()[0].clone(),
+                b.exterior().clone()
+            ]
+        );
+        assert!(boundary.iter().all(|ls| ls.is_closed()));
+    }
+
+    #[test]
+    fn test_empty_boundary() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert!(empty.boundary().0.is_empty());
+        assert!(MultiPolygon::<f64>::new(vec![empty])
+            .boundary()
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rect_boundary() {
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 2. });
+        let boundary = rect.boundary();
+        assert_eq!(boundary.0.len(), 1);
+        assert_eq!(&boundary.0[0], rect.to_polygon().exterior());
+    }
+}
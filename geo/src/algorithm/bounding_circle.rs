@@ -0,0 +1,207 @@
+use num_traits::NumCast;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::{ConvexHull, Coordinate, GeoFloat, MultiPolygon, Point, Polygon};
+
+/// Calculation of the smallest circle enclosing a geometry, as its
+/// center and radius.
+///
+/// The circle is found by Welzl's algorithm, over the vertices of the
+/// convex hull of the geometry. Whether a vertex is in a circle through
+/// three others is decided by the robust `incircle` predicate, so that
+/// only the center and radius of the final circle are rounded.
+///
+/// A geometry with a single distinct vertex has a circle of radius zero,
+/// and one with two has their segment as diameter. Empty geometries have
+/// no bounding circle.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, BoundingCircle};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let (center, radius) = square.bounding_circle().unwrap();
+///
+/// assert_eq!(center, point!(x: 1., y: 1.));
+/// assert_eq!(radius, 2f64.sqrt());
+/// ```
+pub trait BoundingCircle<T: GeoFloat> {
+    fn bounding_circle(&self) -> Option<(Point<T>, T)>;
+}
+
+impl<T: GeoFloat> BoundingCircle<T> for Polygon<T> {
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        welzl(self.convex_hull().exterior().0.clone())
+    }
+}
+
+impl<T: GeoFloat> BoundingCircle<T> for MultiPolygon<T> {
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        welzl(self.convex_hull().exterior().0.clone())
+    }
+}
+
+/// A circle through one, two or three points, on its boundary.
+#[derive(Debug, Clone, Copy)]
+enum Circle<T: GeoFloat> {
+    One(Coordinate<T>),
+    /// The circle with the segment between the points as diameter.
+    Two(Coordinate<T>, Coordinate<T>),
+    /// The circle through three points, not collinear.
+    Three(Coordinate<T>, Coordinate<T>, Coordinate<T>),
+}
+
+impl<T: GeoFloat> Circle<T> {
+    fn three(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Self {
+        if T::Ker::orient2d(a, b, c) != Orientation::Collinear {
+            return Circle::Three(a, b, c);
+        }
+        // Only reached through rounding: use the widest pair.
+        let length = |(p, q): (Coordinate<T>, Coordinate<T>)| (q - p).x.hypot((q - p).y);
+        let (p, q) = [(a, b), (b, c), (a, c)]
+            .into_iter()
+            .max_by(|x, y| length(*x).partial_cmp(&length(*y)).unwrap())
+            .unwrap();
+        Circle::Two(p, q)
+    }
+
+    /// Whether `p` is inside or on the circle.
+    fn contains(&self, p: Coordinate<T>) -> bool {
+        match *self {
+            Circle::One(a) => p == a,
+            Circle::Two(a, b) => {
+                // The angle at `p` is not acute.
+                let (u, v) = (a - p, b - p);
+                u.x * v.x + u.y * v.y <= T::zero()
+            }
+            Circle::Three(a, b, c) => {
+                let det = robust::incircle(to_robust(a), to_robust(b), to_robust(c), to_robust(p));
+                match T::Ker::orient2d(a, b, c) {
+                    Orientation::CounterClockwise => det >= 0.,
+                    _ => det <= 0.,
+                }
+            }
+        }
+    }
+
+    fn center_and_radius(&self) -> (Point<T>, T) {
+        let two = T::one() + T::one();
+        match *self {
+            Circle::One(a) => (a.into(), T::zero()),
+            Circle::Two(a, b) => {
+                let d = b - a;
+                ((a + d / two).into(), d.x.hypot(d.y) / two)
+            }
+            Circle::Three(a, b, c) => {
+                // Relative to `a`, for precision.
+                let (b, c) = (b - a, c - a);
+                let (b2, c2) = (b.x * b.x + b.y * b.y, c.x * c.x + c.y * c.y);
+                let d = two * (b.x * c.y - b.y * c.x);
+                let u = Coordinate {
+                    x: (c.y * b2 - b.y * c2) / d,
+                    y: (b.x * c2 - c.x * b2) / d,
+                };
+                let radius = [u, u - b, u - c]
+                    .iter()
+                    .map(|r| r.x.hypot(r.y))
+                    .fold(T::zero(), T::max);
+                ((a + u).into(), radius)
+            }
+        }
+    }
+}
+
+pub(crate) fn to_robust<T: GeoFloat>(c: Coordinate<T>) -> robust::Coord<f64> {
+    robust::Coord {
+        x: <f64 as NumCast>::from(c.x).unwrap(),
+        y: <f64 as NumCast>::from(c.y).unwrap(),
+    }
+}
+
+fn welzl<T: GeoFloat>(mut points: Vec<Coordinate<T>>) -> Option<(Point<T>, T)> {
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    // The algorithm takes expected linear time on a random order of the
+    // points; a fixed permutation keeps the result deterministic.
+    let mut state: u32 = 0x9e37_79b9;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        points.swap(i, state as usize % (i + 1));
+    }
+
+    let mut circle = Circle::One(*points.first()?);
+    for i in 1..points.len() {
+        if circle.contains(points[i]) {
+            continue;
+        }
+        // `points[i]` is on the boundary of the circle of the first
+        // `i + 1` points.
+        circle = Circle::One(points[i]);
+        for j in 0..i {
+            if circle.contains(points[j]) {
+                continue;
+            }
+            circle = Circle::Two(points[i], points[j]);
+            for k in 0..j {
+                if !circle.contains(points[k]) {
+                    circle = Circle::three(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    Some(circle.center_and_radius())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, EuclideanDistance};
+
+    #[test]
+    fn square() {
+        let square = polygon![(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 5.), (x: 1., y: 5.)];
+        let (center, radius) = square.bounding_circle().unwrap();
+        assert_eq!(center, point!(x: 3., y: 3.));
+        assert_relative_eq!(radius, 32f64.sqrt() / 2.);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).bounding_circle(), None);
+
+        let point = polygon![(x: 1., y: 2.), (x: 1., y: 2.), (x: 1., y: 2.)];
+        assert_eq!(point.bounding_circle(), Some((point!(x: 1., y: 2.), 0.)));
+
+        let segment = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(segment.bounding_circle(), Some((point!(x: 2., y: 0.), 2.)));
+
+        // An obtuse triangle is enclosed by the circle on its longest side.
+        let obtuse = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 1.)];
+        assert_eq!(obtuse.bounding_circle(), Some((point!(x: 2., y: 0.), 2.)));
+    }
+
+    #[test]
+    fn encloses_all_vertices() {
+        let shapes: MultiPolygon<f64> = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 3., y: 1.), (x: 1., y: 4.)],
+            polygon![(x: 7., y: -2.), (x: 9., y: 0.5), (x: 6., y: 3.), (x: 5.5, y: 0.)],
+            polygon![(x: 2., y: 8.), (x: 4., y: 7.), (x: 3., y: 9.5)],
+        ]);
+        let (center, radius) = shapes.bounding_circle().unwrap();
+        let mut on_boundary = 0;
+        for p in shapes.iter().flat_map(|poly| poly.exterior().points()) {
+            let distance = p.euclidean_distance(&center);
+            assert!(distance <= radius * (1. + 1e-12));
+            if relative_eq!(distance, radius) {
+                on_boundary += 1;
+            }
+        }
+        assert!(on_boundary >= 2);
+    }
+}
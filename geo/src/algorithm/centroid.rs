@@ -183,6 +183,29 @@ where
     }
 }
 
+/// The centroid of the parts of the highest dimension of the collection.
+///
+/// The polygons are weighted by their area, the lines by their length,
+/// and the points by their count. As in OGC, the parts of a lower
+/// dimension do not contribute at all when the collection has parts of
+/// a higher dimension: a point is infinitely small compared to a line,
+/// and a line infinitely thin compared to a polygon. Degenerate parts
+/// count with their actual dimension, e.g. a polygon with no area as a
+/// line.
+///
+/// ```
+/// use geo::Centroid;
+/// use geo::{point, polygon, Geometry, GeometryCollection, Line};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Polygon(square),
+///     Geometry::Line(Line::new((10., 10.), (20., 10.))),
+///     Geometry::Point(point!(x: 100., y: 100.)),
+/// ]);
+///
+/// assert_eq!(collection.centroid(), Some(point!(x: 1., y: 1.)));
+/// ```
 impl<T> Centroid for GeometryCollection<T>
 where
     T: GeoFloat,
@@ -806,6 +829,29 @@ mod test {
         assert_eq!(collection.centroid().unwrap(), point!(x: 1.0, y: 1.0));
     }
     #[test]
+    fn collection_dimension_dominates() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let triangle = polygon![(x: 10., y: 0.), (x: 16., y: 0.), (x: 10., y: 6.)];
+        let mut collection = GeometryCollection::new_from(vec![
+            point!(x: -10., y: 50.).into(),
+            square.clone().into(),
+            point!(x: 30., y: -7.).into(),
+            MultiPoint::new(vec![point!(x: 1., y: 1.), point!(x: 9., y: 9.)]).into(),
+        ]);
+        // The stray points do not move the centroid of the polygon.
+        assert_eq!(collection.centroid(), Some(square.centroid().unwrap()));
+
+        // Polygons are weighted by area, regardless of the lines.
+        collection.0.push(triangle.clone().into());
+        collection
+            .0
+            .push(Line::new(c(100., 100.), c(200., 100.)).into());
+        let (a, b) = (square.unsigned_area(), triangle.unsigned_area());
+        let expected =
+            (square.centroid().unwrap() * a + triangle.centroid().unwrap() * b) / (a + b);
+        assert_relative_eq!(collection.centroid().unwrap(), expected);
+    }
+    #[test]
     fn triangles() {
         // boring triangle
         assert_eq!(
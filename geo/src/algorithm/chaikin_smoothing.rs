@@ -14,6 +14,25 @@ use crate::{coord, CoordFloat, Coordinate, LineString, MultiLineString, MultiPol
 ///
 /// This implementation preserves the start and end vertices of an open linestring and
 /// smoothes the corner between start and end of a closed linestring.
+///
+/// The rings of polygons are smoothed independently, and each iteration cuts off
+/// their corners. A ring may thus intersect itself if a corner is cut across a
+/// narrow part of it, and a hole may cross the exterior if they are close. The
+/// output of a few iterations on polygons whose features are large compared to
+/// their edges is valid, but this is not checked.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, ChaikinSmoothing};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// let smooth = square.chaikin_smoothing(1);
+///
+/// // An octagon, closed.
+/// assert_eq!(smooth.exterior().0.len(), 9);
+/// assert_eq!(smooth.unsigned_area(), 14.);
+/// ```
 pub trait ChaikinSmoothing<T>
 where
     T: CoordFloat + FromPrimitive,
@@ -134,7 +153,7 @@ where
 #[cfg(test)]
 mod test {
     use crate::ChaikinSmoothing;
-    use crate::{LineString, Polygon};
+    use crate::{polygon, LineString, Polygon};
 
     #[test]
     fn linestring_open() {
@@ -209,4 +228,30 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn square_to_octagon() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let octagon = square.chaikin_smoothing(1);
+        assert!(octagon.exterior().is_closed());
+        assert_eq!(
+            octagon.exterior(),
+            &LineString::from(vec![
+                (1., 0.),
+                (3., 0.),
+                (4., 1.),
+                (4., 3.),
+                (3., 4.),
+                (1., 4.),
+                (0., 3.),
+                (0., 1.),
+                (1., 0.),
+            ])
+        );
+
+        // Each iteration doubles the vertices, and keeps the ring closed.
+        let smooth = square.chaikin_smoothing(3);
+        assert_eq!(smooth.exterior().0.len(), 4 * 8 + 1);
+        assert!(smooth.exterior().is_closed());
+    }
 }
@@ -155,6 +155,10 @@ impl<F: GeoFloat> ClosestPoint<F> for Rect<F> {
 
 impl<F: GeoFloat> ClosestPoint<F> for MultiPolygon<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        // Check all the polygons before measuring any of the boundaries.
+        if self.intersects(p) {
+            return Closest::Intersection(*p);
+        }
         closest_of(self.iter(), *p)
     }
 }
@@ -344,5 +348,11 @@ mod tests {
 
         let result = multi_polygon.closest_point(&point!(x: 10.5, y: 10.5));
         assert_eq!(result, Closest::Intersection(point!(x: 10.5, y: 10.5)));
+
+        let result = multi_polygon.closest_point(&point!(x: 50.5, y: 51.0));
+        assert_eq!(result, Closest::Intersection(point!(x: 50.5, y: 51.0)));
+
+        let result = multi_polygon.closest_point(&point!(x: 53.0, y: 50.5));
+        assert_eq!(result, Closest::SinglePoint(point!(x: 51.0, y: 50.5)));
     }
 }
@@ -0,0 +1,115 @@
+use crate::{Coordinate, GeoFloat, LineString, Point, SquaredEuclideanDistance};
+
+/// Find the vertex, or the segment, of a geometry nearest to a point.
+///
+/// Unlike [`ClosestPoint`](crate::ClosestPoint), this reports the
+/// index of the nearest part, e.g. to edit the geometry there. Ties are
+/// resolved in favour of the smallest index.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, ClosestPointIndex, Coordinate};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// assert_eq!(ls.nearest_vertex(&point!(x: 8., y: 1.)), Some(1));
+/// assert_eq!(
+///     ls.nearest_segment(&point!(x: 8., y: 1.)),
+///     Some((0, Coordinate { x: 8., y: 0. }))
+/// );
+/// ```
+pub trait ClosestPointIndex<T: GeoFloat> {
+    /// The index of the vertex nearest to `p`, or `None` if there are
+    /// no vertices.
+    fn nearest_vertex(&self, p: &Point<T>) -> Option<usize>;
+
+    /// The index of the segment nearest to `p`, with the point of the
+    /// segment nearest to `p`, or `None` if there are no segments.
+    ///
+    /// The segment at index `i` joins the vertices `i` and `i + 1`.
+    fn nearest_segment(&self, p: &Point<T>) -> Option<(usize, Coordinate<T>)>;
+}
+
+impl<T: GeoFloat> ClosestPointIndex<T> for LineString<T> {
+    fn nearest_vertex(&self, p: &Point<T>) -> Option<usize> {
+        nearest(
+            self.0
+                .iter()
+                .map(|c| (*c, c.squared_euclidean_distance(&p.0))),
+        )
+        .map(|(idx, _)| idx)
+    }
+
+    fn nearest_segment(&self, p: &Point<T>) -> Option<(usize, Coordinate<T>)> {
+        nearest(self.lines().map(|line| {
+            let c = project(line.start, line.end, p.0);
+            (c, c.squared_euclidean_distance(&p.0))
+        }))
+    }
+}
+
+/// The index and coordinate of the item with the smallest distance, the
+/// first one on ties.
+fn nearest<T: GeoFloat>(
+    items: impl Iterator<Item = (Coordinate<T>, T)>,
+) -> Option<(usize, Coordinate<T>)> {
+    items
+        .enumerate()
+        .fold(None, |best, (idx, (c, d))| match best {
+            Some((_, _, best_d)) if best_d <= d => best,
+            _ => Some((idx, c, d)),
+        })
+        .map(|(idx, c, _)| (idx, c))
+}
+
+/// The point of the segment `start`-`end` nearest to `p`.
+pub(crate) fn project<T: GeoFloat>(
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    p: Coordinate<T>,
+) -> Coordinate<T> {
+    let dir = end - start;
+    let len_2 = dir.x * dir.x + dir.y * dir.y;
+    if len_2 == T::zero() {
+        return start;
+    }
+    let to_p = p - start;
+    let t = (to_p.x * dir.x + to_p.y * dir.y) / len_2;
+    let t = t.max(T::zero()).min(T::one());
+    start + dir * t
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn empty_and_single_vertex() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.nearest_vertex(&point!(x: 0., y: 0.)), None);
+        assert_eq!(empty.nearest_segment(&point!(x: 0., y: 0.)), None);
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.nearest_vertex(&point!(x: 0., y: 0.)), Some(0));
+        assert_eq!(single.nearest_segment(&point!(x: 0., y: 0.)), None);
+    }
+
+    #[test]
+    fn ties_resolve_to_smallest_index() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let center = point!(x: 1., y: 1.);
+        assert_eq!(ls.nearest_vertex(&center), Some(0));
+        assert_eq!(
+            ls.nearest_segment(&center),
+            Some((0, Coordinate { x: 1., y: 0. }))
+        );
+
+        // Beyond the end of the last segment.
+        assert_eq!(
+            ls.nearest_segment(&point!(x: -1., y: 3.)),
+            Some((2, Coordinate { x: 0., y: 2. }))
+        );
+    }
+}
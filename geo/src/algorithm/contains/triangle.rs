@@ -1,5 +1,6 @@
 use super::Contains;
-use crate::{Coordinate, GeoNum, LineString, Point, Triangle};
+use crate::kernels::{Kernel, Orientation};
+use crate::{Coordinate, GeoNum, Point, Triangle};
 
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
@@ -9,10 +10,18 @@ impl<T> Contains<Coordinate<T>> for Triangle<T>
 where
     T: GeoNum,
 {
+    /// Whether `coord` is in the interior of the triangle, by the robust
+    /// orientation of `coord` relative to each edge: it is inside if it
+    /// is strictly on the same side of all three. The boundary is not
+    /// contained, and a degenerate triangle contains nothing.
     fn contains(&self, coord: &Coordinate<T>) -> bool {
-        let ls = LineString::new(vec![self.0, self.1, self.2, self.0]);
-        use crate::utils::{coord_pos_relative_to_ring, CoordPos};
-        coord_pos_relative_to_ring(*coord, &ls) == CoordPos::Inside
+        let orientation = T::Ker::orient2d(self.0, self.1, self.2);
+        if orientation == Orientation::Collinear {
+            return false;
+        }
+        [(self.0, self.1), (self.1, self.2), (self.2, self.0)]
+            .iter()
+            .all(|&(start, end)| T::Ker::orient2d(start, end, *coord) == orientation)
     }
 }
 
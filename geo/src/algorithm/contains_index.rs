@@ -0,0 +1,150 @@
+use crate::{BoundingRect, Contains, GeoFloat, Polygon};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+type IndexedRect<T> = GeomWithData<Rectangle<[T; 2]>, usize>;
+
+/// An index over a set of candidate polygons, to find those contained
+/// by a query polygon.
+///
+/// The candidates are first filtered by their bounding rectangles,
+/// which are held in an R-tree: a candidate can only be contained by
+/// the query if its bounding rectangle is within that of the query.
+/// The precise [`Contains`] predicate is then evaluated only on the
+/// remaining candidates.
+///
+/// Building the index takes `O(n log n)` time; re-use it to answer
+/// several queries against the same candidates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, ContainsIndex};
+///
+/// let candidates = vec![
+///     polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+///     polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+///     polygon![(x: 3., y: 3.), (x: 9., y: 3.), (x: 9., y: 9.), (x: 3., y: 9.)],
+/// ];
+/// let index = ContainsIndex::new(&candidates);
+///
+/// let query = polygon![(x: 0., y: 0.), (x: 7., y: 0.), (x: 7., y: 7.), (x: 0., y: 7.)];
+/// assert_eq!(index.containing_indices(&query), vec![0, 1]);
+/// assert!(index.contains_any(&query));
+/// ```
+pub struct ContainsIndex<'a, T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    candidates: &'a [Polygon<T>],
+    tree: RTree<IndexedRect<T>>,
+}
+
+impl<'a, T> ContainsIndex<'a, T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    /// Build the index over `candidates`. Candidates without a
+    /// bounding rectangle (i.e. empty polygons) are never reported.
+    pub fn new(candidates: &'a [Polygon<T>]) -> Self {
+        let rects = candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, poly)| {
+                let rect = poly.bounding_rect()?;
+                let rect = Rectangle::from_corners(rect.min().into(), rect.max().into());
+                Some(GeomWithData::new(rect, idx))
+            })
+            .collect();
+        ContainsIndex {
+            candidates,
+            tree: RTree::bulk_load(rects),
+        }
+    }
+
+    /// The indices of the candidates contained by `query`, in
+    /// increasing order.
+    pub fn containing_indices(&self, query: &Polygon<T>) -> Vec<usize> {
+        let mut indices: Vec<_> = self.contained_by(query).collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Whether `query` contains any of the candidates.
+    pub fn contains_any(&self, query: &Polygon<T>) -> bool {
+        self.contained_by(query).next().is_some()
+    }
+
+    fn contained_by<'b>(&'b self, query: &'b Polygon<T>) -> impl Iterator<Item = usize> + 'b {
+        let envelope = query
+            .bounding_rect()
+            .map(|rect| AABB::from_corners(rect.min().into(), rect.max().into()));
+        envelope
+            .into_iter()
+            .flat_map(move |envelope| self.tree.locate_in_envelope(&envelope))
+            .map(|rect| rect.data)
+            .filter(move |&idx| query.contains(&self.candidates[idx]))
+    }
+}
+
+/// The indices of the `candidates` contained by `query`, in increasing
+/// order.
+///
+/// This builds a [`ContainsIndex`] over the candidates; prefer to build
+/// it once when querying the same candidates repeatedly.
+pub fn containing_indices<T>(query: &Polygon<T>, candidates: &[Polygon<T>]) -> Vec<usize>
+where
+    T: GeoFloat + RTreeNum,
+{
+    ContainsIndex::new(candidates).containing_indices(query)
+}
+
+/// Whether `query` contains any of the `candidates`.
+///
+/// This builds a [`ContainsIndex`] over the candidates; prefer to build
+/// it once when querying the same candidates repeatedly.
+pub fn contains_any<T>(query: &Polygon<T>, candidates: &[Polygon<T>]) -> bool
+where
+    T: GeoFloat + RTreeNum,
+{
+    ContainsIndex::new(candidates).contains_any(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, LineString, Rect};
+
+    #[test]
+    fn test_matches_brute_force() {
+        let query = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        // A grid of unit squares over the bounding box; many are within
+        // the query's bounding rectangle, but outside the L-shape.
+        let candidates: Vec<Polygon<f64>> = (0..12)
+            .flat_map(|i| (0..12).map(move |j| (i as f64 - 0.5, j as f64 - 0.5)))
+            .map(|(x, y)| Rect::new((x, y), (x + 1., y + 1.)).to_polygon())
+            .chain(std::iter::once(Polygon::new(
+                LineString::new(vec![]),
+                vec![],
+            )))
+            .collect();
+
+        let expected: Vec<usize> = (0..candidates.len())
+            .filter(|&idx| query.contains(&candidates[idx]))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(containing_indices(&query, &candidates), expected);
+        assert!(contains_any(&query, &candidates));
+
+        let far = Rect::new((20., 20.), (30., 30.)).to_polygon();
+        assert!(containing_indices(&far, &candidates).is_empty());
+        assert!(!contains_any(&far, &candidates));
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{CoordNum, Coordinate, MapCoords};
+use std::{error, fmt};
+
+use crate::algorithm::is_valid_reason::{polygons_reason, Reason};
+use crate::{
+    CoordNum, Coordinate, HasNonFinite, MapCoords, MultiPolygon, Polygon, ValidationError,
+};
 
 /// Convert (infalliby) the type of a geometry’s coordinate value.
 ///
@@ -73,3 +78,189 @@ where
         })
     }
 }
+
+/// Convert a polygonal geometry to `f32` coordinates, checking that the
+/// conversion did not make it invalid.
+///
+/// Rounding to `f32` may merge nearby vertices, or move them across
+/// nearby edges, e.g. for the thin slivers left by overlays, or far from
+/// the origin. The converted geometry is checked in turn for coordinates
+/// beyond the range of `f32`, rounded to infinity, for rings collapsed by
+/// [`Polygon::try_clean`], and for the crossings and nesting checked by
+/// [`IsValidReason`](crate::IsValidReason). The input is expected to be
+/// valid: the problems of an invalid input are reported as well.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, InvalidConversionError, RingId, RingProblem, ToF32Validated};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert_eq!(square.to_f32_validated().unwrap().0[0].exterior().0.len(), 5);
+///
+/// // A sliver far from the origin, flattened at `f32` precision.
+/// let sliver = polygon![(x: 0., y: 1e4), (x: 2., y: 1e4), (x: 1., y: 1e4 + 1e-5)];
+/// match sliver.to_f32_validated().unwrap_err() {
+///     InvalidConversionError::Collapsed { index, error } => {
+///         assert_eq!(index, 0);
+///         assert_eq!(error.problems, vec![(RingId::Exterior, RingProblem::ZeroArea)]);
+///     }
+///     error => panic!("unexpected error: {}", error),
+/// }
+/// ```
+pub trait ToF32Validated {
+    fn to_f32_validated(&self) -> Result<MultiPolygon<f32>, InvalidConversionError>;
+}
+
+impl ToF32Validated for Polygon<f64> {
+    fn to_f32_validated(&self) -> Result<MultiPolygon<f32>, InvalidConversionError> {
+        MultiPolygon::new(vec![self.clone()]).to_f32_validated()
+    }
+}
+
+impl ToF32Validated for MultiPolygon<f64> {
+    fn to_f32_validated(&self) -> Result<MultiPolygon<f32>, InvalidConversionError> {
+        let converted = self.map_coords(|Coordinate { x, y }| Coordinate {
+            x: x as f32,
+            y: y as f32,
+        });
+        if let Some(c) = converted.first_non_finite() {
+            return Err(InvalidConversionError::NonFinite(c));
+        }
+        for (index, poly) in converted.0.iter().enumerate() {
+            if poly.exterior().0.is_empty() {
+                continue;
+            }
+            if let Err(error) =
+                Polygon::try_clean(poly.exterior().clone(), poly.interiors().to_vec())
+            {
+                return Err(InvalidConversionError::Collapsed { index, error });
+            }
+        }
+        match polygons_reason(&converted.0) {
+            None => Ok(converted),
+            Some((Reason::SelfIntersection | Reason::RingSelfIntersection, c)) => {
+                Err(InvalidConversionError::SelfIntersection(c))
+            }
+            Some((Reason::HoleOutsideShell | Reason::NestedHoles | Reason::NestedShells, c)) => {
+                Err(InvalidConversionError::Misnested(c))
+            }
+            Some((Reason::InvalidCoordinate | Reason::TooFewPoints, _)) => {
+                unreachable!("Non-finite coordinates and collapsed rings are checked first")
+            }
+        }
+    }
+}
+
+/// The error returned when a geometry is invalid after a conversion. The
+/// coordinates are those of the converted geometry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidConversionError {
+    /// A coordinate is beyond the range of the converted type.
+    NonFinite(Coordinate<f32>),
+    /// Rings of the polygon at `index` have too few distinct points, or no
+    /// area left, as reported by [`Polygon::try_clean`].
+    Collapsed {
+        index: usize,
+        error: ValidationError,
+    },
+    /// Two rings cross or overlap, or a ring touches itself, at the given
+    /// coordinate.
+    SelfIntersection(Coordinate<f32>),
+    /// A hole is outside its shell, or a ring is inside another one it
+    /// should not be in, at the given coordinate.
+    Misnested(Coordinate<f32>),
+}
+
+impl fmt::Display for InvalidConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid geometry after conversion: ")?;
+        let c = match self {
+            InvalidConversionError::Collapsed { index, error } => {
+                return write!(f, "{} at index {}", error, index)
+            }
+            InvalidConversionError::NonFinite(c) => {
+                write!(f, "non-finite coordinate")?;
+                c
+            }
+            InvalidConversionError::SelfIntersection(c) => {
+                write!(f, "self-intersection")?;
+                c
+            }
+            InvalidConversionError::Misnested(c) => {
+                write!(f, "misnested rings")?;
+                c
+            }
+        };
+        write!(f, " at ({}, {})", c.x, c.y)
+    }
+}
+
+impl error::Error for InvalidConversionError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InvalidConversionError::Collapsed { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, IsValidReason, RingId, RingProblem};
+
+    #[test]
+    fn f32_validated() {
+        // A triangle far from the origin, and a sliver below its base
+        // collapsing onto it at `f32` precision.
+        let triangle = polygon![(x: 0., y: 1e4), (x: 4., y: 1e4), (x: 2., y: 1e4 + 3.)];
+        let mut parts = MultiPolygon::new(vec![triangle]);
+        let converted = parts.to_f32_validated().unwrap();
+        assert_eq!(
+            converted.0[0].exterior().0[2],
+            Coordinate { x: 2., y: 1e4 + 3. }
+        );
+
+        // A sliver below its base collapsing to a line.
+        let mut collapsed = parts.clone();
+        collapsed.0.push(polygon![
+            (x: 0.5, y: 1e4 - 1e-5),
+            (x: 3.5, y: 1e4 - 1e-5),
+            (x: 2., y: 1e4 - 1e-6),
+        ]);
+        assert_eq!(collapsed.is_valid_reason(), None);
+        assert_eq!(
+            collapsed.to_f32_validated().unwrap_err(),
+            InvalidConversionError::Collapsed {
+                index: 1,
+                error: ValidationError {
+                    problems: vec![(RingId::Exterior, RingProblem::ZeroArea)]
+                }
+            }
+        );
+
+        // A rectangle below its base, with a top edge moved onto it.
+        parts.0.push(polygon![
+            (x: 1., y: 1e4 - 1.),
+            (x: 3., y: 1e4 - 1.),
+            (x: 3., y: 1e4 - 1e-6),
+            (x: 1., y: 1e4 - 1e-6),
+        ]);
+        assert_eq!(parts.is_valid_reason(), None);
+        match parts.to_f32_validated().unwrap_err() {
+            InvalidConversionError::SelfIntersection(c) => assert_eq!(c.y, 1e4),
+            error => panic!("unexpected error: {}", error),
+        }
+
+        // Beyond the range of `f32`.
+        let huge = polygon![(x: 0., y: 0.), (x: 1e300, y: 0.), (x: 0., y: 1.)];
+        assert_eq!(
+            huge.to_f32_validated().unwrap_err(),
+            InvalidConversionError::NonFinite(Coordinate {
+                x: f32::INFINITY,
+                y: 0.
+            })
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::orient::{Direction, Orient};
+use crate::{Coordinate, GeoFloat, IsConvex, LineString, MultiPolygon, Polygon};
+
+/// Decompose a polygon into convex polygons.
+///
+/// The holes of the polygon are first removed, by bridging each of them
+/// to the exterior, and the resulting ring is triangulated by ear
+/// clipping. The triangles are then merged by the Hertel–Mehlhorn
+/// algorithm: each diagonal of the triangulation is removed, unless
+/// that would make one of its ends reflex. This is not optimal, but
+/// yields at most four times the minimum number of pieces.
+///
+/// The pieces are oriented counter-clockwise, and have no holes. A
+/// convex polygon without holes is returned as is. Rings of zero area
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, ConvexDecomposition, IsConvex};
+///
+/// let l_shape = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+/// assert!(!IsConvex::is_convex(&l_shape));
+///
+/// let pieces = l_shape.convex_decomposition();
+/// assert_eq!(pieces.len(), 2);
+/// assert!(pieces.iter().all(|p| p.is_ccw_convex()));
+/// assert_eq!(pieces.iter().map(|p| p.unsigned_area()).sum::<f64>(), 3.);
+/// ```
+pub trait ConvexDecomposition<T: GeoFloat> {
+    fn convex_decomposition(&self) -> Vec<Polygon<T>>;
+}
+
+impl<T: GeoFloat> ConvexDecomposition<T> for Polygon<T> {
+    fn convex_decomposition(&self) -> Vec<Polygon<T>> {
+        let poly = self.orient(Direction::Default);
+        let exterior = ring_coords(poly.exterior());
+        if exterior.len() < 3 {
+            return vec![];
+        }
+        if poly.interiors().is_empty() && poly.is_ccw_convex() {
+            return vec![poly];
+        }
+
+        let holes = poly
+            .interiors()
+            .iter()
+            .map(ring_coords)
+            .filter(|hole| hole.len() >= 3)
+            .collect();
+        let coords = bridge_holes(exterior, holes);
+        merge_triangles(&coords, triangulate(&coords))
+            .into_iter()
+            .map(|piece| {
+                let ring = piece.into_iter().map(|idx| coords[idx]).collect();
+                Polygon::new(LineString::new(ring), vec![])
+            })
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> ConvexDecomposition<T> for MultiPolygon<T> {
+    fn convex_decomposition(&self) -> Vec<Polygon<T>> {
+        self.iter().flat_map(|p| p.convex_decomposition()).collect()
+    }
+}
+
+fn orient<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Orientation {
+    T::Ker::orient2d(a, b, c)
+}
+
+/// The distinct vertices of a closed ring, without the collinear ones.
+fn ring_coords<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coordinate<T>> {
+    let mut coords: Vec<Coordinate<T>> = vec![];
+    for &c in &ring.0 {
+        while coords.len() >= 2
+            && orient(coords[coords.len() - 2], coords[coords.len() - 1], c)
+                == Orientation::Collinear
+        {
+            coords.pop();
+        }
+        if coords.last() != Some(&c) {
+            coords.push(c);
+        }
+    }
+    // The closing point, and the collinear vertices around it.
+    while coords.len() >= 3 {
+        let n = coords.len();
+        if coords[n - 1] == coords[0]
+            || orient(coords[n - 2], coords[n - 1], coords[0]) == Orientation::Collinear
+        {
+            coords.pop();
+        } else if orient(coords[n - 1], coords[0], coords[1]) == Orientation::Collinear {
+            coords.remove(0);
+        } else {
+            break;
+        }
+    }
+    coords
+}
+
+/// Merge the (clockwise) holes into the (counter-clockwise) exterior,
+/// each by a bridge from its rightmost vertex to the closest vertex of
+/// the exterior it sees. The bridge is traversed in both directions,
+/// so that the result is a single, weakly simple ring.
+fn bridge_holes<T: GeoFloat>(
+    mut exterior: Vec<Coordinate<T>>,
+    mut holes: Vec<Vec<Coordinate<T>>>,
+) -> Vec<Coordinate<T>> {
+    for hole in &mut holes {
+        let rightmost = (0..hole.len())
+            .max_by(|&i, &j| hole[i].x.partial_cmp(&hole[j].x).unwrap())
+            .unwrap();
+        hole.rotate_left(rightmost);
+    }
+    // Bridge the rightmost holes first, so that the bridges of the
+    // others do not have to go around them.
+    holes.sort_by(|a, b| b[0].x.partial_cmp(&a[0].x).unwrap());
+
+    for idx in 0..holes.len() {
+        let from = holes[idx][0];
+        let distance = |c: Coordinate<T>| (c - from).x.hypot((c - from).y);
+        let mut candidates: Vec<_> = (0..exterior.len()).collect();
+        candidates.sort_by(|&i, &j| {
+            distance(exterior[i])
+                .partial_cmp(&distance(exterior[j]))
+                .unwrap()
+        });
+        let to = candidates
+            .iter()
+            .copied()
+            .find(|&i| {
+                let rings = std::iter::once(&exterior).chain(&holes[idx..]);
+                is_visible(from, exterior[i], rings)
+            })
+            .unwrap_or(candidates[0]);
+
+        let bridged: Vec<_> = exterior[..=to]
+            .iter()
+            .chain(&holes[idx])
+            .chain([&from, &exterior[to]])
+            .chain(&exterior[to + 1..])
+            .copied()
+            .collect();
+        exterior = bridged;
+    }
+    exterior
+}
+
+/// Whether the segment `a`-`b` neither crosses an edge of the rings, nor
+/// goes through one of their vertices.
+fn is_visible<'a, T: GeoFloat + 'a>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    rings: impl Iterator<Item = &'a Vec<Coordinate<T>>>,
+) -> bool {
+    let (min, max) = (
+        Coordinate {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+        },
+        Coordinate {
+            x: a.x.max(b.x),
+            y: a.y.max(b.y),
+        },
+    );
+    let is_between = |p: Coordinate<T>| {
+        p != a
+            && p != b
+            && orient(a, b, p) == Orientation::Collinear
+            && min.x <= p.x
+            && p.x <= max.x
+            && min.y <= p.y
+            && p.y <= max.y
+    };
+    let crosses = |p: Coordinate<T>, q: Coordinate<T>| {
+        let (o1, o2) = (orient(a, b, p), orient(a, b, q));
+        let (o3, o4) = (orient(p, q, a), orient(p, q, b));
+        [o1, o2, o3, o4]
+            .iter()
+            .all(|o| *o != Orientation::Collinear)
+            && o1 != o2
+            && o3 != o4
+    };
+    for ring in rings {
+        for (i, &p) in ring.iter().enumerate() {
+            let q = ring[(i + 1) % ring.len()];
+            if is_between(p) || crosses(p, q) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Triangulate the counter-clockwise ring by ear clipping. The
+/// triangles are counter-clockwise triples of indices in `coords`.
+fn triangulate<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..coords.len()).collect();
+    let mut triangles = vec![];
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let corners = |i: usize| {
+            [
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            ]
+        };
+        let is_ear = |[a, b, c]: [usize; 3]| {
+            let (a, b, c) = (coords[a], coords[b], coords[c]);
+            orient(a, b, c) == Orientation::CounterClockwise
+                && remaining.iter().all(|&idx| {
+                    let p = coords[idx];
+                    // Vertices of a bridge are repeated in the ring.
+                    p == a
+                        || p == b
+                        || p == c
+                        || orient(a, b, p) == Orientation::Clockwise
+                        || orient(b, c, p) == Orientation::Clockwise
+                        || orient(c, a, p) == Orientation::Clockwise
+                })
+        };
+        if let Some(i) = (0..n).find(|&i| is_ear(corners(i))) {
+            triangles.push(corners(i));
+            remaining.remove(i);
+            continue;
+        }
+        // Only degenerate rings have no ear, such as a bridge collinear
+        // with an edge: drop a vertex with no area to clip.
+        let flat = (0..n).find(|&i| {
+            let [a, b, c] = corners(i);
+            orient(coords[a], coords[b], coords[c]) == Orientation::Collinear
+        });
+        match flat {
+            Some(i) => {
+                remaining.remove(i);
+            }
+            None => break,
+        }
+    }
+    if let [a, b, c] = remaining[..] {
+        if orient(coords[a], coords[b], coords[c]) == Orientation::CounterClockwise {
+            triangles.push([a, b, c]);
+        }
+    }
+    triangles
+}
+
+/// Merge the triangles across their common edges, as long as the pieces
+/// stay convex (Hertel–Mehlhorn).
+fn merge_triangles<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    triangles: Vec<[usize; 3]>,
+) -> Vec<Vec<usize>> {
+    let mut pieces: Vec<Option<Vec<usize>>> = triangles.iter().map(|t| Some(t.to_vec())).collect();
+    // The piece on the left of each directed edge.
+    let mut owner = HashMap::new();
+    for (id, t) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            owner.insert((t[k], t[(k + 1) % 3]), id);
+        }
+    }
+    let mut diagonals: Vec<_> = owner
+        .keys()
+        .copied()
+        .filter(|&(a, b)| a < b && owner.contains_key(&(b, a)))
+        .collect();
+    diagonals.sort_unstable();
+
+    let is_convex_at = |piece: &[usize], idx: usize| {
+        let n = piece.len();
+        let [prev, cur, next] = [piece[(idx + n - 1) % n], piece[idx], piece[(idx + 1) % n]];
+        orient(coords[prev], coords[cur], coords[next]) != Orientation::Clockwise
+    };
+    for (a, b) in diagonals {
+        let (id_a, id_b) = (owner[&(a, b)], owner[&(b, a)]);
+        // Rotate the piece on the left of `a`-`b` to run from `b` to
+        // `a`, and the other one from `a` to `b`.
+        let mut left = pieces[id_a].clone().unwrap();
+        let mut right = pieces[id_b].clone().unwrap();
+        let pos = left.iter().position(|&v| v == b).unwrap();
+        left.rotate_left(pos);
+        let pos = right.iter().position(|&v| v == a).unwrap();
+        right.rotate_left(pos);
+
+        let at_a = left.len() - 1;
+        let merged: Vec<_> = left
+            .iter()
+            .chain(&right[1..right.len() - 1])
+            .copied()
+            .collect();
+        if !is_convex_at(&merged, 0) || !is_convex_at(&merged, at_a) {
+            continue;
+        }
+
+        owner.remove(&(a, b));
+        owner.remove(&(b, a));
+        for k in 0..right.len() - 1 {
+            owner.insert((right[k], right[k + 1]), id_a);
+        }
+        pieces[id_a] = Some(merged);
+        pieces[id_b] = None;
+    }
+    pieces.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area};
+
+    fn check(poly: &Polygon<f64>, pieces: &[Polygon<f64>]) {
+        assert!(pieces.iter().all(|p| p.is_ccw_convex()));
+        let area: f64 = pieces.iter().map(|p| p.unsigned_area()).sum();
+        assert_relative_eq!(area, poly.unsigned_area(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn convex() {
+        let square = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)];
+        assert!(IsConvex::is_convex(&square));
+        let pieces = square.convex_decomposition();
+        assert_eq!(pieces, vec![square.orient(Direction::Default)]);
+    }
+
+    #[test]
+    fn comb() {
+        // Three teeth, with collinear and repeated vertices.
+        let comb = polygon![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 3.),
+            (x: 4., y: 3.),
+            (x: 4., y: 1.),
+            (x: 3., y: 1.),
+            (x: 3., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        let pieces = comb.convex_decomposition();
+        check(&comb, &pieces);
+        // Four reflex vertices.
+        assert!(pieces.len() <= 9);
+    }
+
+    #[test]
+    fn holes() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]),
+            vec![
+                LineString::from(vec![(2., 2.), (4., 2.), (4., 4.), (2., 4.)]),
+                LineString::from(vec![(6., 6.), (8., 6.), (7., 8.)]),
+                // No area.
+                LineString::from(vec![(6., 2.), (8., 2.), (7., 2.)]),
+            ],
+        );
+        assert!(!IsConvex::is_convex(&poly));
+        let pieces = poly.convex_decomposition();
+        check(&poly, &pieces);
+        assert_relative_eq!(
+            pieces.iter().map(|p| p.unsigned_area()).sum::<f64>(),
+            100. - 4. - 2.
+        );
+
+        let degenerate = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        assert!(degenerate.convex_decomposition().is_empty());
+    }
+}
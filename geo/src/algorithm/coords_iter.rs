@@ -1,9 +1,10 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use crate::geometry::*;
 use crate::{coord, CoordNum};
 
-use std::{fmt, iter, marker, slice};
+use alloc::{boxed::Box, string::String};
+use core::{fmt, iter, marker, slice};
 
 type CoordinateChainOnce<T> = iter::Chain<iter::Once<Coordinate<T>>, iter::Once<Coordinate<T>>>;
 
@@ -0,0 +1,194 @@
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use crate::sweep::{Cross, CrossingsIter, LineOrPoint, SweepPoint};
+use crate::{Coordinate, GeoFloat, Line, LineString};
+
+/// Split a self-intersecting `LineString` into its simple loops.
+///
+/// The segments are noded at every self-crossing using the planar
+/// sweep, and the noded ring is traversed in its original order. Each
+/// time the traversal returns to a previously visited point, the cycle
+/// closed since that visit is split off as a separate loop. The loops
+/// hence preserve the orientation of the part of the input they trace.
+///
+/// This is different from repairing the geometry: the loops are only
+/// separated, and no interior is computed.
+///
+/// # Remarks
+///
+/// - Every returned loop is closed and has at least three distinct
+///   coordinates. Degenerate back-and-forth spikes are dropped.
+///
+/// - If the input is not closed, only the closed loops are returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, DecomposeLoops, Winding};
+///
+/// // A figure eight crossing itself at (1, 1).
+/// let ls = line_string![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 0., y: 2.),
+///     (x: 0., y: 0.),
+/// ];
+/// let loops = ls.decompose_loops();
+/// assert_eq!(loops.len(), 2);
+/// assert_ne!(loops[0].winding_order(), loops[1].winding_order());
+/// ```
+pub trait DecomposeLoops {
+    type Scalar: GeoFloat;
+
+    fn decompose_loops(&self) -> Vec<LineString<Self::Scalar>>;
+}
+
+impl<T: GeoFloat> DecomposeLoops for LineString<T> {
+    type Scalar = T;
+
+    fn decompose_loops(&self) -> Vec<LineString<T>> {
+        let coords = noded_coords(self);
+
+        let mut output = vec![];
+        let mut current: Vec<Coordinate<T>> = vec![];
+        let mut history: BTreeMap<SweepPoint<T>, usize> = BTreeMap::new();
+        for coord in coords {
+            let pt = SweepPoint::from(coord);
+            if let Some(&idx) = history.get(&pt) {
+                // [idx..) is a simple loop closed at `pt`.
+                let mut ring: Vec<_> = current
+                    .drain(idx + 1..)
+                    .inspect(|c| {
+                        history.remove(&SweepPoint::from(*c));
+                    })
+                    .collect();
+                if ring.len() >= 2 {
+                    ring.insert(0, coord);
+                    ring.push(coord);
+                    output.push(LineString::new(ring));
+                }
+                continue;
+            }
+            history.insert(pt, current.len());
+            current.push(coord);
+        }
+        output
+    }
+}
+
+/// A segment of the input, tagged with its position in the ring.
+#[derive(Debug, Clone, Copy)]
+struct IndexedLine<T: GeoFloat> {
+    idx: usize,
+    line: Line<T>,
+}
+
+impl<T: GeoFloat> Cross for IndexedLine<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+/// Coordinates of `ls` with all self-crossings inserted as vertices, and
+/// consecutive duplicates removed.
+fn noded_coords<T: GeoFloat>(ls: &LineString<T>) -> Vec<Coordinate<T>> {
+    let lines: Vec<_> = ls
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.start != line.end)
+        .map(|(idx, line)| IndexedLine { idx, line })
+        .collect();
+
+    let mut splits: Vec<Vec<Coordinate<T>>> = vec![vec![]; ls.0.len().saturating_sub(1)];
+    let mut iter = CrossingsIter::from_iter(lines.iter().copied());
+    while let Some(pt) = iter.next() {
+        for crossing in iter.intersections() {
+            splits[crossing.cross.idx].push(pt);
+        }
+    }
+
+    let mut coords: Vec<Coordinate<T>> = Vec::with_capacity(ls.0.len());
+    for IndexedLine { idx, line } in lines {
+        let pts = &mut splits[idx];
+        let dist = |c: &Coordinate<T>| {
+            let d = *c - line.start;
+            d.x.abs() + d.y.abs()
+        };
+        pts.sort_by(|a, b| dist(a).partial_cmp(&dist(b)).unwrap());
+        coords.push(line.start);
+        coords.extend(pts.iter().copied());
+        coords.push(line.end);
+    }
+    coords.dedup();
+    coords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winding_order::{Winding, WindingOrder};
+    use crate::{line_string, Area, Polygon};
+
+    fn loop_area(ls: &LineString<f64>) -> f64 {
+        Polygon::new(ls.clone(), vec![]).unsigned_area()
+    }
+
+    #[test]
+    fn test_figure_eight() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let loops = ls.decompose_loops();
+        assert_eq!(loops.len(), 2);
+        let windings: Vec<_> = loops.iter().map(|l| l.winding_order()).collect();
+        assert!(windings.contains(&Some(WindingOrder::Clockwise)));
+        assert!(windings.contains(&Some(WindingOrder::CounterClockwise)));
+        for l in &loops {
+            assert!(l.is_closed());
+            assert_relative_eq!(loop_area(l), 1.);
+        }
+    }
+
+    #[test]
+    fn test_simple_ring() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let loops = ls.decompose_loops();
+        assert_eq!(loops.len(), 1);
+        assert!(loops[0].is_ccw());
+        assert_relative_eq!(loop_area(&loops[0]), 1.);
+    }
+
+    #[test]
+    fn test_chain_of_loops() {
+        // Crosses itself at (2, 1), (5, 1) and (7, 1).
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 2.),
+            (x: 6., y: 0.),
+            (x: 8., y: 2.),
+            (x: 8., y: 0.),
+            (x: 6., y: 2.),
+            (x: 4., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let loops = ls.decompose_loops();
+        assert_eq!(loops.len(), 4);
+        let mut areas: Vec<f64> = loops.iter().map(loop_area).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1., 2., 2., 3.]);
+    }
+}
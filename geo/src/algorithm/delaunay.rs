@@ -0,0 +1,428 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::bounding_circle::to_robust;
+use crate::kernels::{Kernel, Orientation};
+use crate::{
+    Coordinate, CoordsIter, GeoFloat, LineString, MultiPoint, MultiPolygon, Polygon, Rect, Triangle,
+};
+
+/// The Delaunay triangulation of the vertices of a geometry, and its
+/// dual, the Voronoi diagram.
+///
+/// The triangulation is built by the Bowyer–Watson algorithm, inserting
+/// the vertices one by one. Whether a vertex is in the circumcircle of a
+/// triangle is decided by the robust `incircle` predicate, and the hull
+/// is handled exactly by a symbolic vertex at infinity, rather than by a
+/// large enclosing triangle.
+///
+/// Repeated vertices are only used once. If all vertices are collinear,
+/// there is no triangle.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, point, Area, Delaunay, MultiPoint, Rect};
+///
+/// let points: MultiPoint<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (2., 1.)].into();
+///
+/// let triangles = points.delaunay();
+/// assert_eq!(triangles.len(), 4);
+/// let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+/// assert_eq!(area, 16.);
+///
+/// let bounds = Rect::new(coord! { x: -1., y: -1. }, coord! { x: 5., y: 5. });
+/// let cells = points.voronoi(bounds);
+/// assert_eq!(cells.len(), 5);
+/// let area: f64 = cells.iter().map(|c| c.unsigned_area()).sum();
+/// assert_eq!(area, 36.);
+/// ```
+pub trait Delaunay<T: GeoFloat> {
+    /// The triangles of the Delaunay triangulation, counter-clockwise.
+    fn delaunay(&self) -> Vec<Triangle<T>>;
+
+    /// The Voronoi cells of the distinct vertices, in the order of their
+    /// first occurrence, clipped to `bounds`.
+    ///
+    /// The cell of a vertex is the region nearer to it than to any other
+    /// vertex. The cells of the vertices outside `bounds` may be empty.
+    fn voronoi(&self, bounds: Rect<T>) -> Vec<Polygon<T>>;
+}
+
+impl<T: GeoFloat> Delaunay<T> for MultiPoint<T> {
+    fn delaunay(&self) -> Vec<Triangle<T>> {
+        Triangulation::new(self.iter().map(|p| p.0)).delaunay()
+    }
+
+    fn voronoi(&self, bounds: Rect<T>) -> Vec<Polygon<T>> {
+        Triangulation::new(self.iter().map(|p| p.0)).voronoi(bounds)
+    }
+}
+
+impl<T: GeoFloat> Delaunay<T> for MultiPolygon<T> {
+    fn delaunay(&self) -> Vec<Triangle<T>> {
+        Triangulation::new(self.coords_iter()).delaunay()
+    }
+
+    fn voronoi(&self, bounds: Rect<T>) -> Vec<Polygon<T>> {
+        Triangulation::new(self.coords_iter()).voronoi(bounds)
+    }
+}
+
+/// The symbolic vertex at infinity, joined to every edge of the hull.
+const INFINITE: usize = usize::MAX;
+
+struct Triangulation<T: GeoFloat> {
+    /// The distinct vertices, in the order of their first occurrence.
+    sites: Vec<Coordinate<T>>,
+    /// The sites in lexicographic order, the order of insertion.
+    sorted: Vec<usize>,
+    /// The vertices of each triangle, counter-clockwise. The triangles
+    /// on the outside of the hull have the infinite vertex last.
+    triangles: Vec<[usize; 3]>,
+    /// The triangle across the edge opposite each vertex.
+    neighbors: Vec<[usize; 3]>,
+    /// Whether each triangle was removed by a later insertion.
+    removed: Vec<bool>,
+}
+
+impl<T: GeoFloat> Triangulation<T> {
+    fn new(coords: impl Iterator<Item = Coordinate<T>>) -> Self {
+        let coords: Vec<_> = coords.collect();
+        let mut sorted: Vec<usize> = (0..coords.len()).collect();
+        sorted.sort_by(|&i, &j| lex_cmp(coords[i], coords[j]).then(i.cmp(&j)));
+        // Keep the first occurrence of each vertex.
+        sorted.dedup_by(|j, i| coords[*i] == coords[*j]);
+        let mut first = sorted.clone();
+        first.sort_unstable();
+        let sites = first.iter().map(|&i| coords[i]).collect();
+        let sorted = sorted
+            .iter()
+            .map(|i| first.binary_search(i).unwrap())
+            .collect();
+
+        let mut triangulation = Triangulation {
+            sites,
+            sorted,
+            triangles: vec![],
+            neighbors: vec![],
+            removed: vec![],
+        };
+        triangulation.triangulate();
+        triangulation
+    }
+
+    fn triangulate(&mut self) {
+        if self.sorted.len() < 3 {
+            return;
+        }
+        let (a, b) = (self.sorted[0], self.sorted[1]);
+        let k = match (2..self.sorted.len())
+            .find(|&k| self.orient(a, b, self.sorted[k]) != Orientation::Collinear)
+        {
+            Some(k) => k,
+            None => return,
+        };
+        let c = self.sorted[k];
+        let (b, c) = match self.orient(a, b, c) {
+            Orientation::CounterClockwise => (b, c),
+            _ => (c, b),
+        };
+        self.triangles = vec![
+            [a, b, c],
+            [b, a, INFINITE],
+            [c, b, INFINITE],
+            [a, c, INFINITE],
+        ];
+        self.neighbors = vec![[2, 3, 1], [3, 2, 0], [1, 3, 0], [2, 1, 0]];
+        self.removed = vec![false; 4];
+
+        let mut last = 0;
+        for idx in (2..self.sorted.len()).filter(|&idx| idx != k) {
+            last = self.insert(self.sorted[idx], last);
+        }
+    }
+
+    /// Insert the site `p`, starting the search for the triangles whose
+    /// circumcircle contains it at `start`, and return one of the new
+    /// triangles.
+    fn insert(&mut self, p: usize, start: usize) -> usize {
+        // The cavity of the triangles in conflict with `p` is connected,
+        // and star-shaped from `p`.
+        let first = self.locate(p, start);
+        self.removed[first] = true;
+        let mut cavity = vec![first];
+        let mut boundary = vec![];
+        let mut idx = 0;
+        while idx < cavity.len() {
+            let t = cavity[idx];
+            idx += 1;
+            for i in 0..3 {
+                let n = self.neighbors[t][i];
+                if self.removed[n] {
+                    continue;
+                }
+                if self.in_conflict(n, p) {
+                    self.removed[n] = true;
+                    cavity.push(n);
+                } else {
+                    let tri = self.triangles[t];
+                    boundary.push((tri[(i + 1) % 3], tri[(i + 2) % 3], n));
+                }
+            }
+        }
+
+        // Join `p` to each edge of the boundary of the cavity.
+        let offset = self.triangles.len();
+        let mut by_start = HashMap::new();
+        let mut by_end = HashMap::new();
+        for (idx, &(u, v, outside)) in boundary.iter().enumerate() {
+            let t = offset + idx;
+            let tri = match (u, v) {
+                (INFINITE, _) => [v, p, INFINITE],
+                (_, INFINITE) => [p, u, INFINITE],
+                _ => [u, v, p],
+            };
+            self.triangles.push(tri);
+            self.removed.push(false);
+            let across = self.triangles[outside];
+            let j = (0..3).find(|&j| across[j] != u && across[j] != v).unwrap();
+            self.neighbors[outside][j] = t;
+            by_start.insert(u, t);
+            by_end.insert(v, t);
+        }
+        for &(u, v, outside) in &boundary {
+            let t = self.neighbors.len();
+            let tri = self.triangles[t];
+            let mut neighbors = [0; 3];
+            for i in 0..3 {
+                neighbors[i] = match tri[i] {
+                    x if x == p => outside,
+                    x if x == u => by_start[&v],
+                    _ => by_end[&u],
+                };
+            }
+            self.neighbors.push(neighbors);
+        }
+        offset
+    }
+
+    /// A triangle in conflict with `p`, found by walking from `start`
+    /// towards `p`.
+    fn locate(&self, p: usize, start: usize) -> usize {
+        let mut t = start;
+        if self.triangles[t][2] == INFINITE {
+            t = self.neighbors[t][2];
+        }
+        // Rotate the first edge tested, so the walk can not cycle.
+        let mut turn = 0;
+        loop {
+            let tri = self.triangles[t];
+            if tri[2] == INFINITE {
+                // Entered across the hull edge, which `p` is beyond.
+                return t;
+            }
+            let beyond = (0..3).map(|k| (k + turn) % 3).find(|&i| {
+                self.orient(tri[(i + 1) % 3], tri[(i + 2) % 3], p) == Orientation::Clockwise
+            });
+            match beyond {
+                Some(i) => {
+                    t = self.neighbors[t][i];
+                    turn += 1;
+                }
+                None => return t,
+            }
+        }
+    }
+
+    /// Whether `p` is in the circumcircle of the triangle `t`. For a
+    /// triangle outside the hull, this is the open half-plane beyond its
+    /// edge of the hull, and the inside of the edge itself.
+    fn in_conflict(&self, t: usize, p: usize) -> bool {
+        let [a, b, c] = self.triangles[t];
+        if c == INFINITE {
+            return match self.orient(a, b, p) {
+                Orientation::CounterClockwise => true,
+                Orientation::Clockwise => false,
+                Orientation::Collinear => {
+                    let (a, b, p) = (self.sites[a], self.sites[b], self.sites[p]);
+                    let (lo, hi) = match lex_cmp(a, b) {
+                        Ordering::Less => (a, b),
+                        _ => (b, a),
+                    };
+                    lex_cmp(lo, p) == Ordering::Less && lex_cmp(p, hi) == Ordering::Less
+                }
+            };
+        }
+        let [a, b, c, p] = [a, b, c, p].map(|v| to_robust(self.sites[v]));
+        robust::incircle(a, b, c, p) > 0.
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        T::Ker::orient2d(self.sites[a], self.sites[b], self.sites[c])
+    }
+
+    /// The triangles inside the hull.
+    fn finite_triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.triangles
+            .iter()
+            .zip(&self.removed)
+            .filter(|(tri, removed)| !**removed && tri[2] != INFINITE)
+            .map(|(tri, _)| *tri)
+    }
+
+    fn delaunay(&self) -> Vec<Triangle<T>> {
+        self.finite_triangles()
+            .map(|[a, b, c]| Triangle::new(self.sites[a], self.sites[b], self.sites[c]))
+            .collect()
+    }
+
+    fn voronoi(&self, bounds: Rect<T>) -> Vec<Polygon<T>> {
+        // The cell of a site is the intersection of the half-planes nearer
+        // to it than to each of its neighbors in the triangulation.
+        let mut adjacent = vec![vec![]; self.sites.len()];
+        if self.triangles.is_empty() {
+            for w in self.sorted.windows(2) {
+                adjacent[w[0]].push(w[1]);
+                adjacent[w[1]].push(w[0]);
+            }
+        }
+        for tri in self.finite_triangles() {
+            for i in 0..3 {
+                let (u, v) = (tri[i], tri[(i + 1) % 3]);
+                adjacent[u].push(v);
+                adjacent[v].push(u);
+            }
+        }
+
+        let (min, max) = (bounds.min(), bounds.max());
+        let corners = vec![
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+        ];
+        adjacent
+            .iter_mut()
+            .enumerate()
+            .map(|(site, adjacent)| {
+                adjacent.sort_unstable();
+                adjacent.dedup();
+                let cell = adjacent.iter().fold(corners.clone(), |cell, &other| {
+                    clip(cell, self.sites[site], self.sites[other])
+                });
+                Polygon::new(LineString::from(cell), vec![])
+            })
+            .collect()
+    }
+}
+
+/// The part of the convex polygon `cell` nearer to `site` than to
+/// `other`.
+fn clip<T: GeoFloat>(
+    cell: Vec<Coordinate<T>>,
+    site: Coordinate<T>,
+    other: Coordinate<T>,
+) -> Vec<Coordinate<T>> {
+    let normal = other - site;
+    let mid = site + normal / (T::one() + T::one());
+    let side = |c: Coordinate<T>| (c - mid).x * normal.x + (c - mid).y * normal.y;
+    let mut clipped = vec![];
+    for (idx, &a) in cell.iter().enumerate() {
+        let b = cell[(idx + 1) % cell.len()];
+        let (side_a, side_b) = (side(a), side(b));
+        if side_a <= T::zero() {
+            clipped.push(a);
+        }
+        if (side_a < T::zero() && side_b > T::zero()) || (side_a > T::zero() && side_b < T::zero())
+        {
+            clipped.push(a + (b - a) * (side_a / (side_a - side_b)));
+        }
+    }
+    clipped
+}
+
+fn lex_cmp<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap()
+        .then_with(|| a.y.partial_cmp(&b.y).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, Area, Contains};
+
+    #[test]
+    fn square() {
+        let corners: MultiPoint<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into();
+        let triangles = corners.delaunay();
+        assert_eq!(triangles.len(), 2);
+        for t in &triangles {
+            assert_eq!(t.signed_area(), 0.5);
+        }
+
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        for (cell, corner) in corners.voronoi(bounds).iter().zip(&corners) {
+            assert_eq!(cell.unsigned_area(), 0.25);
+            assert!(cell.exterior().0.contains(&corner.0));
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(MultiPoint::<f64>::new(vec![]).delaunay().is_empty());
+        let line: MultiPoint<f64> = vec![(0., 0.), (2., 2.), (1., 1.), (2., 2.)].into();
+        assert!(line.delaunay().is_empty());
+
+        // Strips across the line, in the order of the points.
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. });
+        let cells = line.voronoi(bounds);
+        assert_eq!(cells.len(), 3);
+        assert_eq!(
+            cells.iter().map(|c| c.unsigned_area()).collect::<Vec<_>>(),
+            vec![0.5, 0.5, 3.]
+        );
+    }
+
+    #[test]
+    fn empty_circumcircles() {
+        // A grid, with many cocircular points, and a jittered grid.
+        let mut coords = vec![];
+        for i in 0..12 {
+            for j in 0..12 {
+                let (x, y) = (i as f64, j as f64);
+                coords.push(coord! { x: x, y: y });
+                let jitter = ((i * 7 + j * 13) % 11) as f64 / 11.;
+                coords.push(coord! { x: x + 20. + jitter, y: y + jitter * 0.5 });
+            }
+        }
+        let points = MultiPoint::from(coords.clone());
+        let triangles = points.delaunay();
+
+        // As many triangles as for any triangulation of the points.
+        let hull = crate::ConvexHull::convex_hull(&points);
+        let on_hull = coords
+            .iter()
+            .filter(|c| hull.exterior().contains(*c) || hull.exterior().0.contains(c))
+            .count();
+        assert_eq!(triangles.len(), 2 * coords.len() - 2 - on_hull);
+
+        for t in &triangles {
+            // Nearly flat triangles, so not by `signed_area`.
+            let [a, b, c] = t.to_array().map(to_robust);
+            assert!(robust::orient2d(a, b, c) > 0.);
+            for p in &coords {
+                assert!(robust::incircle(a, b, c, to_robust(*p)) <= 0.);
+            }
+        }
+
+        let bounds = Rect::new(coord! { x: -1., y: -1. }, coord! { x: 34., y: 13. });
+        let cells = points.voronoi(bounds);
+        let area: f64 = cells.iter().map(|c| c.unsigned_area()).sum();
+        assert_relative_eq!(area, bounds.unsigned_area(), epsilon = 1e-9);
+        for (cell, site) in cells.iter().zip(&coords) {
+            assert!(cell.contains(site) || cell.exterior().contains(site));
+        }
+    }
+}
@@ -6,6 +6,10 @@ use crate::{
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
 ///
+/// This is planar: each line longer than `max_distance` is split into the least number of
+/// equal parts no longer than `max_distance`. The existing coordinates are kept exactly, and
+/// lines already short enough are left as they are.
+///
 /// Note: `max_distance` must be greater than 0.
 ///
 /// # Examples
@@ -106,11 +110,15 @@ where
     type Output = LineString<T>;
 
     fn densify(&self, max_distance: T) -> Self::Output {
+        let last = match self.points().last() {
+            Some(last) => last,
+            None => return LineString::new(vec![]),
+        };
         let mut new_line = vec![];
         self.lines()
             .for_each(|line| densify_line(line, &mut new_line, max_distance));
         // we're done, push the last coordinate on to finish
-        new_line.push(self.points().last().unwrap());
+        new_line.push(last);
         LineString::from(new_line)
     }
 }
@@ -234,4 +242,26 @@ mod tests {
         let densified = line.densify(max_dist);
         assert_eq!(densified, correct);
     }
+
+    #[test]
+    fn test_equal_parts() {
+        let linestring: LineString<f64> = vec![[0.0, 0.0], [10.0, 0.0], [10.0, 2.0]].into();
+        let densified = linestring.densify(3.0);
+        // The long line in 4 parts of 2.5, and the short one untouched.
+        let correct: LineString<f64> = vec![
+            [0.0, 0.0],
+            [2.5, 0.0],
+            [5.0, 0.0],
+            [7.5, 0.0],
+            [10.0, 0.0],
+            [10.0, 2.0],
+        ]
+        .into();
+        assert_eq!(densified, correct);
+        assert_eq!(densified.densify(3.0), densified);
+
+        assert_eq!(LineString::<f64>::new(vec![]).densify(3.0).0, vec![]);
+        let point: LineString<f64> = vec![[1.0, 1.0]].into();
+        assert_eq!(point.densify(3.0), point);
+    }
 }
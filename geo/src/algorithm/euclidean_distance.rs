@@ -3,8 +3,8 @@ use crate::utils::{coord_pos_relative_to_ring, CoordPos};
 use crate::EuclideanLength;
 use crate::Intersects;
 use crate::{
-    Coordinate, GeoFloat, GeoNum, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Point, Polygon, Triangle,
+    CoordNum, Coordinate, GeoFloat, GeoNum, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Triangle,
 };
 use num_traits::{float::FloatConst, Bounded, Float, Signed};
 
@@ -94,6 +94,62 @@ pub trait EuclideanDistance<T, Rhs = Self> {
     fn euclidean_distance(&self, rhs: &Rhs) -> T;
 }
 
+/// Returns the squared distance between two geometries.
+///
+/// This is the square of [`EuclideanDistance`], without taking its square root, and is exact for
+/// integer coordinates. As squaring is monotonic on distances, comparing or sorting by the squared
+/// distance gives the same order as by the distance, so it is the one to use to find the nearest of
+/// many candidates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, point, EuclideanDistance, SquaredEuclideanDistance};
+///
+/// let a = coord! { x: 1., y: 1. };
+/// let b = coord! { x: 4., y: 5. };
+///
+/// assert_eq!(a.squared_euclidean_distance(&b), 25.);
+/// assert_eq!(a.euclidean_distance(&b), 5.);
+///
+/// // The nearest point by either distance.
+/// let p = point!(x: 0., y: 0.);
+/// let points = [point!(x: 3., y: 0.), point!(x: -1., y: 2.), point!(x: 0., y: -2.)];
+/// let nearest = points
+///     .iter()
+///     .min_by(|a, b| {
+///         let (a, b) = (a.squared_euclidean_distance(&p), b.squared_euclidean_distance(&p));
+///         a.partial_cmp(&b).unwrap()
+///     })
+///     .unwrap();
+/// assert_eq!(*nearest, point!(x: 0., y: -2.));
+/// ```
+pub trait SquaredEuclideanDistance<T, Rhs = Self> {
+    /// Returns the squared distance between two geometries
+    fn squared_euclidean_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> SquaredEuclideanDistance<T, Coordinate<T>> for Coordinate<T>
+where
+    T: CoordNum,
+{
+    /// Squared distance between two `Coordinate`s
+    fn squared_euclidean_distance(&self, c: &Coordinate<T>) -> T {
+        let delta = *self - *c;
+        delta.x * delta.x + delta.y * delta.y
+    }
+}
+
+impl<T> SquaredEuclideanDistance<T, Point<T>> for Point<T>
+where
+    T: CoordNum,
+{
+    /// Squared distance between two Points
+    fn squared_euclidean_distance(&self, p: &Point<T>) -> T {
+        self.0.squared_euclidean_distance(&p.0)
+    }
+}
+
 // ┌────────────────────────────────┐
 // │ Implementations for Coordinate │
 // └────────────────────────────────┘
@@ -1196,4 +1252,40 @@ mod test {
             nearest_neighbour_distance(first_polygon.exterior(), second_polygon.exterior())
         );
     }
+
+    #[test]
+    fn squared_distance_is_monotonic() {
+        let origin = coord! { x: 0, y: 0 };
+        assert_eq!(
+            origin.squared_euclidean_distance(&coord! { x: 3, y: -4 }),
+            25
+        );
+
+        let p = Point::new(0.5, -1.5);
+        let mut points = vec![
+            Point::new(3., 2.),
+            Point::new(-1., -1.),
+            Point::new(0.5, 10.),
+            Point::new(-2.5, 1.),
+            Point::new(1e-3, -1.5),
+        ];
+        for q in &points {
+            assert_relative_eq!(
+                q.squared_euclidean_distance(&p).sqrt(),
+                q.euclidean_distance(&p)
+            );
+        }
+        let mut by_distance = points.clone();
+        by_distance.sort_by(|a, b| {
+            a.euclidean_distance(&p)
+                .partial_cmp(&b.euclidean_distance(&p))
+                .unwrap()
+        });
+        points.sort_by(|a, b| {
+            a.squared_euclidean_distance(&p)
+                .partial_cmp(&b.squared_euclidean_distance(&p))
+                .unwrap()
+        });
+        assert_eq!(points, by_distance);
+    }
 }
@@ -84,6 +84,17 @@ mod test {
         assert_relative_eq!(10.0_f64, linestring.euclidean_length());
     }
     #[test]
+    fn unit_square_perimeter_test() {
+        let linestring = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.)
+        ];
+        assert_relative_eq!(4.0_f64, linestring.euclidean_length());
+    }
+    #[test]
     fn multilinestring_test() {
         let mline = MultiLineString::new(vec![
             line_string![
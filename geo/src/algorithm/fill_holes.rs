@@ -0,0 +1,106 @@
+use crate::area::twice_signed_ring_area;
+use crate::{CoordFloat, MultiPolygon, Polygon};
+use std::cmp::Ordering;
+
+/// Remove the holes of a polygonal geometry whose area is below a
+/// threshold.
+///
+/// Each interior ring with an unsigned area `<= max_area` is dropped,
+/// while the exteriors and the larger holes are kept as-is. This is
+/// useful for cartographic generalization, eg. to fill small lakes.
+///
+/// # Edge Cases
+///
+/// - A `max_area` of `T::infinity()` fills all the holes.
+///
+/// - A negative (or `NaN`) `max_area` fills nothing.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, FillHoles};
+///
+/// let poly = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+///         [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+///     ],
+/// ];
+///
+/// let filled = poly.fill_holes(2.);
+/// assert_eq!(filled.interiors().len(), 1);
+/// assert_eq!(filled.interiors()[0], poly.interiors()[1]);
+/// ```
+pub trait FillHoles<T: CoordFloat> {
+    fn fill_holes(&self, max_area: T) -> Self;
+}
+
+impl<T: CoordFloat> FillHoles<T> for Polygon<T> {
+    fn fill_holes(&self, max_area: T) -> Self {
+        let two = T::one() + T::one();
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter(|hole| {
+                let area = twice_signed_ring_area(hole).abs() / two;
+                !matches!(
+                    area.partial_cmp(&max_area),
+                    Some(Ordering::Less | Ordering::Equal)
+                )
+            })
+            .cloned()
+            .collect();
+        Polygon::new(self.exterior().clone(), interiors)
+    }
+}
+
+impl<T: CoordFloat> FillHoles<T> for MultiPolygon<T> {
+    fn fill_holes(&self, max_area: T) -> Self {
+        MultiPolygon::new(self.iter().map(|p| p.fill_holes(max_area)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon;
+
+    fn two_holes() -> Polygon<f64> {
+        polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_threshold_between_holes() {
+        let poly = two_holes();
+        let filled = poly.fill_holes(5.);
+        assert_eq!(filled.exterior(), poly.exterior());
+        assert_eq!(filled.interiors(), &poly.interiors()[1..]);
+
+        // The threshold is inclusive.
+        assert_eq!(poly.fill_holes(1.).interiors(), &poly.interiors()[1..]);
+    }
+
+    #[test]
+    fn test_edge_thresholds() {
+        let poly = two_holes();
+        assert!(poly.fill_holes(f64::INFINITY).interiors().is_empty());
+        assert_eq!(poly.fill_holes(-1.), poly);
+        assert_eq!(poly.fill_holes(f64::NAN), poly);
+    }
+
+    #[test]
+    fn test_multi_polygon() {
+        let mp = MultiPolygon::new(vec![two_holes(), two_holes()]);
+        let filled = mp.fill_holes(16.);
+        assert_eq!(filled.0.len(), 2);
+        assert!(filled.iter().all(|p| p.interiors().is_empty()));
+        assert!(MultiPolygon::<f64>::new(vec![]).fill_holes(1.).0.is_empty());
+    }
+}
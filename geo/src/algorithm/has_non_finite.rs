@@ -0,0 +1,85 @@
+use crate::{
+    Coordinate, CoordsIter, GeoFloat, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Check a geometry for `NaN` or infinite coordinates.
+///
+/// This is a cheap scan of the coordinates, to detect (and sanitize)
+/// input that can not be ordered, before it reaches algorithms that
+/// would fail on it, such as the [`BooleanOps`](crate::BooleanOps).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Coordinate, HasNonFinite, MultiPolygon};
+///
+/// let mut mp = MultiPolygon::new(vec![
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+/// ]);
+/// assert!(!mp.has_non_finite());
+///
+/// mp.0.push(polygon![(x: 2., y: 0.), (x: 3., y: f64::INFINITY), (x: 3., y: 1.)]);
+/// assert!(mp.has_non_finite());
+/// assert_eq!(
+///     mp.first_non_finite(),
+///     Some(Coordinate { x: 3., y: f64::INFINITY })
+/// );
+/// ```
+pub trait HasNonFinite<T: GeoFloat> {
+    /// Whether any coordinate is `NaN` or infinite.
+    fn has_non_finite(&self) -> bool {
+        self.first_non_finite().is_some()
+    }
+
+    /// The first coordinate that is `NaN` or infinite, if any.
+    fn first_non_finite(&self) -> Option<Coordinate<T>>;
+}
+
+/// Generate a `HasNonFinite` implementation scanning the coordinates of
+/// the geometry.
+macro_rules! coords_impl {
+    ($type:ident) => {
+        impl<T> HasNonFinite<T> for $type<T>
+        where
+            T: GeoFloat,
+        {
+            fn first_non_finite(&self) -> Option<Coordinate<T>> {
+                self.coords_iter()
+                    .find(|c| !(c.x.is_finite() && c.y.is_finite()))
+            }
+        }
+    };
+}
+
+coords_impl!(Point);
+coords_impl!(Line);
+coords_impl!(LineString);
+coords_impl!(Polygon);
+coords_impl!(MultiPoint);
+coords_impl!(MultiLineString);
+coords_impl!(MultiPolygon);
+coords_impl!(Rect);
+coords_impl!(Triangle);
+coords_impl!(GeometryCollection);
+coords_impl!(Geometry);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn injected_nan() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let mut mp = MultiPolygon::new(vec![square.clone(), square]);
+        assert!(!mp.has_non_finite());
+        assert_eq!(mp.first_non_finite(), None);
+
+        mp.0[1].interiors_push(vec![(0.2, 0.2), (0.4, f64::NAN), (0.4, 0.4)]);
+        assert!(mp.has_non_finite());
+        let c = mp.first_non_finite().unwrap();
+        assert_eq!(c.x, 0.4);
+        assert!(c.y.is_nan());
+    }
+}
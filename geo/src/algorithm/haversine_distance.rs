@@ -44,18 +44,23 @@ where
     T: CoordFloat + FromPrimitive,
 {
     fn haversine_distance(&self, rhs: &Point<T>) -> T {
-        let two = T::one() + T::one();
-        let theta1 = self.y().to_radians();
-        let theta2 = rhs.y().to_radians();
-        let delta_theta = (rhs.y() - self.y()).to_radians();
-        let delta_lambda = (rhs.x() - self.x()).to_radians();
-        let a = (delta_theta / two).sin().powi(2)
-            + theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
-        let c = two * a.sqrt().asin();
-        T::from(MEAN_EARTH_RADIUS).unwrap() * c
+        T::from(MEAN_EARTH_RADIUS).unwrap() * central_angle(*self, *rhs)
     }
 }
 
+/// The angle in radians subtended at the center of the sphere by two
+/// `Point`s given as longitude/latitude in degrees.
+pub(crate) fn central_angle<T: CoordFloat>(p: Point<T>, q: Point<T>) -> T {
+    let two = T::one() + T::one();
+    let theta1 = p.y().to_radians();
+    let theta2 = q.y().to_radians();
+    let delta_theta = (q.y() - p.y()).to_radians();
+    let delta_lambda = (q.x() - p.x()).to_radians();
+    let a = (delta_theta / two).sin().powi(2)
+        + theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
+    two * a.sqrt().asin()
+}
+
 #[cfg(test)]
 mod test {
     use crate::HaversineDistance;
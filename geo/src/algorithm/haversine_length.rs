@@ -1,14 +1,16 @@
 use num_traits::FromPrimitive;
 
-use crate::HaversineDistance;
-use crate::{CoordFloat, Line, LineString, MultiLineString};
+use crate::haversine_distance::central_angle;
+use crate::{CoordFloat, Line, LineString, MultiLineString, MEAN_EARTH_RADIUS};
 
 /// Determine the length of a geometry using the [haversine formula].
 ///
 /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
 ///
 /// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
-/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf). Use
+/// [`haversine_length_with_radius`](Self::haversine_length_with_radius)
+/// to measure on a sphere of a different radius.
 pub trait HaversineLength<T, RHS = Self> {
     /// Determine the length of a geometry using the [haversine formula].
     ///
@@ -39,6 +41,47 @@ pub trait HaversineLength<T, RHS = Self> {
     ///
     /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
     fn haversine_length(&self) -> T;
+
+    /// Determine the length of a geometry using the [haversine formula],
+    /// on a sphere of the given `radius`.
+    ///
+    /// By default, this scales [`haversine_length`](Self::haversine_length)
+    /// from the mean earth radius to `radius`.
+    ///
+    /// # Units
+    ///
+    /// - `radius`: any length unit
+    /// - return value: the unit of `radius`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::LineString;
+    ///
+    /// let linestring = LineString::<f64>::from(vec![
+    ///     // New York City
+    ///     (-74.006, 40.7128),
+    ///     // London
+    ///     (-0.1278, 51.5074),
+    /// ]);
+    ///
+    /// // Equatorial radius of the WGS-84 ellipsoid, in kilometers.
+    /// let length = linestring.haversine_length_with_radius(6378.137);
+    ///
+    /// assert_eq!(
+    ///     5_576., // kilometers
+    ///     length.round()
+    /// );
+    /// ```
+    ///
+    /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+    fn haversine_length_with_radius(&self, radius: T) -> T
+    where
+        T: CoordFloat + FromPrimitive,
+    {
+        self.haversine_length() / T::from(MEAN_EARTH_RADIUS).unwrap() * radius
+    }
 }
 
 impl<T> HaversineLength<T> for Line<T>
@@ -46,8 +89,12 @@ where
     T: CoordFloat + FromPrimitive,
 {
     fn haversine_length(&self) -> T {
+        self.haversine_length_with_radius(T::from(MEAN_EARTH_RADIUS).unwrap())
+    }
+
+    fn haversine_length_with_radius(&self, radius: T) -> T {
         let (start, end) = self.points();
-        start.haversine_distance(&end)
+        radius * central_angle(start, end)
     }
 }
 
@@ -60,6 +107,12 @@ where
             total_length + line.haversine_length()
         })
     }
+
+    fn haversine_length_with_radius(&self, radius: T) -> T {
+        self.lines().fold(T::zero(), |total_length, line| {
+            total_length + line.haversine_length_with_radius(radius)
+        })
+    }
 }
 
 impl<T> HaversineLength<T> for MultiLineString<T>
@@ -71,4 +124,62 @@ where
             .iter()
             .fold(T::zero(), |total, line| total + line.haversine_length())
     }
+
+    fn haversine_length_with_radius(&self, radius: T) -> T {
+        self.0.iter().fold(T::zero(), |total, line| {
+            total + line.haversine_length_with_radius(radius)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{line_string, HaversineLength, LineString, MultiLineString};
+
+    #[test]
+    fn linestring_test() {
+        // Paris to Berlin.
+        let linestring = line_string![
+            (x: 2.3522, y: 48.8566),
+            (x: 13.4050, y: 52.5200),
+        ];
+        assert_relative_eq!(linestring.haversine_length(), 877_464., epsilon = 1.);
+    }
+
+    #[test]
+    fn radius_test() {
+        // A quarter of the equator, and up to the north pole.
+        let mline = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 45., y: 0.), (x: 90., y: 0.)],
+            line_string![(x: 90., y: 0.), (x: 90., y: 90.)],
+        ]);
+        assert_relative_eq!(
+            mline.haversine_length_with_radius(1.),
+            std::f64::consts::PI,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            mline.haversine_length(),
+            std::f64::consts::PI * 6_371_008.8,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn default_radius_test() {
+        // Only implements the length on the mean earth radius.
+        struct Route(LineString<f64>);
+        impl HaversineLength<f64> for Route {
+            fn haversine_length(&self) -> f64 {
+                self.0.haversine_length()
+            }
+        }
+
+        let route = Route(line_string![(x: 0., y: 0.), (x: 90., y: 0.)]);
+        assert_relative_eq!(
+            route.haversine_length_with_radius(1.),
+            std::f64::consts::FRAC_PI_2,
+            epsilon = 1e-12
+        );
+    }
 }
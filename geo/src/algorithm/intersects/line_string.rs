@@ -1,7 +1,42 @@
 use super::{has_disjoint_bboxes, Intersects};
+use crate::sweep::Intersections;
 use crate::BoundingRect;
 use crate::*;
 
+/// Whether two line strings intersect, by a planar sweep over the
+/// segments of both.
+///
+/// This is the same predicate as [`Intersects`] between the line
+/// strings: they intersect if they cross, overlap or touch, in
+/// particular if they share an end point. Line strings with no segments
+/// intersect nothing.
+///
+/// The sweep stops at the first intersection between the two line
+/// strings, and only checks the pairs of segments that are adjacent in
+/// it, rather than all the pairs of segments of `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, lines_intersect};
+///
+/// let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 4., y: 0.)];
+/// let b = line_string![(x: 4., y: 0.), (x: 6., y: 2.)];
+/// let c = line_string![(x: 0., y: 1.), (x: 2., y: 3.)];
+///
+/// assert!(lines_intersect(&a, &b));
+/// assert!(!lines_intersect(&a, &c));
+/// ```
+pub fn lines_intersect<T: GeoFloat>(a: &LineString<T>, b: &LineString<T>) -> bool {
+    if has_disjoint_bboxes(a, b) {
+        return false;
+    }
+    let segments = a.lines().map(|l| (0, l)).chain(b.lines().map(|l| (1, l)));
+    segments
+        .collect::<Intersections<_>>()
+        .any(|((a, _), (b, _), _)| a != b)
+}
+
 // Blanket implementation using self.lines().any().
 impl<T, G> Intersects<G> for LineString<T>
 where
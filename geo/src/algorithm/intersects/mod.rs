@@ -73,12 +73,14 @@ mod polygon;
 mod rect;
 mod triangle;
 
+pub use line_string::lines_intersect;
+
 // Helper function to check value lies between min and max.
 // Only makes sense if min <= max (or always false)
 #[inline]
 fn value_in_range<T>(value: T, min: T, max: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     value >= min && value <= max
 }
@@ -88,7 +90,7 @@ where
 #[inline]
 fn value_in_between<T>(value: T, bound_1: T, bound_2: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     if bound_1 < bound_2 {
         value_in_range(value, bound_1, bound_2)
@@ -134,6 +136,33 @@ mod test {
         MultiPolygon, Point, Polygon, Rect,
     };
 
+    #[test]
+    fn lines_intersect_by_sweep() {
+        use super::lines_intersect;
+
+        let a = line_string![(x: 0., y: 0.), (x: 4., y: 4.), (x: 8., y: 0.)];
+        let crossing = line_string![(x: 0., y: 3.), (x: 8., y: 3.)];
+        let parallel = line_string![(x: 0., y: -1.), (x: 4., y: 3.), (x: 8., y: -1.)];
+        let touching = line_string![(x: 8., y: 0.), (x: 9., y: 0.)];
+        assert!(lines_intersect(&a, &crossing));
+        assert!(!lines_intersect(&a, &parallel));
+        assert!(lines_intersect(&a, &touching));
+        assert!(!lines_intersect(&a, &line_string![]));
+
+        // Self-intersections of either do not count.
+        let bowtie = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        assert!(!lines_intersect(&bowtie, &touching));
+
+        for (a, b) in [
+            (&a, &crossing),
+            (&a, &parallel),
+            (&a, &touching),
+            (&bowtie, &a),
+        ] {
+            assert_eq!(lines_intersect(a, b), a.intersects(b));
+        }
+    }
+
     /// Tests: intersection LineString and LineString
     #[test]
     fn empty_linestring1_test() {
@@ -376,6 +405,35 @@ mod test {
         assert!(!b3.intersects(&poly));
         assert!(b4.intersects(&poly));
     }
+    #[test]
+    fn multi_polygon_intersects_rect_test() {
+        let mp = MultiPolygon::new(vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+            ],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 25., y: 10.)],
+        ]);
+        let rect = |x0, y0, x1, y1| Rect::new(coord! { x: x0, y: y0 }, coord! { x: x1, y: y1 });
+
+        // A vertex in the rectangle.
+        assert!(mp.intersects(&rect(24., 8., 26., 12.)));
+        // Edges crossing the rectangle, without any vertex in it.
+        assert!(mp.intersects(&rect(22., -1., 28., 1.)));
+        assert!(mp.intersects(&rect(7., 4., 9., 6.)));
+        // The rectangle inside a part.
+        assert!(mp.intersects(&rect(0.5, 0.5, 1.5, 1.5)));
+        assert!(mp.intersects(&rect(24., 1., 26., 2.)));
+        // The part inside the rectangle.
+        assert!(mp.intersects(&rect(19., -1., 31., 11.)));
+
+        // Disjoint, within the bounding rectangle of a part.
+        assert!(!mp.intersects(&rect(3., 3., 7., 7.)));
+        assert!(!mp.intersects(&rect(20., 8., 22., 9.)));
+        assert!(!mp.intersects(&rect(12., 0., 18., 10.)));
+        assert!(!mp.intersects(&rect(40., 0., 50., 10.)));
+    }
+
     #[test]
     fn bounding_rect_test() {
         let bounding_rect_xl =
@@ -41,7 +41,17 @@ where
     T: GeoNum,
 {
     fn intersects(&self, rect: &Rect<T>) -> bool {
-        self.intersects(&rect.to_polygon())
+        if has_disjoint_bboxes(self, rect) {
+            return false;
+        }
+        // A vertex in the rectangle, or an edge crossing it.
+        core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(|ring| ring.lines())
+            .any(|line| rect.intersects(&line))
+            // Otherwise, the rectangle lies within a single face of the
+            // boundary, inside the polygon or outside it.
+            || self.intersects(&rect.min())
     }
 }
 symmetric_intersects_impl!(Rect<T>, Polygon<T>);
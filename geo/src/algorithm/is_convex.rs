@@ -1,7 +1,8 @@
 use crate::kernels::*;
-use crate::{Coordinate, LineString};
+use crate::{Coordinate, LineString, Polygon};
 
-/// Predicates to test the convexity of a [ `LineString` ].
+/// Predicates to test the convexity of a [ `LineString` ] or a
+/// [`Polygon`].
 /// A closed `LineString` is said to be _convex_ if it
 /// encloses a [convex set]. It is said to be _strictly
 /// convex_ if in addition, no three consecutive vertices
@@ -15,8 +16,8 @@ use crate::{Coordinate, LineString};
 ///
 /// - This definition is closely related to the notion
 /// of [convexity of polygons][convex set]. In particular, a
-/// [`Polygon`](crate::Polygon) is convex, if and only if its `exterior` is
-/// convex, and `interiors` is empty.
+/// [`Polygon`] is convex, if and only if its `exterior` is convex,
+/// and `interiors` is empty, as the predicates implement it.
 ///
 /// - The [`ConvexHull`] algorithm always returns a strictly
 /// convex `LineString` unless the input is empty or
@@ -127,6 +128,25 @@ impl<T: HasKernel> IsConvex for LineString<T> {
     }
 }
 
+impl<T: HasKernel> IsConvex for Polygon<T> {
+    fn convex_orientation(
+        &self,
+        allow_collinear: bool,
+        specific_orientation: Option<Orientation>,
+    ) -> Option<Orientation> {
+        if self.interiors().is_empty() {
+            self.exterior()
+                .convex_orientation(allow_collinear, specific_orientation)
+        } else {
+            None
+        }
+    }
+
+    fn is_collinear(&self) -> bool {
+        self.exterior().is_collinear()
+    }
+}
+
 /// A utility that tests convexity of a sequence of
 /// coordinates. It verifies that for all `0 <= i < n`, the
 /// vertices at positions `i`, `i+1`, `i+2` (mod `n`) have
@@ -245,4 +265,23 @@ mod tests {
         assert!(!two.is_strictly_ccw_convex());
         assert!(!two.is_strictly_cw_convex());
     }
+
+    #[test]
+    fn test_polygon() {
+        let square = line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)];
+        let hole = line_string![(x: 1, y: 1), (x: 1, y: 2), (x: 2, y: 2), (x: 2, y: 1)];
+        assert!(Polygon::new(square.clone(), vec![]).is_strictly_ccw_convex());
+        // `Polygon::is_convex` is the deprecated inherent method.
+        assert!(!IsConvex::is_convex(&Polygon::new(square, vec![hole])));
+
+        let l_shape = line_string![
+            (x: 0, y: 0),
+            (x: 2, y: 0),
+            (x: 2, y: 1),
+            (x: 1, y: 1),
+            (x: 1, y: 2),
+            (x: 0, y: 2),
+        ];
+        assert!(!IsConvex::is_convex(&Polygon::new(l_shape, vec![])));
+    }
 }
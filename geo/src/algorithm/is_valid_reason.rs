@@ -0,0 +1,275 @@
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::sweep::Intersections;
+use crate::{
+    Coordinate, GeoFloat, HasNonFinite, Line, LineIntersection, LineString, MultiPolygon, Polygon,
+};
+
+/// The first reason a geometry is invalid, if any.
+///
+/// The checks stop at the first problem found, and report it as a
+/// message, with the location of the problem. The messages follow those
+/// of `isValidReason` in GEOS:
+///
+/// - `"Invalid Coordinate"`: a coordinate is `NaN` or infinite.
+/// - `"Too few points"`: a ring has fewer than four distinct points.
+/// - `"Self-intersection"`: two rings cross or overlap, or a ring
+///   crosses itself.
+/// - `"Ring Self-intersection"`: a ring touches itself at a point.
+/// - `"Hole lies outside shell"`, `"Holes are nested"` and `"Nested
+///   shells"`: the rings of the polygons are not nested correctly.
+///
+/// The intersections between the rings are found by a single planar
+/// sweep, which stops at the first one that is not allowed. Rings may
+/// only touch other rings at isolated points.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Coordinate, IsValidReason};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// assert_eq!(square.is_valid_reason(), None);
+///
+/// let bowtie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert_eq!(
+///     bowtie.is_valid_reason(),
+///     Some(("Self-intersection".to_string(), Coordinate { x: 1., y: 1. }))
+/// );
+/// ```
+pub trait IsValidReason<T: GeoFloat> {
+    fn is_valid_reason(&self) -> Option<(String, Coordinate<T>)>;
+}
+
+impl<T: GeoFloat> IsValidReason<T> for Polygon<T> {
+    fn is_valid_reason(&self) -> Option<(String, Coordinate<T>)> {
+        polygons_reason(std::slice::from_ref(self))
+            .map(|(reason, c)| (reason.message().to_string(), c))
+    }
+}
+
+impl<T: GeoFloat> IsValidReason<T> for MultiPolygon<T> {
+    fn is_valid_reason(&self) -> Option<(String, Coordinate<T>)> {
+        polygons_reason(&self.0).map(|(reason, c)| (reason.message().to_string(), c))
+    }
+}
+
+/// The reasons reported by [`IsValidReason`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reason {
+    InvalidCoordinate,
+    TooFewPoints,
+    SelfIntersection,
+    RingSelfIntersection,
+    HoleOutsideShell,
+    NestedHoles,
+    NestedShells,
+}
+
+impl Reason {
+    pub(crate) fn message(self) -> &'static str {
+        match self {
+            Reason::InvalidCoordinate => "Invalid Coordinate",
+            Reason::TooFewPoints => "Too few points",
+            Reason::SelfIntersection => "Self-intersection",
+            Reason::RingSelfIntersection => "Ring Self-intersection",
+            Reason::HoleOutsideShell => "Hole lies outside shell",
+            Reason::NestedHoles => "Holes are nested",
+            Reason::NestedShells => "Nested shells",
+        }
+    }
+}
+
+pub(crate) fn polygons_reason<T: GeoFloat>(
+    polygons: &[Polygon<T>],
+) -> Option<(Reason, Coordinate<T>)> {
+    let invalid = |reason: Reason, c: Coordinate<T>| Some((reason, c));
+
+    for poly in polygons {
+        if let Some(c) = poly.first_non_finite() {
+            return invalid(Reason::InvalidCoordinate, c);
+        }
+    }
+
+    // The distinct consecutive points of the non-empty rings, and the
+    // indices of the shell and holes of each polygon in them.
+    let mut rings: Vec<Vec<_>> = vec![];
+    let mut shells = vec![];
+    for poly in polygons.iter().filter(|poly| !poly.exterior().0.is_empty()) {
+        let shell = rings.len();
+        for ring in std::iter::once(poly.exterior()).chain(poly.interiors()) {
+            let mut coords = ring.0.clone();
+            coords.dedup();
+            match coords.len() {
+                0 => continue,
+                1..=3 => return invalid(Reason::TooFewPoints, coords[0]),
+                _ => rings.push(coords),
+            }
+        }
+        shells.push((shell, shell + 1..rings.len()));
+    }
+
+    // Segments are tagged by the index of their ring, and their index
+    // in the ring.
+    let segments = rings.iter().enumerate().flat_map(|(ring_idx, coords)| {
+        coords
+            .windows(2)
+            .enumerate()
+            .map(move |(seg_idx, w)| (ring_idx, (seg_idx, Line::new(w[0], w[1]))))
+    });
+    let mut intersections: Intersections<_> = segments.collect();
+    let crossing = intersections.find_map(|((r1, (s1, _)), (r2, (s2, _)), int)| {
+        let (pt, is_proper) = match int {
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper,
+            } => (intersection, is_proper),
+            LineIntersection::Collinear { intersection } => {
+                return Some((Reason::SelfIntersection, intersection.start))
+            }
+        };
+        if r1 != r2 {
+            return if is_proper {
+                Some((Reason::SelfIntersection, pt))
+            } else {
+                None
+            };
+        }
+        let last = rings[r1].len() - 2;
+        let (s1, s2) = (s1.min(s2), s1.max(s2));
+        let adjacent = s2 - s1 == 1 || (s1 == 0 && s2 == last);
+        if adjacent {
+            None
+        } else if is_proper {
+            Some((Reason::SelfIntersection, pt))
+        } else {
+            Some((Reason::RingSelfIntersection, pt))
+        }
+    });
+    if let Some((reason, c)) = crossing {
+        return invalid(reason, c);
+    }
+
+    // The rings do not cross, so a ring is inside another one if any of
+    // its points not on the other one is.
+    let position = |ring: &[Coordinate<T>], other: &[Coordinate<T>]| {
+        let other = LineString::from(other.to_vec());
+        ring.iter()
+            .map(|c| (*c, coord_pos_relative_to_ring(*c, &other)))
+            .find(|(_, pos)| *pos != CoordPos::OnBoundary)
+    };
+    for (shell, holes) in &shells {
+        for hole in holes.clone() {
+            if let Some((c, CoordPos::Outside)) = position(&rings[hole], &rings[*shell]) {
+                return invalid(Reason::HoleOutsideShell, c);
+            }
+            for other in holes.clone().filter(|other| *other != hole) {
+                if let Some((c, CoordPos::Inside)) = position(&rings[hole], &rings[other]) {
+                    return invalid(Reason::NestedHoles, c);
+                }
+            }
+        }
+    }
+    for (shell, _) in &shells {
+        for (other, holes) in shells.iter().filter(|(other, _)| other != shell) {
+            if let Some((c, CoordPos::Inside)) = position(&rings[*shell], &rings[*other]) {
+                let in_hole = holes.clone().any(|hole| {
+                    let hole = LineString::from(rings[hole].clone());
+                    coord_pos_relative_to_ring(c, &hole) != CoordPos::Outside
+                });
+                if !in_hole {
+                    return invalid(Reason::NestedShells, c);
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn self_intersection() {
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: -2.),
+            (x: 0., y: -2.),
+        ];
+        assert_eq!(
+            poly.is_valid_reason(),
+            Some(("Self-intersection".to_string(), Coordinate { x: 2., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn rings() {
+        let square = |x: f64, y: f64, size: f64| -> LineString<f64> {
+            vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]
+            .into()
+        };
+
+        let valid = Polygon::new(
+            square(0., 0., 10.),
+            vec![square(1., 1., 2.), square(5., 5., 2.)],
+        );
+        assert_eq!(valid.is_valid_reason(), None);
+
+        // A hole touching the shell at a point.
+        let touching = Polygon::new(
+            square(0., 0., 10.),
+            vec![vec![(0., 5.), (3., 4.), (3., 6.)].into()],
+        );
+        assert_eq!(touching.is_valid_reason(), None);
+
+        let outside = Polygon::new(square(0., 0., 10.), vec![square(20., 20., 2.)]);
+        assert_eq!(
+            outside.is_valid_reason(),
+            Some((
+                "Hole lies outside shell".to_string(),
+                Coordinate { x: 20., y: 20. }
+            ))
+        );
+
+        let nested = Polygon::new(
+            square(0., 0., 10.),
+            vec![square(1., 1., 8.), square(2., 2., 2.)],
+        );
+        assert_eq!(
+            nested.is_valid_reason().map(|(r, _)| r),
+            Some("Holes are nested".to_string())
+        );
+
+        let shells = MultiPolygon::new(vec![
+            Polygon::new(square(0., 0., 10.), vec![]),
+            Polygon::new(square(2., 2., 2.), vec![]),
+        ]);
+        assert_eq!(
+            shells.is_valid_reason().map(|(r, _)| r),
+            Some("Nested shells".to_string())
+        );
+
+        // A shell in the hole of another one is fine.
+        let island = MultiPolygon::new(vec![
+            Polygon::new(square(0., 0., 10.), vec![square(1., 1., 8.)]),
+            Polygon::new(square(2., 2., 2.), vec![]),
+        ]);
+        assert_eq!(island.is_valid_reason(), None);
+
+        let few = Polygon::new(vec![(0., 0.), (1., 0.), (1., 0.), (0., 0.)].into(), vec![]);
+        assert_eq!(
+            few.is_valid_reason(),
+            Some(("Too few points".to_string(), Coordinate { x: 0., y: 0. }))
+        );
+    }
+}
@@ -1,8 +1,10 @@
+use core::cmp::Ordering;
 use num_traits::Zero;
-use std::cmp::Ordering;
 
 use crate::{coord, CoordNum, Coordinate};
 
+/// The orientation of three 2-dimensional points, as computed by
+/// [`orient2d`].
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum Orientation {
     CounterClockwise,
@@ -56,6 +58,40 @@ pub trait Kernel<T: CoordNum> {
     }
 }
 
+/// Gives the orientation of `r` with respect to the line from `p` to
+/// `q`: `CounterClockwise` if `r` lies to its left, `Clockwise` if `r`
+/// lies to its right, and `Collinear` otherwise.
+///
+/// This uses the [`Kernel`] of the scalar type, which is also used by
+/// the algorithms of this crate (eg. the planar sweep), so the results
+/// agree with them.
+///
+/// # Exactness
+///
+/// For `f64` and `f32`, the [`RobustKernel`] evaluates the sign of the
+/// determinant using Shewchuk's adaptive precision arithmetic. The
+/// result is always exact: it is the sign of the determinant of the
+/// input coordinates as if computed with infinite precision, even when
+/// the naive floating-point evaluation rounds to the wrong sign.
+///
+/// For integer types, the [`SimpleKernel`] computes the determinant
+/// directly, which is exact as long as it does not overflow.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, orient2d, Orientation};
+///
+/// // Nearly collinear: the naive determinant rounds to zero here.
+/// let p = coord! { x: 0.5, y: 0.5000000000000001 };
+/// let q = coord! { x: 12., y: 12. };
+/// let r = coord! { x: 24., y: 24. };
+/// assert_eq!(orient2d(p, q, r), Orientation::CounterClockwise);
+/// ```
+pub fn orient2d<T: HasKernel>(p: Coordinate<T>, q: Coordinate<T>, r: Coordinate<T>) -> Orientation {
+    T::Ker::orient2d(p, q, r)
+}
+
 /// Marker trait to assign Kernel for scalars
 pub trait HasKernel: CoordNum {
     type Ker: Kernel<Self>;
@@ -88,3 +124,42 @@ has_kernel!(isize, SimpleKernel);
 
 #[cfg(has_i128)]
 has_kernel!(i128, SimpleKernel);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orient2d_nearly_collinear() {
+        // The naive determinant gives the opposite sign.
+        let p = coord! { x: 24., y: 24. };
+        let q = coord! { x: 0.5000000000000046, y: 0.5000000000000053 };
+        let r = coord! { x: 12., y: 12. };
+        assert_eq!(SimpleKernel::orient2d(p, q, r), Orientation::Clockwise);
+        assert_eq!(orient2d(p, q, r), Orientation::CounterClockwise);
+        assert_eq!(orient2d(q, p, r), Orientation::Clockwise);
+
+        // The naive determinant rounds to zero.
+        let p = coord! { x: 0.5, y: 0.5000000000000001 };
+        let q = coord! { x: 12., y: 12. };
+        let r = coord! { x: 24., y: 24. };
+        assert_eq!(SimpleKernel::orient2d(p, q, r), Orientation::Collinear);
+        assert_eq!(orient2d(p, q, r), Orientation::CounterClockwise);
+    }
+
+    #[test]
+    fn test_orient2d_exact() {
+        let p = coord! { x: 0.5, y: 0.5 };
+        let q = coord! { x: 12., y: 12. };
+        let r = coord! { x: 24., y: 24. };
+        assert_eq!(orient2d(p, q, r), Orientation::Collinear);
+        assert_eq!(
+            orient2d(
+                coord! { x: 0, y: 0 },
+                coord! { x: 2, y: 0 },
+                coord! { x: 1, y: 1 }
+            ),
+            Orientation::CounterClockwise
+        );
+    }
+}
@@ -158,6 +158,65 @@ where
     }
 }
 
+/// Returns the parameters of the intersection point of two [`Lines`](Line) along each of them.
+///
+/// The parameters `(t, u)` are such that the intersection is at `p.start + t * (p.end - p.start)`
+/// and `q.start + u * (q.end - q.start)`, and are within `[0, 1]`. They allow interpolating
+/// attributes of the lines, such as a z-coordinate or a time, at the intersection.
+///
+/// Whether the lines intersect is decided by [`line_intersection`], with robust predicates; the
+/// parameters are then computed in floating point, from the intersection point. An endpoint of a
+/// line at the intersection has the exact parameter `0` or `1`.
+///
+/// Returns `None` if the lines do not intersect, and also if they are collinear and overlap,
+/// since the intersection is then not a single point: use [`line_intersection`] for the
+/// overlapping segment.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::coord;
+/// use geo::Line;
+/// use geo::line_intersection::line_intersection_params;
+///
+/// let line_1 = Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 4.0, y: 0.0 });
+/// let line_2 = Line::new(coord! { x: 1.0, y: -1.0 }, coord! { x: 1.0, y: 3.0 });
+/// let (t, u): (f64, f64) = line_intersection_params(line_1, line_2).unwrap();
+/// assert!((t - 0.25).abs() < 1e-12 && (u - 0.25).abs() < 1e-12);
+///
+/// let line_3 = Line::new(coord! { x: 2.0, y: 0.0 }, coord! { x: 6.0, y: 0.0 });
+/// assert_eq!(line_intersection_params(line_1, line_3), None);
+/// ```
+pub fn line_intersection_params<F>(p: Line<F>, q: Line<F>) -> Option<(F, F)>
+where
+    F: GeoFloat,
+{
+    match line_intersection(p, q)? {
+        LineIntersection::SinglePoint { intersection, .. } => {
+            Some((line_param(p, intersection), line_param(q, intersection)))
+        }
+        LineIntersection::Collinear { .. } => None,
+    }
+}
+
+/// The parameter along `line` of the projection of `c` on it, clamped to `[0, 1]`.
+fn line_param<F: GeoFloat>(line: Line<F>, c: Coordinate<F>) -> F {
+    if c == line.start {
+        return F::zero();
+    }
+    if c == line.end {
+        return F::one();
+    }
+    let delta = line.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+    if length_2 == F::zero() {
+        return F::zero();
+    }
+    let to_c = c - line.start;
+    let t = (to_c.x * delta.x + to_c.y * delta.y) / length_2;
+    t.max(F::zero()).min(F::one())
+}
+
 fn collinear_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
     fn collinear<F: GeoFloat>(intersection: Line<F>) -> LineIntersection<F> {
         LineIntersection::Collinear { intersection }
@@ -314,6 +373,29 @@ mod test {
     use super::*;
     use crate::geo_types::coord;
 
+    #[test]
+    fn test_params() {
+        // An X crossing at (3, 3).
+        let line_1 = Line::new(coord! { x: 0., y: 0. }, coord! { x: 8., y: 8. });
+        let line_2 = Line::new(coord! { x: 0., y: 6. }, coord! { x: 12., y: -6. });
+        let (t, u) = line_intersection_params(line_1, line_2).unwrap();
+        assert_relative_eq!(t, 0.375);
+        assert_relative_eq!(u, 0.25);
+        let (u, t) = line_intersection_params(line_2, line_1).unwrap();
+        assert_relative_eq!(t, 0.375);
+        assert_relative_eq!(u, 0.25);
+
+        // Touching at an end point.
+        let line_3 = Line::new(coord! { x: 8., y: 8. }, coord! { x: 8., y: 0. });
+        assert_eq!(line_intersection_params(line_1, line_3), Some((1., 0.)));
+
+        // Parallel, and collinear.
+        let line_4 = Line::new(coord! { x: 0., y: 1. }, coord! { x: 8., y: 9. });
+        assert_eq!(line_intersection_params(line_1, line_4), None);
+        let line_5 = Line::new(coord! { x: 4., y: 4. }, coord! { x: 12., y: 12. });
+        assert_eq!(line_intersection_params(line_1, line_5), None);
+    }
+
     /// Based on JTS test `testCentralEndpointHeuristicFailure`
     /// > Following cases were failures when using the CentralEndpointIntersector heuristic.
     /// > This is because one segment lies at a significant angle to the other,
@@ -1238,6 +1238,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn multipolygon_try_map_coords() {
+        let mp = MultiPolygon::new(vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+            ],
+            polygon![(x: 20., y: 80.), (x: 30., y: 80.), (x: 30., y: 90.)],
+        ]);
+
+        // The identity keeps the parts, rings and their closing coordinates.
+        let identity = mp.try_map_coords(Ok::<_, ()>).unwrap();
+        assert_eq!(identity, mp);
+        assert_eq!(identity.map_coords(|c| c), mp);
+
+        // A projection failing at the poles fails the whole map.
+        let project = |c: Coordinate<f64>| {
+            if c.y.abs() < 90. {
+                Ok(coord! { x: c.x, y: c.y.to_radians().tan() })
+            } else {
+                Err(c)
+            }
+        };
+        assert_eq!(mp.try_map_coords(project), Err(coord! { x: 30., y: 90. }));
+        let projected = MultiPolygon::new(vec![mp.0[0].clone()])
+            .try_map_coords(project)
+            .unwrap();
+        assert_eq!(projected.0[0].interiors().len(), 1);
+        assert!(projected.0[0].exterior().is_closed());
+        assert!(projected.0[0].interiors()[0].is_closed());
+    }
+
     #[test]
     fn rect_map_invert_coords() {
         let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
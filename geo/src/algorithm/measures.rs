@@ -0,0 +1,235 @@
+use crate::{Centroid, Coordinate, GeoFloat, LineString, MultiPolygon, Point, Polygon, Rect};
+
+/// The area, centroid and bounding rectangle of a geometry, as computed
+/// by [`Measure::measures`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measures<T: GeoFloat> {
+    /// The signed area, as [`Area::signed_area`](crate::Area::signed_area).
+    pub area: T,
+    /// The centroid, as [`Centroid::centroid`].
+    pub centroid: Option<Point<T>>,
+    /// The bounding rectangle, as
+    /// [`BoundingRect::bounding_rect`](crate::BoundingRect::bounding_rect).
+    pub bounds: Option<Rect<T>>,
+}
+
+/// Compute the area, centroid and bounding rectangle of a polygonal
+/// geometry in a single pass over its coordinates.
+///
+/// Computing them separately iterates over the coordinates three times,
+/// which dominates on polygons with many vertices. The results are
+/// the same as those of the separate methods; only the centroid of
+/// degenerate polygons, whose exterior or net area is zero, is computed
+/// by a second pass.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Measure, Rect};
+///
+/// let frame = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 1., y: 2.)]],
+/// ];
+/// let measures = frame.measures();
+///
+/// assert_eq!(measures.area, 14.);
+/// assert_eq!(measures.centroid.unwrap().x(), 2.);
+/// assert_eq!(
+///     measures.bounds,
+///     Some(Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. }))
+/// );
+/// ```
+pub trait Measure<T: GeoFloat> {
+    fn measures(&self) -> Measures<T>;
+}
+
+impl<T: GeoFloat> Measure<T> for Polygon<T> {
+    fn measures(&self) -> Measures<T> {
+        let mut sums = Sums::new();
+        sums.add_polygon(self);
+        sums.finish(|| self.centroid())
+    }
+}
+
+impl<T: GeoFloat> Measure<T> for MultiPolygon<T> {
+    fn measures(&self) -> Measures<T> {
+        let mut sums = Sums::new();
+        self.iter().for_each(|poly| sums.add_polygon(poly));
+        sums.finish(|| self.centroid())
+    }
+}
+
+/// The sums accumulated over the rings.
+struct Sums<T: GeoFloat> {
+    area: T,
+    /// The area weighted centroids, and their total weight.
+    accumulated: Coordinate<T>,
+    weight: T,
+    /// Whether a polygon is degenerate, for the centroid.
+    degenerate: bool,
+    min: Option<Coordinate<T>>,
+    max: Option<Coordinate<T>>,
+}
+
+impl<T: GeoFloat> Sums<T> {
+    fn new() -> Self {
+        Sums {
+            area: T::zero(),
+            accumulated: Coordinate::zero(),
+            weight: T::zero(),
+            degenerate: false,
+            min: None,
+            max: None,
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        let (area, centroid) = self.add_ring(polygon.exterior(), true);
+        if area == T::zero() {
+            self.degenerate = true;
+        }
+        let mut weight = area.abs();
+        let mut accumulated = centroid * weight;
+
+        // As `Area` and `Centroid`, whatever the orientation of the rings.
+        let mut net_area = area.abs();
+        let (mut interiors, mut interiors_weight) = (Coordinate::zero(), T::zero());
+        for interior in polygon.interiors() {
+            let (area, centroid) = self.add_ring(interior, false);
+            net_area = net_area - area.abs();
+            if area != T::zero() {
+                interiors = interiors + centroid * area.abs();
+                interiors_weight = interiors_weight + area.abs();
+            }
+        }
+        if interiors_weight != T::zero() {
+            accumulated = accumulated - interiors;
+            weight = weight - interiors_weight;
+            if weight.is_zero() {
+                self.degenerate = true;
+            }
+        }
+
+        let signed_area = if area < T::zero() {
+            -net_area
+        } else {
+            net_area
+        };
+        self.area = self.area + signed_area;
+        self.accumulated = self.accumulated + accumulated;
+        self.weight = self.weight + weight;
+    }
+
+    /// The signed area and the centroid of a ring, extending the bounds
+    /// by the exteriors.
+    fn add_ring(&mut self, ring: &LineString<T>, is_exterior: bool) -> (T, Coordinate<T>) {
+        let shift = match ring.0.first() {
+            Some(first) => *first,
+            None => return (T::zero(), Coordinate::zero()),
+        };
+        // Shifted for precision, as `Area` and `Centroid`.
+        let mut twice_area = T::zero();
+        let mut accumulated = Coordinate::zero();
+        let mut prev = Coordinate::zero();
+        for c in &ring.0 {
+            if is_exterior {
+                self.extend_bounds(*c);
+            }
+            let c = *c - shift;
+            let determinant = prev.x * c.y - prev.y * c.x;
+            twice_area = twice_area + determinant;
+            accumulated = accumulated + (c + prev) * determinant;
+            prev = c;
+        }
+        if ring.0.len() < 3 || !ring.is_closed() {
+            return (T::zero(), Coordinate::zero());
+        }
+
+        let area = twice_area / (T::one() + T::one());
+        if area == T::zero() {
+            return (area, Coordinate::zero());
+        }
+        let six = T::from(6).unwrap();
+        (area, accumulated / (six * area) + shift)
+    }
+
+    fn extend_bounds(&mut self, c: Coordinate<T>) {
+        self.min = Some(match self.min {
+            Some(min) => Coordinate {
+                x: min.x.min(c.x),
+                y: min.y.min(c.y),
+            },
+            None => c,
+        });
+        self.max = Some(match self.max {
+            Some(max) => Coordinate {
+                x: max.x.max(c.x),
+                y: max.y.max(c.y),
+            },
+            None => c,
+        });
+    }
+
+    fn finish(self, centroid: impl FnOnce() -> Option<Point<T>>) -> Measures<T> {
+        let centroid = if self.degenerate || self.weight == T::zero() {
+            centroid()
+        } else {
+            Some(Point::from(self.accumulated / self.weight))
+        };
+        let bounds = self.min.zip(self.max).map(|(min, max)| Rect::new(min, max));
+        Measures {
+            area: self.area,
+            centroid,
+            bounds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, BoundingRect};
+
+    fn assert_matches<G>(geom: &G)
+    where
+        G: Measure<f64> + Area<f64> + Centroid<Output = Option<Point<f64>>>,
+        G: BoundingRect<f64, Output = Option<Rect<f64>>>,
+    {
+        let measures = geom.measures();
+        assert_eq!(measures.area, geom.signed_area());
+        assert_eq!(measures.centroid, geom.centroid());
+        assert_eq!(measures.bounds, geom.bounding_rect());
+    }
+
+    #[test]
+    fn matches_separate_methods() {
+        let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        assert_matches(&norway);
+
+        // Holes of either orientation, and a clockwise exterior.
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 3.), (x: 1., y: 3.)],
+                [(x: 6., y: 6.), (x: 6., y: 9.), (x: 9., y: 9.), (x: 9., y: 6.)],
+            ],
+        ];
+        let clockwise = polygon![(x: 20., y: 0.), (x: 20., y: 5.), (x: 27., y: 1.)];
+        assert_matches(&frame);
+        assert_matches(&MultiPolygon::new(vec![frame, clockwise, norway]));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_matches(&MultiPolygon::<f64>::new(vec![]));
+        assert_matches(&polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 2., y: 1.)]);
+
+        // A hole covering the exterior.
+        let square = [(1., 1.), (4., 1.), (4., 4.), (1., 4.)];
+        let covered = Polygon::new(square.to_vec().into(), vec![square.to_vec().into()]);
+        assert_matches(&covered);
+        let triangle = polygon![(x: 5., y: 0.), (x: 7., y: 0.), (x: 6., y: 2.)];
+        assert_matches(&MultiPolygon::new(vec![covered, triangle]));
+    }
+}
@@ -1,15 +1,31 @@
 /// Kernels to compute various predicates
 pub mod kernels;
-pub use kernels::{HasKernel, Kernel, Orientation};
+pub use kernels::{orient2d, HasKernel, Kernel, Orientation};
+
+/// Scale and rotate a `Geometry` about a chosen origin.
+pub mod anchored_transform;
+pub use anchored_transform::AnchoredTransform;
 
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
 pub use area::Area;
 
+/// Calculate the barycentric coordinates of a `Point` relative to a `Triangle`.
+pub mod barycentric;
+pub use barycentric::Barycentric;
+
 /// Calculate the bearing to another `Point`, in degrees.
 pub mod bearing;
 pub use bearing::Bearing;
 
+/// Calculate the boundary of an areal `Geometry` as a `MultiLineString`.
+pub mod boundary;
+pub use boundary::Boundary;
+
+/// Calculate the smallest circle enclosing a `Geometry`.
+pub mod bounding_circle;
+pub use bounding_circle::BoundingCircle;
+
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 pub use bounding_rect::BoundingRect;
@@ -30,6 +46,10 @@ pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// Find the index of the vertex or segment of a geometry nearest to a point.
+pub mod closest_point_index;
+pub use closest_point_index::ClosestPointIndex;
+
 /// Calculate the concave hull of a `Geometry`.
 pub mod concave_hull;
 pub use concave_hull::ConcaveHull;
@@ -38,9 +58,17 @@ pub use concave_hull::ConcaveHull;
 pub mod contains;
 pub use contains::Contains;
 
+/// Find the polygons of a set contained by a query polygon, filtering by bounding rectangles first.
+pub mod contains_index;
+pub use contains_index::{containing_indices, contains_any, ContainsIndex};
+
 /// Convert the type of a geometry’s coordinate value.
 pub mod convert;
-pub use convert::{Convert, TryConvert};
+pub use convert::{Convert, InvalidConversionError, ToF32Validated, TryConvert};
+
+/// Decompose a `Polygon` into convex polygons.
+pub mod convex_decomposition;
+pub use convex_decomposition::ConvexDecomposition;
 
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
@@ -54,13 +82,21 @@ pub use coordinate_position::CoordinatePosition;
 pub mod coords_iter;
 pub use coords_iter::CoordsIter;
 
+/// Split a self-intersecting `LineString` into its simple loops.
+pub mod decompose_loops;
+pub use decompose_loops::DecomposeLoops;
+
+/// Compute the Delaunay triangulation and Voronoi diagram of the vertices of a geometry.
+pub mod delaunay;
+pub use delaunay::Delaunay;
+
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
-pub use euclidean_distance::EuclideanDistance;
+pub use euclidean_distance::{EuclideanDistance, SquaredEuclideanDistance};
 
 /// Calculate the length of a planar line between two `Geometries`.
 pub mod euclidean_length;
@@ -70,6 +106,10 @@ pub use euclidean_length::EuclideanLength;
 pub mod extremes;
 pub use extremes::Extremes;
 
+/// Fill the holes of a `Polygon` or `MultiPolygon` below an area threshold.
+pub mod fill_holes;
+pub use fill_holes::FillHoles;
+
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
 pub use frechet_distance::FrechetDistance;
@@ -86,6 +126,10 @@ pub use geodesic_intermediate::GeodesicIntermediate;
 pub mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Check a geometry for `NaN` or infinite coordinates.
+pub mod has_non_finite;
+pub use has_non_finite::HasNonFinite;
+
 /// Calculate a destination `Point`, given a distance and a bearing.
 pub mod haversine_destination;
 pub use haversine_destination::HaversineDestination;
@@ -104,12 +148,16 @@ pub use haversine_length::HaversineLength;
 
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
-pub use intersects::Intersects;
+pub use intersects::{lines_intersect, Intersects};
 
-/// Determines whether a `LineString` is convex.
+/// Determines whether a `LineString` or `Polygon` is convex.
 pub mod is_convex;
 pub use is_convex::IsConvex;
 
+/// Find the first reason a `Polygon` or `MultiPolygon` is invalid.
+pub mod is_valid_reason;
+pub use is_valid_reason::IsValidReason;
+
 /// Calculate concave hull using k-nearest algorithm
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
@@ -136,17 +184,57 @@ pub use map_coords::{MapCoords, MapCoordsInPlace};
 #[allow(deprecated)]
 pub use map_coords::{MapCoordsInplace, TryMapCoords, TryMapCoordsInplace};
 
+/// Compute the area, centroid and bounding rectangle in a single pass.
+pub mod measures;
+pub use measures::{Measure, Measures};
+
+/// Find the closest pair of points of two polygonal geometries.
+pub mod nearest_points;
+pub use nearest_points::NearestPoints;
+
+/// Offset a `LineString` to one side.
+pub mod offset;
+pub use offset::Offset;
+
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
 pub use orient::Orient;
 
+/// Calculate the length of the boundary of a `Polygon` or `MultiPolygon`, holes included.
+pub mod perimeter;
+pub use perimeter::Perimeter;
+
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
 pub(crate) mod polygon_distance_fast_path;
 
+/// Sample points at a regular spacing along a `LineString`.
+pub mod points_along;
+pub use points_along::PointsAlong;
+
+/// Pre-process a `MultiPolygon` for fast repeated point-in-polygon queries.
+pub mod prepared_polygon;
+pub use prepared_polygon::PreparedPolygon;
+
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
 
+/// Map coordinates to a fixed-point integer grid spanning a bounding box, and back.
+pub mod quantize;
+pub use quantize::Quantizer;
+
+/// Find the reflex vertices of a ring or a polygon.
+pub mod reflex_vertices;
+pub use reflex_vertices::ReflexVertices;
+
+/// Remove the thin parts of a `MultiPolygon`, by a shape metric.
+pub mod remove_slivers;
+pub use remove_slivers::RemoveSlivers;
+
+/// Remove zero-width spikes from a `LineString`, `Polygon` or `MultiPolygon`.
+pub mod remove_spikes;
+pub use remove_spikes::RemoveSpikes;
+
 /// Relate two geometries based on DE-9IM
 pub mod relate;
 pub use relate::Relate;
@@ -157,12 +245,23 @@ pub use rotate::{Rotate, RotatePoint};
 
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
-pub use simplify::{Simplify, SimplifyIdx};
+pub use simplify::{
+    simplify_ring, Simplify, SimplifyIdx, SimplifyTopologyPreserving, SimplifyWithFixed,
+    SimplifyWithMinPoints,
+};
 
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplifyvw;
 pub use simplifyvw::{SimplifyVW, SimplifyVWPreserve, SimplifyVwIdx};
 
+/// Round the coordinates of a `Geometry` to a grid.
+pub mod snap_to_grid;
+pub use snap_to_grid::{HashableCoord, SnapToGrid};
+
+/// Split a polygonal geometry in longitude/latitude along the antimeridian.
+pub mod split_at_antimeridian;
+pub use split_at_antimeridian::SplitAtAntimeridian;
+
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
 pub mod transform;
@@ -181,6 +280,10 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Count the windings of the boundary of a geometry around a coordinate.
+pub mod winding_number;
+pub use winding_number::WindingNumber;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -190,7 +293,11 @@ pub mod sweep;
 
 /// Boolean Ops such as union, xor, difference;
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+pub use bool_ops::{
+    collection_op, difference_all, unary_intersection, unary_union, venn, AreaSimilarity,
+    BooleanOps, BooleanOpsMixedPrecision, BufferVariable, GridClip, MakeValid, OpType,
+    UnionGapClosing, VennRegions,
+};
 
 /// Densify linear geometry components
 pub mod densify;
@@ -0,0 +1,204 @@
+use crate::closest_point_index::project;
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{
+    BoundingRect, Coordinate, GeoFloat, Intersects, Line, MultiPolygon, Point, Polygon, Rect,
+    SquaredEuclideanDistance,
+};
+
+/// The closest pair of points of two polygonal geometries, and the
+/// distance between them.
+///
+/// The first point lies on `self`, and the second on `other`. When the
+/// geometries are disjoint, both points lie on the boundaries, and are
+/// found by comparing the closest approach of pairs of edges; the pairs
+/// of edges whose bounding rectangles are farther apart than the closest
+/// pair found so far are skipped. When the geometries intersect, the
+/// distance is zero, and both points are the same point of their
+/// intersection.
+///
+/// Returns `None` if either geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, NearestPoints, Point};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 5., y: 1.), (x: 7., y: 1.), (x: 7., y: 3.), (x: 5., y: 3.)];
+///
+/// let (on_a, on_b, distance) = a.nearest_points(&b).unwrap();
+/// assert_eq!(distance, 3.);
+/// assert_eq!(on_a.x(), 2.);
+/// assert_eq!(on_b.x(), 5.);
+/// assert_eq!(on_a.y(), on_b.y());
+/// ```
+pub trait NearestPoints<T: GeoFloat, Rhs = Self> {
+    fn nearest_points(&self, other: &Rhs) -> Option<(Point<T>, Point<T>, T)>;
+}
+
+impl<T: GeoFloat> NearestPoints<T> for Polygon<T> {
+    fn nearest_points(&self, other: &Self) -> Option<(Point<T>, Point<T>, T)> {
+        nearest_points(std::slice::from_ref(self), std::slice::from_ref(other))
+    }
+}
+
+impl<T: GeoFloat> NearestPoints<T> for MultiPolygon<T> {
+    fn nearest_points(&self, other: &Self) -> Option<(Point<T>, Point<T>, T)> {
+        nearest_points(&self.0, &other.0)
+    }
+}
+
+fn nearest_points<T: GeoFloat>(
+    a: &[Polygon<T>],
+    b: &[Polygon<T>],
+) -> Option<(Point<T>, Point<T>, T)> {
+    // A polygon with a vertex in the other geometry; otherwise, the
+    // geometries either are disjoint or have crossing boundaries.
+    let vertex_within = |polys: &[Polygon<T>], other: &[Polygon<T>]| {
+        polys
+            .iter()
+            .filter_map(|poly| poly.exterior().0.first().copied())
+            .find(|&coord| other.iter().any(|poly| poly.intersects(&coord)))
+    };
+    if let Some(coord) = vertex_within(b, a).or_else(|| vertex_within(a, b)) {
+        return Some((coord.into(), coord.into(), T::zero()));
+    }
+
+    let a_edges = edges(a);
+    let mut b_edges = edges(b);
+    b_edges.sort_by(|(_, r1), (_, r2)| r1.min().x.partial_cmp(&r2.min().x).unwrap());
+
+    let mut best: Option<(Coordinate<T>, Coordinate<T>, T)> = None;
+    for (line_a, rect_a) in &a_edges {
+        for (line_b, rect_b) in &b_edges {
+            let bound = best.map(|(_, _, dist)| dist);
+            // The edges of `b` are sorted by their left ends, so all the
+            // remaining ones are at least this far away.
+            if let Some(bound) = bound {
+                let gap = rect_b.min().x - rect_a.max().x;
+                if gap > T::zero() && gap * gap >= bound {
+                    break;
+                }
+                if rect_distance_2(rect_a, rect_b) >= bound {
+                    continue;
+                }
+            }
+            let (ca, cb) = closest_pair(*line_a, *line_b);
+            let dist = ca.squared_euclidean_distance(&cb);
+            let closer = match bound {
+                Some(bound) => dist < bound,
+                None => true,
+            };
+            if closer {
+                best = Some((ca, cb, dist));
+                if dist == T::zero() {
+                    return Some((ca.into(), cb.into(), T::zero()));
+                }
+            }
+        }
+    }
+    best.map(|(ca, cb, dist)| (ca.into(), cb.into(), dist.sqrt()))
+}
+
+/// The edges of the rings of `polys`, with their bounding rectangles.
+fn edges<T: GeoFloat>(polys: &[Polygon<T>]) -> Vec<(Line<T>, Rect<T>)> {
+    polys
+        .iter()
+        .flat_map(|poly| std::iter::once(poly.exterior()).chain(poly.interiors()))
+        .flat_map(|ring| ring.lines())
+        .map(|line| (line, line.bounding_rect()))
+        .collect()
+}
+
+/// The closest pair of points of two segments.
+fn closest_pair<T: GeoFloat>(a: Line<T>, b: Line<T>) -> (Coordinate<T>, Coordinate<T>) {
+    match line_intersection(a, b) {
+        Some(LineIntersection::SinglePoint { intersection, .. }) => (intersection, intersection),
+        Some(LineIntersection::Collinear { intersection }) => {
+            (intersection.start, intersection.start)
+        }
+        // Disjoint segments are closest at an end of one of them.
+        None => [
+            (a.start, project(b.start, b.end, a.start)),
+            (a.end, project(b.start, b.end, a.end)),
+            (project(a.start, a.end, b.start), b.start),
+            (project(a.start, a.end, b.end), b.end),
+        ]
+        .into_iter()
+        .min_by(|p, q| {
+            p.0.squared_euclidean_distance(&p.1)
+                .partial_cmp(&q.0.squared_euclidean_distance(&q.1))
+                .unwrap()
+        })
+        .unwrap(),
+    }
+}
+
+/// The squared distance between two rectangles.
+fn rect_distance_2<T: GeoFloat>(a: &Rect<T>, b: &Rect<T>) -> T {
+    let gap =
+        |min_a: T, max_a: T, min_b: T, max_b: T| (min_b - max_a).max(min_a - max_b).max(T::zero());
+    let dx = gap(a.min().x, a.max().x, b.min().x, b.max().x);
+    let dy = gap(a.min().y, a.max().y, b.min().y, b.max().y);
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, EuclideanDistance};
+
+    #[test]
+    fn squares_apart() {
+        let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let b = polygon![(x: 5., y: 0.), (x: 7., y: 0.), (x: 7., y: 2.), (x: 5., y: 2.)];
+        let (pa, pb, dist) = a.nearest_points(&b).unwrap();
+        assert_eq!(dist, 3.);
+        assert_eq!(pa.x(), 2.);
+        assert_eq!(pb.x(), 5.);
+        assert_eq!(pa.y(), pb.y());
+
+        // Swapped, and diagonally apart.
+        let c = polygon![(x: 5., y: 6.), (x: 7., y: 6.), (x: 7., y: 8.), (x: 5., y: 8.)];
+        let (pc, pa, dist) = c.nearest_points(&a).unwrap();
+        assert_eq!(dist, 5.);
+        assert_eq!(pc, Point::new(5., 6.));
+        assert_eq!(pa, Point::new(2., 2.));
+    }
+
+    #[test]
+    fn overlapping_and_nested() {
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let crossing = polygon![(x: 7., y: 4.), (x: 9., y: 4.), (x: 9., y: 6.), (x: 7., y: 6.)];
+        let (pa, pb, dist) = frame.nearest_points(&crossing).unwrap();
+        assert_eq!(dist, 0.);
+        assert_eq!(pa, pb);
+        assert!(frame.intersects(&pa) && crossing.intersects(&pb));
+
+        // In the hole, and so disjoint from the frame.
+        let inside = polygon![(x: 4., y: 3.), (x: 6., y: 3.), (x: 6., y: 5.), (x: 4., y: 5.)];
+        let (pa, pb, dist) = frame.nearest_points(&inside).unwrap();
+        assert_eq!(dist, 1.);
+        assert_eq!(pa.y(), 2.);
+        assert_eq!(pb.y(), 3.);
+
+        let both = MultiPolygon::new(vec![inside, crossing]);
+        let (_, _, dist) = MultiPolygon::from(frame.clone())
+            .nearest_points(&both)
+            .unwrap();
+        assert_eq!(dist, 0.);
+        assert!(MultiPolygon::new(vec![]).nearest_points(&both).is_none());
+    }
+
+    #[test]
+    fn matches_distance() {
+        let a = polygon![(x: 0., y: 0.), (x: 3., y: 1.), (x: 1., y: 4.)];
+        let b = polygon![(x: 4., y: 3.), (x: 9., y: 2.), (x: 6., y: 7.)];
+        let (pa, pb, dist) = a.nearest_points(&b).unwrap();
+        assert_relative_eq!(dist, a.euclidean_distance(&b));
+        assert_relative_eq!(pa.euclidean_distance(&pb), dist);
+    }
+}
@@ -0,0 +1,235 @@
+use std::cmp::Reverse;
+
+use crate::sweep::Intersections;
+use crate::{Coordinate, GeoFloat, Line, LineIntersection, LineString};
+
+/// Offset a linear geometry to one side, by a distance.
+///
+/// Each segment is moved parallel to itself by `distance`: to the left
+/// of its direction if positive, and to the right if negative. The
+/// offset segments are joined at the intersection of their supporting
+/// lines (a mitre join): they are extended at the outer side of a
+/// corner, and trimmed at its inner side. The loops that form where
+/// the offset of a segment crosses that of another one, found by a
+/// planar sweep, are then cut out.
+///
+/// Mitre joins are not limited, so that the offset of a very sharp
+/// outer corner may extend far from the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Offset};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.)];
+///
+/// assert_eq!(
+///     ls.offset(1.),
+///     line_string![(x: 0., y: 1.), (x: 7., y: 1.), (x: 7., y: 8.)],
+/// );
+/// assert_eq!(
+///     ls.offset(-1.),
+///     line_string![(x: 0., y: -1.), (x: 9., y: -1.), (x: 9., y: 8.)],
+/// );
+/// ```
+pub trait Offset<T: GeoFloat> {
+    /// The geometry offset to the left by `distance`, or to the right
+    /// if negative.
+    ///
+    /// A closed line string is offset as a ring: its last and first
+    /// segments are joined at the closing vertex, and the output is
+    /// closed at that join. Offsetting a counter-clockwise ring by a
+    /// positive distance hence shrinks it.
+    fn offset(&self, distance: T) -> Self;
+}
+
+impl<T: GeoFloat> Offset<T> for LineString<T> {
+    fn offset(&self, distance: T) -> Self {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 || distance == T::zero() {
+            return LineString::new(coords);
+        }
+
+        let segments: Vec<_> = coords
+            .windows(2)
+            .map(|w| offset_segment(w[0], w[1], distance))
+            .collect();
+        let last = segments[segments.len() - 1];
+        // A ring starts and ends at the join of its closing vertex.
+        let is_ring = self.is_closed() && coords.len() > 3;
+        let closing = if is_ring {
+            let mut closing = vec![];
+            join(&mut closing, last, segments[0]);
+            closing
+        } else {
+            vec![]
+        };
+        let mut raw = vec![*closing.last().unwrap_or(&segments[0].start)];
+        for w in segments.windows(2) {
+            join(&mut raw, w[0], w[1]);
+        }
+        if is_ring {
+            raw.extend(closing);
+        } else {
+            raw.push(last.end);
+        }
+        raw.dedup();
+
+        LineString::new(cut_loops(raw, is_ring))
+    }
+}
+
+/// The segment `start`-`end` moved to its left by `distance`.
+fn offset_segment<T: GeoFloat>(start: Coordinate<T>, end: Coordinate<T>, distance: T) -> Line<T> {
+    let delta = end - start;
+    let scale = distance / delta.x.hypot(delta.y);
+    let normal = Coordinate {
+        x: -delta.y * scale,
+        y: delta.x * scale,
+    };
+    Line::new(start + normal, end + normal)
+}
+
+/// Push the mitre join of the consecutive offset segments `a` and `b`.
+fn join<T: GeoFloat>(coords: &mut Vec<Coordinate<T>>, a: Line<T>, b: Line<T>) {
+    let (da, db) = (a.delta(), b.delta());
+    let denom = cross(da, db);
+    if denom == T::zero() {
+        // Collinear, or turning back.
+        coords.push(a.end);
+        coords.push(b.start);
+    } else {
+        let t = cross(b.start - a.start, db) / denom;
+        coords.push(a.start + da * t);
+    }
+}
+
+fn cross<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+/// Cut out the loops of the line string through `coords`: where two
+/// non-adjacent segments cross, skip the vertices in between.
+fn cut_loops<T: GeoFloat>(coords: Vec<Coordinate<T>>, is_closed: bool) -> Vec<Coordinate<T>> {
+    let lines: Vec<_> = coords.windows(2).map(|w| Line::new(w[0], w[1])).collect();
+    let last = lines.len().saturating_sub(1);
+    let mut cuts: Vec<_> = lines
+        .iter()
+        .copied()
+        .enumerate()
+        .collect::<Intersections<_>>()
+        .filter_map(|((i, _), (j, _), int)| {
+            let (i, j) = (i.min(j), i.max(j));
+            // The ends of a closed line string meet by design.
+            if j <= i + 1 || (is_closed && i == 0 && j == last) {
+                return None;
+            }
+            let pt = match int {
+                LineIntersection::SinglePoint { intersection, .. } => intersection,
+                LineIntersection::Collinear { intersection } => intersection.start,
+            };
+            Some((i, j, pt))
+        })
+        .collect();
+    // For each segment, take the cut skipping the most.
+    cuts.sort_by_key(|&(i, j, _)| (i, Reverse(j)));
+
+    let mut output = vec![coords[0]];
+    let mut cuts = cuts.into_iter().peekable();
+    let mut idx = 0;
+    while idx < lines.len() {
+        while matches!(cuts.peek(), Some(&(i, _, _)) if i < idx) {
+            cuts.next();
+        }
+        match cuts.peek() {
+            Some(&(i, j, pt)) if i == idx => {
+                output.push(pt);
+                idx = j;
+            }
+            _ => {
+                output.push(lines[idx].end);
+                idx += 1;
+            }
+        }
+    }
+    output.dedup();
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn right_angle() {
+        // Turning right.
+        let ls = line_string![(x: 0., y: 8.), (x: 0., y: 0.), (x: 8., y: 0.)];
+        assert_eq!(
+            ls.offset(2.),
+            line_string![(x: 2., y: 8.), (x: 2., y: 2.), (x: 8., y: 2.)]
+        );
+        assert_eq!(
+            ls.offset(-2.),
+            line_string![(x: -2., y: 8.), (x: -2., y: -2.), (x: 8., y: -2.)]
+        );
+    }
+
+    #[test]
+    fn collinear_and_degenerate() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 3., y: 0.)];
+        assert_eq!(
+            ls.offset(1.),
+            line_string![(x: 0., y: 1.), (x: 1., y: 1.), (x: 3., y: 1.)]
+        );
+        let point = line_string![(x: 1., y: 1.)];
+        assert_eq!(point.offset(1.), point);
+    }
+
+    #[test]
+    fn cut_self_intersection() {
+        // The short segment makes the inner offset of the last segment
+        // cross that of the first one.
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.5), (x: 0., y: 3.)];
+        let offset = ls.offset(1.);
+        assert_eq!(offset.0.len(), 3);
+        assert_eq!(offset.0[0], Coordinate { x: 0., y: 1. });
+        assert_relative_eq!(offset.0[1].y, 1., epsilon = 1e-9);
+        assert!(0. < offset.0[1].x && offset.0[1].x < 9.);
+        assert_eq!(offset.0[2], offset_segment(ls.0[2], ls.0[3], 1.).end);
+    }
+
+    #[test]
+    fn closed_square() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(
+            square.offset(1.),
+            line_string![
+                (x: 1., y: 1.),
+                (x: 3., y: 1.),
+                (x: 3., y: 3.),
+                (x: 1., y: 3.),
+                (x: 1., y: 1.),
+            ]
+        );
+        let grown = square.offset(-1.);
+        assert!(grown.is_closed());
+        assert_eq!(
+            grown,
+            line_string![
+                (x: -1., y: -1.),
+                (x: 5., y: -1.),
+                (x: 5., y: 5.),
+                (x: -1., y: 5.),
+                (x: -1., y: -1.),
+            ]
+        );
+    }
+}
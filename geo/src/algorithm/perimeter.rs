@@ -0,0 +1,162 @@
+use num_traits::FromPrimitive;
+
+use crate::{
+    CoordFloat, EuclideanLength, HaversineLength, LineString, MultiPolygon, Polygon,
+    MEAN_EARTH_RADIUS,
+};
+
+/// The length of the boundary of a polygonal geometry: of the exteriors
+/// and of all the interiors, which are part of the boundary too.
+pub trait Perimeter<T: CoordFloat> {
+    /// The planar perimeter, as the sum of the
+    /// [`EuclideanLength`](crate::EuclideanLength) of the rings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Perimeter};
+    ///
+    /// let frame = polygon![
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+    /// ];
+    ///
+    /// assert_eq!(frame.perimeter(), 16. + 4.);
+    /// ```
+    fn perimeter(&self) -> T;
+
+    /// The perimeter on the sphere, as the sum of the
+    /// [`HaversineLength`](crate::HaversineLength) of the rings, for
+    /// coordinates in degrees of longitude and latitude.
+    ///
+    /// *Note*: this uses a mean earth radius of 6371.088 km, as
+    /// [`HaversineLength`](crate::HaversineLength). Use
+    /// [`haversine_perimeter_with_radius`](Self::haversine_perimeter_with_radius)
+    /// to measure on a sphere of a different radius.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Perimeter};
+    ///
+    /// // A square of one degree on the equator.
+    /// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    ///
+    /// let perimeter: f64 = square.haversine_perimeter();
+    ///
+    /// assert_eq!(444_763., perimeter.round());
+    /// ```
+    fn haversine_perimeter(&self) -> T;
+
+    /// The perimeter on a sphere of the given `radius`, as
+    /// [`haversine_perimeter`](Self::haversine_perimeter).
+    ///
+    /// # Units
+    ///
+    /// - `radius`: any length unit
+    /// - return value: the unit of `radius`
+    fn haversine_perimeter_with_radius(&self, radius: T) -> T;
+}
+
+/// The rings of a polygon, exterior first.
+fn rings<T: CoordFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    std::iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+/// The planar perimeter of a polygon, shared with the metrics needing
+/// only a `CoordFloat`.
+pub(crate) fn planar_perimeter<T: CoordFloat>(polygon: &Polygon<T>) -> T {
+    rings(polygon)
+        .flat_map(|ring| ring.lines())
+        .fold(T::zero(), |total, line| total + line.euclidean_length())
+}
+
+impl<T> Perimeter<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn perimeter(&self) -> T {
+        planar_perimeter(self)
+    }
+
+    fn haversine_perimeter(&self) -> T {
+        self.haversine_perimeter_with_radius(T::from(MEAN_EARTH_RADIUS).unwrap())
+    }
+
+    fn haversine_perimeter_with_radius(&self, radius: T) -> T {
+        rings(self).fold(T::zero(), |total, ring| {
+            total + ring.haversine_length_with_radius(radius)
+        })
+    }
+}
+
+impl<T> Perimeter<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn perimeter(&self) -> T {
+        self.iter()
+            .fold(T::zero(), |total, poly| total + poly.perimeter())
+    }
+
+    fn haversine_perimeter(&self) -> T {
+        self.haversine_perimeter_with_radius(T::from(MEAN_EARTH_RADIUS).unwrap())
+    }
+
+    fn haversine_perimeter_with_radius(&self, radius: T) -> T {
+        self.iter().fold(T::zero(), |total, poly| {
+            total + poly.haversine_perimeter_with_radius(radius)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn holes_count() {
+        let unit = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(unit.perimeter(), 4.);
+
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 5., y: 2.), (x: 5., y: 5.), (x: 2., y: 5.)]],
+        ];
+        assert_eq!(frame.perimeter(), 40. + 12.);
+        let both = MultiPolygon::new(vec![unit.clone(), frame.clone()]);
+        assert_eq!(both.perimeter(), 4. + 52.);
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).perimeter(), 0.);
+
+        let haversine =
+            |poly: &Polygon<f64>| rings(poly).map(|ring| ring.haversine_length()).sum::<f64>();
+        assert_relative_eq!(
+            both.haversine_perimeter(),
+            haversine(&unit) + haversine(&frame),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn radius() {
+        // The triangle between the equator and the north pole, with a
+        // right angle at each corner.
+        let octant = polygon![(x: 0., y: 0.), (x: 90., y: 0.), (x: 0., y: 90.)];
+        let quarter = std::f64::consts::FRAC_PI_2;
+        assert_relative_eq!(
+            octant.haversine_perimeter_with_radius(1.),
+            3. * quarter,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            octant.haversine_perimeter(),
+            3. * quarter * 6_371_008.8,
+            epsilon = 1e-6
+        );
+    }
+}
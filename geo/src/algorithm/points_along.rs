@@ -0,0 +1,138 @@
+use crate::{CoordFloat, LineString, Point};
+
+/// Sample points at a regular spacing along a linear geometry, with the
+/// direction of the geometry at each of them.
+///
+/// The points are at the distances `0`, `spacing`, `2 * spacing`, …
+/// from the start, measured along the geometry in its planar
+/// coordinates. The end is only included if `include_end` is set, or if
+/// it falls at a multiple of `spacing`.
+///
+/// Each point comes with the bearing of the segment it lies on, in
+/// degrees, where North (the `y` axis) is 0° and East (the `x` axis) is
+/// 90°, as for [`Bearing`](crate::Bearing). A point at a vertex takes
+/// the bearing of the segment starting there, and the end that of the
+/// last segment. Zero-length segments are skipped.
+///
+/// A geometry of zero length yields no points.
+///
+/// # Panics
+///
+/// If `spacing` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, PointsAlong};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 2.)];
+///
+/// assert_eq!(
+///     ls.points_along(2., false),
+///     vec![
+///         (point!(x: 0., y: 0.), 90.),
+///         (point!(x: 2., y: 0.), 90.),
+///         (point!(x: 3., y: 1.), 0.),
+///     ]
+/// );
+/// assert_eq!(ls.points_along(2., true).last(), Some(&(point!(x: 3., y: 2.), 0.)));
+/// ```
+pub trait PointsAlong<T: CoordFloat> {
+    fn points_along(&self, spacing: T, include_end: bool) -> Vec<(Point<T>, T)>;
+}
+
+impl<T: CoordFloat> PointsAlong<T> for LineString<T> {
+    fn points_along(&self, spacing: T, include_end: bool) -> Vec<(Point<T>, T)> {
+        assert!(spacing > T::zero(), "spacing must be positive");
+
+        let segments: Vec<_> = self
+            .lines()
+            .map(|line| {
+                let delta = line.delta();
+                (line, delta.x.hypot(delta.y))
+            })
+            .filter(|(_, length)| *length > T::zero())
+            .collect();
+
+        let mut points = vec![];
+        let mut start = T::zero();
+        let mut count = 0;
+        for (idx, (line, length)) in segments.iter().enumerate() {
+            let delta = line.delta();
+            let bearing = delta.x.atan2(delta.y).to_degrees();
+            let end = start + *length;
+            let is_last = idx + 1 == segments.len();
+            loop {
+                // Computed from the count, so that errors do not add up.
+                let distance = spacing * T::from(count).unwrap();
+                if distance > end || (distance == end && !is_last) {
+                    break;
+                }
+                let fraction = (distance - start) / *length;
+                points.push(((line.start + delta * fraction).into(), bearing));
+                count += 1;
+            }
+            start = end;
+        }
+
+        if include_end {
+            if let Some((line, _)) = segments.last() {
+                let end = line.end_point();
+                if points.last().map(|(p, _)| *p) != Some(end) {
+                    let delta = line.delta();
+                    points.push((end, delta.x.atan2(delta.y).to_degrees()));
+                }
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn remainder_and_end() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: -1., y: 4.)];
+        let bearings: Vec<_> = ls
+            .points_along(2., false)
+            .into_iter()
+            .map(|(_, b)| b)
+            .collect();
+        assert_eq!(bearings, vec![0., 0., -90.]);
+
+        // The end falls at a multiple of the spacing.
+        let points = ls.points_along(2.5, false);
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[2], (point!(x: -1., y: 4.), -90.));
+        assert_eq!(ls.points_along(2.5, true), points);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(empty.points_along(1., true).is_empty());
+
+        let collapsed = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert!(collapsed.points_along(1., true).is_empty());
+
+        // Zero-length segments are skipped.
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: -1.)];
+        assert_eq!(
+            ls.points_along(1., false),
+            vec![
+                (point!(x: 0., y: 0.), 90.),
+                (point!(x: 1., y: 0.), 180.),
+                (point!(x: 1., y: -1.), 180.),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_spacing() {
+        line_string![(x: 0., y: 0.), (x: 1., y: 0.)].points_along(0., false);
+    }
+}
@@ -0,0 +1,253 @@
+use std::{cmp::Ordering, iter::FromIterator};
+
+use crate::kernels::*;
+use crate::sweep::{CrossingsIter, LineOrPoint};
+use crate::{Contains, Coordinate, GeoFloat, Line, LinesIter, MultiPolygon, Point, Polygon};
+
+/// A [`MultiPolygon`] pre-processed for fast, repeated
+/// point-in-polygon queries.
+///
+/// The edges of the input are noded using the planar sweep, and the
+/// vertical strip between every two consecutive event `x`-coordinates
+/// (a _slab_) stores the edges crossing it, ordered from bottom to
+/// top. This freezes the active-segments of the sweep at each slab.
+/// A query is then a binary search for the slab, followed by a binary
+/// search for the number of edges below the point. The parity of this
+/// count decides whether the point is inside.
+///
+/// Building the index is a single sweep, and each query runs in `O(log
+/// n)` time. The index may use `O(n^2)` space in the worst case, and
+/// is hence best suited for answering many queries against a fixed
+/// geometry.
+///
+/// # Semantics
+///
+/// The queries agree with [`Contains`] on [`MultiPolygon`]: points on
+/// the boundary are not contained. The interior is determined by the
+/// even-odd rule, and thus the result for invalid inputs (eg.
+/// overlapping polygons) may differ from other algorithms.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, point, Contains, PreparedPolygon};
+///
+/// let poly = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+/// ];
+/// let prepared = PreparedPolygon::from(&poly);
+///
+/// assert!(prepared.contains(&point!(x: 0.5, y: 2.)));
+/// assert!(!prepared.contains(&point!(x: 2., y: 2.)));
+/// assert!(!prepared.contains(&point!(x: 4., y: 2.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedPolygon<T: GeoFloat> {
+    /// Sorted, distinct `x`-coordinates of the noded vertices.
+    xs: Vec<T>,
+    /// `slabs[i]` holds the edges spanning `xs[i]..=xs[i + 1]`, ordered
+    /// bottom to top. Each edge is stored left to right.
+    slabs: Vec<Vec<Line<T>>>,
+    /// `verticals[i]` holds the `(min_y, max_y)` of the vertical edges
+    /// at `xs[i]`.
+    verticals: Vec<Vec<(T, T)>>,
+}
+
+impl<T: GeoFloat> PreparedPolygon<T> {
+    /// Build the index from a [`MultiPolygon`].
+    pub fn new(mp: &MultiPolygon<T>) -> Self {
+        Self::from_edges(mp.lines_iter())
+    }
+
+    fn from_edges<I: IntoIterator<Item = Line<T>>>(edges: I) -> Self {
+        let edges: Vec<LineOrPoint<T>> = edges
+            .into_iter()
+            .map(LineOrPoint::from)
+            .filter(|lp| lp.is_line())
+            .collect();
+
+        // Node the edges: every piece ending at a sweep point is free of
+        // crossings in its interior.
+        let mut pieces = vec![];
+        let mut iter = CrossingsIter::from_iter(edges);
+        while iter.next().is_some() {
+            pieces.extend(
+                iter.intersections()
+                    .iter()
+                    .filter(|c| !c.at_left && c.line.is_line())
+                    .map(|c| c.line),
+            );
+        }
+
+        let mut xs: Vec<T> = pieces
+            .iter()
+            .flat_map(|p| [p.left().x, p.right().x])
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs.dedup();
+
+        let mut verticals = vec![vec![]; xs.len()];
+        pieces.sort_by_key(|p| p.left());
+        let mut pieces = pieces.into_iter().peekable();
+
+        let mut slabs = Vec::with_capacity(xs.len().saturating_sub(1));
+        let mut active: Vec<Line<T>> = vec![];
+        for idx in 0..xs.len().saturating_sub(1) {
+            let (x_left, x_right) = (xs[idx], xs[idx + 1]);
+            active.retain(|l| l.end.x > x_left);
+            while let Some(piece) = pieces.next_if(|p| p.left().x == x_left) {
+                if piece.right().x == x_left {
+                    verticals[idx].push((piece.left().y, piece.right().y));
+                } else {
+                    active.push(piece.line());
+                }
+            }
+
+            // Noded pieces do not cross inside the slab, so ordering by
+            // the `y`-coordinate at its middle orders them bottom to top.
+            let x_mid = (x_left + x_right) / (T::one() + T::one());
+            let y_at = |l: &Line<T>| l.start.y + (x_mid - l.start.x) * l.slope();
+            active.sort_by(|a, b| y_at(a).partial_cmp(&y_at(b)).unwrap_or(Ordering::Equal));
+            slabs.push(active.clone());
+        }
+        if let Some(last) = verticals.last_mut() {
+            last.extend(pieces.map(|p| (p.left().y, p.right().y)));
+        }
+
+        PreparedPolygon {
+            xs,
+            slabs,
+            verticals,
+        }
+    }
+
+    fn contains_coord(&self, coord: Coordinate<T>) -> bool {
+        let (first, last) = match (self.xs.first(), self.xs.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return false,
+        };
+        if !(first <= coord.x && coord.x <= last) {
+            return false;
+        }
+
+        let idx = self.xs.partition_point(|x| *x < coord.x);
+        if self.xs[idx] != coord.x {
+            return self.slab_parity(idx - 1, coord).unwrap_or(false);
+        }
+
+        // On a slab boundary: the point may be on a vertical edge, or
+        // on a vertex of an edge in either adjacent slab. Away from the
+        // boundary, both slabs give the same answer.
+        let on_vertical = self.verticals[idx]
+            .iter()
+            .any(|(lo, hi)| *lo <= coord.y && coord.y <= *hi);
+        if on_vertical {
+            return false;
+        }
+        let left = if idx > 0 {
+            self.slab_parity(idx - 1, coord)
+        } else {
+            Some(false)
+        };
+        let right = if idx < self.slabs.len() {
+            self.slab_parity(idx, coord)
+        } else {
+            Some(false)
+        };
+        matches!((left, right), (Some(l), Some(r)) if l || r)
+    }
+
+    /// Parity of the number of edges below `coord` in the given slab, or
+    /// `None` if `coord` is on one of its edges.
+    fn slab_parity(&self, slab: usize, coord: Coordinate<T>) -> Option<bool> {
+        let edges = &self.slabs[slab];
+        let below = edges.partition_point(|l| {
+            T::Ker::orient2d(l.start, l.end, coord) == Orientation::CounterClockwise
+        });
+        if below < edges.len()
+            && T::Ker::orient2d(edges[below].start, edges[below].end, coord)
+                == Orientation::Collinear
+        {
+            return None;
+        }
+        Some(below % 2 == 1)
+    }
+}
+
+impl<T: GeoFloat> From<&MultiPolygon<T>> for PreparedPolygon<T> {
+    fn from(mp: &MultiPolygon<T>) -> Self {
+        Self::new(mp)
+    }
+}
+
+impl<T: GeoFloat> From<&Polygon<T>> for PreparedPolygon<T> {
+    fn from(poly: &Polygon<T>) -> Self {
+        Self::from_edges(poly.lines_iter())
+    }
+}
+
+impl<T: GeoFloat> Contains<Coordinate<T>> for PreparedPolygon<T> {
+    fn contains(&self, coord: &Coordinate<T>) -> bool {
+        self.contains_coord(*coord)
+    }
+}
+
+impl<T: GeoFloat> Contains<Point<T>> for PreparedPolygon<T> {
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.contains_coord(p.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, polygon};
+    use wkt::TryFromWkt;
+
+    fn check_against_polygon(mp: &MultiPolygon<f64>) {
+        let prepared = PreparedPolygon::new(mp);
+        for i in -2..=42 {
+            for j in -2..=42 {
+                let pt = coord! { x: i as f64 * 0.5, y: j as f64 * 0.5 };
+                assert_eq!(
+                    prepared.contains(&pt),
+                    mp.contains(&pt),
+                    "mismatch at {pt:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_square_with_hole() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 20.), (x: 0., y: 20.)],
+            interiors: [[(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)]],
+        ];
+        check_against_polygon(&poly.into());
+    }
+
+    #[test]
+    fn test_multi_polygon() {
+        let mp = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 3,20 0,17 10,20 20,10 17,0 20,3 10,0 0),(5 5,15 5,10 12,5 5)),((8 7,12 7,10 9,8 7)))",
+        )
+        .unwrap();
+        check_against_polygon(&mp);
+    }
+
+    #[test]
+    fn test_boundary_and_empty() {
+        let poly = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 2.)];
+        let prepared = PreparedPolygon::from(&poly);
+        assert!(prepared.contains(&coord! { x: 1., y: 1. }));
+        assert!(!prepared.contains(&coord! { x: 1., y: 0. }));
+        assert!(!prepared.contains(&coord! { x: 1., y: 2. }));
+        assert!(!prepared.contains(&coord! { x: 0.5, y: 1. }));
+        assert!(!prepared.contains(&coord! { x: 3., y: 1. }));
+
+        let empty = PreparedPolygon::new(&MultiPolygon::<f64>::new(vec![]));
+        assert!(!empty.contains(&coord! { x: 0., y: 0. }));
+    }
+}
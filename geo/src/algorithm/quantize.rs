@@ -0,0 +1,161 @@
+use crate::{coord, CoordFloat, Coordinate, Rect};
+
+/// Map coordinates within a bounding box to a fixed-point integer grid,
+/// and back.
+///
+/// The grid has `2^bits` points along each axis, spanning `bounds` from
+/// its minimum to its maximum. Quantizing rounds a coordinate to the
+/// nearest grid point, so that inputs computed on different platforms,
+/// which may differ in their last bits, meet on the same coordinates.
+/// The integer coordinates are exact, and can be compared, hashed or
+/// stored as they are.
+///
+/// Quantizing and dequantizing a coordinate within `bounds` moves it by
+/// at most half the [`resolution`](Quantizer::resolution) along each
+/// axis. Coordinates outside `bounds` are clamped to it, and `NaN`s are
+/// mapped to its minimum.
+///
+/// # Examples
+///
+/// Snap the inputs of an operation to the grid, and quantize its output.
+///
+/// ```
+/// use geo::{coord, polygon, BooleanOps, MapCoords, Quantizer, Rect};
+///
+/// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. });
+/// let quantizer = Quantizer::new(bounds, 20);
+/// let snap = |c| quantizer.dequantize(quantizer.quantize(c));
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)];
+/// let b = polygon![(x: 4., y: 4.), (x: 10., y: 4.), (x: 10., y: 10.), (x: 4., y: 10.)];
+/// let union = a.map_coords(snap).union(&b.map_coords(snap));
+///
+/// let quantized = union.map_coords(|c| quantizer.quantize(c));
+/// assert_eq!(quantized.0[0].exterior().0.len(), 9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantizer<T: CoordFloat> {
+    min: Coordinate<T>,
+    /// The number of grid cells along each axis, `2^bits - 1`.
+    cells: T,
+    /// The size of a grid cell along each axis.
+    resolution: Coordinate<T>,
+    max_index: i64,
+}
+
+impl<T: CoordFloat> Quantizer<T> {
+    /// A grid of `2^bits` points along each axis spanning `bounds`.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is not between 1 and 62.
+    pub fn new(bounds: Rect<T>, bits: u32) -> Self {
+        assert!(
+            (1..=62).contains(&bits),
+            "quantization bits must be between 1 and 62"
+        );
+        let max_index = (1_i64 << bits) - 1;
+        let cells = T::from(max_index).unwrap();
+        Quantizer {
+            min: bounds.min(),
+            cells,
+            resolution: coord! {
+                x: bounds.width() / cells,
+                y: bounds.height() / cells,
+            },
+            max_index,
+        }
+    }
+
+    /// The size of a grid cell along each axis.
+    pub fn resolution(&self) -> Coordinate<T> {
+        self.resolution
+    }
+
+    /// The largest integer coordinate, `2^bits - 1`.
+    pub fn max_index(&self) -> i64 {
+        self.max_index
+    }
+
+    /// The integer coordinates of the grid point nearest to `coord`.
+    pub fn quantize(&self, coord: Coordinate<T>) -> Coordinate<i64> {
+        coord! {
+            x: self.quantize_axis(coord.x - self.min.x, self.resolution.x),
+            y: self.quantize_axis(coord.y - self.min.y, self.resolution.y),
+        }
+    }
+
+    /// The coordinate of the grid point at the integer coordinates
+    /// `coord`.
+    pub fn dequantize(&self, coord: Coordinate<i64>) -> Coordinate<T> {
+        coord! {
+            x: self.min.x + T::from(coord.x).unwrap() * self.resolution.x,
+            y: self.min.y + T::from(coord.y).unwrap() * self.resolution.y,
+        }
+    }
+
+    fn quantize_axis(&self, offset: T, resolution: T) -> i64 {
+        if resolution <= T::zero() {
+            return 0;
+        }
+        // `max` and `min` map `NaN` to zero.
+        let index = (offset / resolution).round().max(T::zero()).min(self.cells);
+        index.to_i64().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, BooleanOps, MapCoords, MultiPolygon};
+
+    #[test]
+    fn round_trip() {
+        let bounds = Rect::new(coord! { x: -180., y: -90. }, coord! { x: 180., y: 90. });
+        let quantizer = Quantizer::new(bounds, 24);
+        let res = quantizer.resolution();
+        for i in 0..1000 {
+            let t = f64::from(i) / 1000.;
+            let c = coord! { x: -180. + 360. * t * t, y: 90. - 180. * t };
+            let q = quantizer.quantize(c);
+            let back = quantizer.dequantize(q);
+            assert!((back.x - c.x).abs() <= res.x / 2. + 1e-12);
+            assert!((back.y - c.y).abs() <= res.y / 2. + 1e-12);
+            assert_eq!(quantizer.quantize(back), q);
+        }
+
+        assert_eq!(quantizer.quantize(bounds.min()), coord! { x: 0, y: 0 });
+        let top = quantizer.max_index();
+        assert_eq!(quantizer.quantize(bounds.max()), coord! { x: top, y: top });
+        assert_eq!(
+            quantizer.quantize(coord! { x: 1000., y: f64::NAN }),
+            coord! { x: top, y: 0 }
+        );
+    }
+
+    #[test]
+    fn reproducible_overlay() {
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        let quantizer = Quantizer::new(bounds, 16);
+        let snap = |c| quantizer.dequantize(quantizer.quantize(c));
+        let overlay = |a: &MultiPolygon<f64>, b: &MultiPolygon<f64>| {
+            a.map_coords(snap)
+                .union(&b.map_coords(snap))
+                .map_coords(|c| quantizer.quantize(c))
+        };
+
+        let a: MultiPolygon<f64> =
+            polygon![(x: 0.13, y: 0.24), (x: 0.71, y: 0.22), (x: 0.62, y: 0.83), (x: 0.22, y: 0.74)]
+                .into();
+        let b: MultiPolygon<f64> =
+            polygon![(x: 0.32, y: 0.32), (x: 0.91, y: 0.41), (x: 0.82, y: 0.92)].into();
+        // The same inputs, as computed with different rounding errors.
+        let nudge = |c: Coordinate<f64>| coord! { x: c.x * (1. + 1e-12), y: c.y - 1e-13 };
+        let (a2, b2) = (a.map_coords(nudge), b.map_coords(nudge));
+        assert_ne!(a, a2);
+
+        let expected = overlay(&a, &b);
+        assert!(!expected.0.is_empty());
+        assert_eq!(overlay(&a2, &b2), expected);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{Coordinate, GeoNum, LineString, Polygon};
+
+/// The reflex vertices of a ring or a polygon: the vertices where the
+/// interior angle exceeds 180°.
+///
+/// A vertex is classified by the robust orientation predicate on the
+/// previous and the next distinct vertices. For a closed `LineString`,
+/// the interior is the region it winds around: a vertex is reflex if
+/// the ring turns there against its winding order. For a `Polygon`,
+/// the vertices of the exterior are classified as for a `LineString`,
+/// and those of the interiors by the opposite rule, as the interior of
+/// the polygon lies outside of its holes: a corner of a hole is reflex
+/// where the hole is convex.
+///
+/// The vertices are returned with their indices: into the coordinates
+/// of a `LineString`, and into the coordinates of a `Polygon` in the
+/// order of [`CoordsIter::coords_iter`](crate::CoordsIter::coords_iter),
+/// the exterior first. Collinear vertices are not reflex, and the
+/// closing coordinate of a ring is not reported. An unclosed
+/// `LineString`, or a ring without area, has no reflex vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, ReflexVertices};
+///
+/// // An L-shape, with its inner corner at (1, 1).
+/// let l_shape = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// assert_eq!(l_shape.reflex_vertices(), vec![(3, coord! { x: 1., y: 1. })]);
+/// ```
+pub trait ReflexVertices<T: GeoNum> {
+    fn reflex_vertices(&self) -> Vec<(usize, Coordinate<T>)>;
+}
+
+impl<T: GeoNum> ReflexVertices<T> for LineString<T> {
+    fn reflex_vertices(&self) -> Vec<(usize, Coordinate<T>)> {
+        match self.winding_order() {
+            Some(winding) => turning_against(self, winding),
+            None => vec![],
+        }
+    }
+}
+
+impl<T: GeoNum> ReflexVertices<T> for Polygon<T> {
+    fn reflex_vertices(&self) -> Vec<(usize, Coordinate<T>)> {
+        let mut reflex = self.exterior().reflex_vertices();
+        let mut offset = self.exterior().0.len();
+        for hole in self.interiors() {
+            if let Some(winding) = hole.winding_order() {
+                // The polygon is on the outside of the hole.
+                let reversed = match winding {
+                    WindingOrder::Clockwise => WindingOrder::CounterClockwise,
+                    WindingOrder::CounterClockwise => WindingOrder::Clockwise,
+                };
+                reflex.extend(
+                    turning_against(hole, reversed)
+                        .into_iter()
+                        .map(|(idx, coord)| (idx + offset, coord)),
+                );
+            }
+            offset += hole.0.len();
+        }
+        reflex
+    }
+}
+
+/// The vertices of a closed `ring` where it turns against `winding`.
+fn turning_against<T: GeoNum>(
+    ring: &LineString<T>,
+    winding: WindingOrder,
+) -> Vec<(usize, Coordinate<T>)> {
+    let against = match winding {
+        WindingOrder::Clockwise => Orientation::CounterClockwise,
+        WindingOrder::CounterClockwise => Orientation::Clockwise,
+    };
+    // The distinct vertices, without the closing coordinate.
+    let coords = &ring.0[..ring.0.len() - 1];
+    let n = coords.len();
+    let mut reflex = vec![];
+    for (idx, &coord) in coords.iter().enumerate() {
+        let prev = (1..n)
+            .map(|k| coords[(idx + n - k) % n])
+            .find(|&c| c != coord);
+        let next = (1..n).map(|k| coords[(idx + k) % n]).find(|&c| c != coord);
+        let (prev, next) = match (prev, next) {
+            (Some(prev), Some(next)) => (prev, next),
+            _ => continue,
+        };
+        // Only report the last of repeated coordinates.
+        if coords[(idx + 1) % n] == coord {
+            continue;
+        }
+        if T::Ker::orient2d(prev, coord, next) == against {
+            reflex.push((idx, coord));
+        }
+    }
+    reflex
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon};
+
+    #[test]
+    fn l_shape() {
+        let ccw = line_string![
+            (x: 0, y: 0),
+            (x: 2, y: 0),
+            (x: 2, y: 1),
+            (x: 1, y: 1),
+            (x: 1, y: 2),
+            (x: 0, y: 2),
+            (x: 0, y: 0),
+        ];
+        assert_eq!(ccw.reflex_vertices(), vec![(3, coord! { x: 1, y: 1 })]);
+
+        // The same shape, clockwise and with a repeated corner.
+        let cw = line_string![
+            (x: 0, y: 0),
+            (x: 0, y: 2),
+            (x: 1, y: 2),
+            (x: 1, y: 1),
+            (x: 1, y: 1),
+            (x: 2, y: 1),
+            (x: 2, y: 0),
+            (x: 0, y: 0),
+        ];
+        assert_eq!(cw.reflex_vertices(), vec![(4, coord! { x: 1, y: 1 })]);
+
+        // Convex, collinear, and unclosed rings.
+        let triangle =
+            line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 0)];
+        assert!(triangle.reflex_vertices().is_empty());
+        assert!(
+            line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 2, y: 0), (x: 0, y: 0)]
+                .reflex_vertices()
+                .is_empty()
+        );
+        assert!(line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1)]
+            .reflex_vertices()
+            .is_empty());
+    }
+
+    #[test]
+    fn holes() {
+        let poly = polygon![
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)],
+            interiors: [
+                // A triangle, oriented counter-clockwise.
+                [(x: 2, y: 2), (x: 4, y: 2), (x: 3, y: 4)],
+                // An arrow head, with a corner pointing into the hole.
+                [(x: 6, y: 6), (x: 7, y: 7), (x: 8, y: 6), (x: 7, y: 9)],
+            ],
+        ];
+        let reflex = poly.reflex_vertices();
+        let exterior = 5;
+        let triangle = exterior + 4;
+        assert_eq!(
+            reflex,
+            vec![
+                (exterior, coord! { x: 2, y: 2 }),
+                (exterior + 1, coord! { x: 4, y: 2 }),
+                (exterior + 2, coord! { x: 3, y: 4 }),
+                (triangle, coord! { x: 6, y: 6 }),
+                (triangle + 2, coord! { x: 8, y: 6 }),
+                (triangle + 3, coord! { x: 7, y: 9 }),
+            ]
+        );
+    }
+}
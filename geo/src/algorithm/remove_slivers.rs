@@ -0,0 +1,94 @@
+use crate::algorithm::perimeter::planar_perimeter;
+use crate::{Area, GeoFloat, MultiPolygon, Polygon};
+
+/// Remove slivers: the thin parts of a `MultiPolygon`.
+///
+/// Overlays often leave long, thin parts along the shared boundaries of
+/// their inputs. Such a sliver may enclose as much area as a legitimate
+/// small part, so it is recognized by its shape rather than its area:
+/// the parts whose [`thinness`] is below `thinness_ratio` are dropped,
+/// whatever their size. The holes of the kept parts are kept.
+///
+/// The thinness is `1` for a disc, about `0.785` for a square, and tends
+/// to `0` for ever longer and thinner parts.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon, RemoveSlivers};
+///
+/// // A square, and a sliver of the same area along its bottom.
+/// let square = polygon![(x: 0., y: 1.), (x: 2., y: 1.), (x: 2., y: 3.), (x: 0., y: 3.)];
+/// let sliver = polygon![(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 0.04), (x: 0., y: 0.04)];
+/// let parts = MultiPolygon::new(vec![square.clone(), sliver]);
+///
+/// assert_eq!(parts.remove_slivers(0.1), MultiPolygon::new(vec![square]));
+/// ```
+pub trait RemoveSlivers<T: GeoFloat> {
+    fn remove_slivers(&self, thinness_ratio: T) -> Self;
+}
+
+/// The Polsby–Popper compactness of a polygon, `4π · area / perimeter²`,
+/// where the [`Perimeter`](crate::Perimeter) includes the holes. An empty polygon has a
+/// thinness of `0`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::remove_slivers::thinness;
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert_eq!(thinness(&square), std::f64::consts::FRAC_PI_4);
+/// ```
+pub fn thinness<T: GeoFloat>(polygon: &Polygon<T>) -> T {
+    let perimeter = planar_perimeter(polygon);
+    if perimeter == T::zero() {
+        return T::zero();
+    }
+    let four_pi = T::from(4. * std::f64::consts::PI).unwrap();
+    four_pi * polygon.unsigned_area() / (perimeter * perimeter)
+}
+
+impl<T: GeoFloat> RemoveSlivers<T> for MultiPolygon<T> {
+    fn remove_slivers(&self, thinness_ratio: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .filter(|poly| thinness(poly) >= thinness_ratio)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, LineString};
+
+    #[test]
+    fn test_shape_not_area() {
+        // A long sliver, and a compact part of the same area with a hole.
+        let sliver = polygon![(x: 0., y: 0.), (x: 50., y: 0.), (x: 50., y: 0.1), (x: 0., y: 0.1)];
+        let compact = polygon![
+            exterior: [(x: 0., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 0., y: 3.)],
+            interiors: [[(x: 1., y: 1.5), (x: 2., y: 1.5), (x: 2., y: 2.5), (x: 1., y: 2.5)]],
+        ];
+        assert_eq!(sliver.unsigned_area(), compact.unsigned_area());
+        assert!(thinness(&sliver) < 0.01);
+        assert!(thinness(&compact) > 0.3);
+
+        let parts = MultiPolygon::new(vec![sliver, compact.clone()]);
+        assert_eq!(parts.remove_slivers(0.1), MultiPolygon::new(vec![compact]));
+        assert!(parts.remove_slivers(0.).0.len() == 2);
+        assert!(parts.remove_slivers(1.).0.is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(thinness(&empty), 0.);
+        let parts = MultiPolygon::new(vec![empty]);
+        assert!(parts.remove_slivers(0.1).0.is_empty());
+    }
+}
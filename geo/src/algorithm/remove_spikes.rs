@@ -0,0 +1,216 @@
+use crate::kernels::*;
+use crate::{Coordinate, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// Remove spikes: vertices where the boundary turns back on itself.
+///
+/// A spike is a vertex `B` in a sequence `A → B → C` where the
+/// direction reverses, so the boundary pokes out and returns along
+/// (almost) the same line. Such protrusions enclose (almost) no area,
+/// and are typically artifacts of other operations.
+///
+/// A vertex is a spike if the angle `∠ABC` is at most `angle_tolerance`
+/// (in degrees). An exact reversal, detected with the orientation
+/// predicate, is always a spike; a tolerance of `0` removes only those.
+/// Removing a spike may expose another one (eg. `A → B → C → B → A`),
+/// so removal is repeated until none remain. Consecutive repeated
+/// coordinates are also removed.
+///
+/// Closed `LineString`s and polygon rings are treated as cyclic, while
+/// the end points of an open `LineString` are always kept. A ring that
+/// collapses entirely is replaced by an empty `LineString`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, RemoveSpikes};
+///
+/// // A square with a zero-width spike from (2, 0) to (3, 0).
+/// let spiky = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 3., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// assert_eq!(spiky.remove_spikes(0.), square);
+/// ```
+pub trait RemoveSpikes<T: GeoFloat> {
+    fn remove_spikes(&self, angle_tolerance: T) -> Self;
+}
+
+/// Whether `b` is a spike between `a` and `c`.
+fn is_spike<T: GeoFloat>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+    angle_tolerance: T,
+) -> bool {
+    let (u, v) = (a - b, c - b);
+    let dot = u.x * v.x + u.y * v.y;
+    if T::Ker::orient2d(a, b, c) == Orientation::Collinear {
+        return dot > T::zero();
+    }
+    let cross = u.x * v.y - u.y * v.x;
+    cross.abs().atan2(dot) <= angle_tolerance.to_radians()
+}
+
+/// Remove spikes from a sequence of coordinates. If `cyclic`, the
+/// sequence must not repeat the first coordinate at the end.
+fn remove_spikes_from<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    cyclic: bool,
+    angle_tolerance: T,
+) -> Vec<Coordinate<T>> {
+    let mut output: Vec<Coordinate<T>> = Vec::with_capacity(coords.len());
+    for &c in coords {
+        loop {
+            if output.last() == Some(&c) {
+                break;
+            }
+            let n = output.len();
+            if n >= 2 && is_spike(output[n - 2], output[n - 1], c, angle_tolerance) {
+                output.pop();
+                continue;
+            }
+            output.push(c);
+            break;
+        }
+    }
+
+    if cyclic {
+        // Fix up spikes around the seam of the ring.
+        loop {
+            let n = output.len();
+            let seam_spike =
+                n >= 3 && is_spike(output[n - 2], output[n - 1], output[0], angle_tolerance);
+            if (n >= 2 && output[0] == output[n - 1]) || seam_spike {
+                output.pop();
+            } else if n >= 3 && is_spike(output[n - 1], output[0], output[1], angle_tolerance) {
+                output.remove(0);
+            } else {
+                break;
+            }
+        }
+    }
+    output
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for LineString<T> {
+    fn remove_spikes(&self, angle_tolerance: T) -> Self {
+        if !self.is_closed() || self.0.is_empty() {
+            return LineString::new(remove_spikes_from(&self.0, false, angle_tolerance));
+        }
+        let mut coords = remove_spikes_from(&self.0[..self.0.len() - 1], true, angle_tolerance);
+        if coords.len() < 3 {
+            return LineString::new(vec![]);
+        }
+        coords.push(coords[0]);
+        LineString::new(coords)
+    }
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for Polygon<T> {
+    fn remove_spikes(&self, angle_tolerance: T) -> Self {
+        Polygon::new(
+            self.exterior().remove_spikes(angle_tolerance),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.remove_spikes(angle_tolerance))
+                .filter(|ring| !ring.0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for MultiPolygon<T> {
+    fn remove_spikes(&self, angle_tolerance: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|poly| poly.remove_spikes(angle_tolerance))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    fn square() -> Polygon<f64> {
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]
+    }
+
+    #[test]
+    fn test_spike_on_edge() {
+        let spiky = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 3., y: 1.),
+            (x: 4., y: 1.),
+            (x: 3., y: 1.),
+            (x: 2., y: 1.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let cleaned = spiky.remove_spikes(0.);
+        // The root of the spike remains as a collinear vertex.
+        let expected = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_eq!(cleaned, expected);
+    }
+
+    #[test]
+    fn test_spike_at_seam() {
+        let spiky = polygon![
+            (x: 0., y: 0.),
+            (x: -1., y: -1.),
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let cleaned = spiky.remove_spikes(0.);
+        assert_eq!(cleaned.exterior().0.len(), 5);
+        assert_eq!(crate::Area::unsigned_area(&cleaned), 4.);
+        assert_eq!(square().remove_spikes(0.), square());
+    }
+
+    #[test]
+    fn test_angle_tolerance() {
+        // Almost a spike: the angle at (3, 1) is about 1.15 degrees.
+        let spiky = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 3., y: 1.01),
+            (x: 2., y: 1.03),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_eq!(spiky.remove_spikes(0.), spiky);
+        assert_eq!(spiky.remove_spikes(2.).exterior().0.len(), 7);
+    }
+
+    #[test]
+    fn test_open_line_string() {
+        // A closed line string that is just a spike collapses.
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert!(ls.remove_spikes(0.).0.is_empty());
+
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            ls.remove_spikes(0.),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)]
+        );
+    }
+}
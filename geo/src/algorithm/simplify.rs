@@ -1,5 +1,14 @@
-use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
-use crate::{CoordsIter, EuclideanDistance};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::iter::{self, FromIterator};
+
+use crate::area::twice_signed_ring_area;
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::EuclideanDistance;
+use crate::{
+    BoundingRect, Contains, Coordinate, GeoFloat, Line, LineIntersection, LineString,
+    MultiLineString, MultiPolygon, Polygon,
+};
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
 // instead, we wrap a simple struct around index and point in a wrapper function,
@@ -103,7 +112,8 @@ where
 /// The [Ramer–Douglas–Peucker
 /// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) simplifies a
 /// linestring. Polygons are simplified by running the RDP algorithm on all their constituent
-/// rings. This may result in invalid Polygons, and has no guarantee of preserving topology.
+/// rings, as cyclic sequences by [`simplify_ring`]. This may result in invalid Polygons, and has no
+/// guarantee of preserving topology.
 ///
 /// Multi* objects are simplified by simplifying all their constituent geometries individually.
 ///
@@ -180,12 +190,146 @@ pub trait SimplifyIdx<T, Epsilon = T> {
         T: GeoFloat;
 }
 
+/// Simplifies a polygonal geometry, preserving its topology.
+///
+/// The [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) is run on all the
+/// rings, as in [`Simplify`]. Then, every simplified segment that crosses any other segment, or
+/// that jumps over a vertex of the geometry, is split back at its farthest vertex. This is
+/// repeated until no such segments remain, so a valid input gives a valid output: rings do not
+/// cross, and holes stay inside their exteriors. This is similar to the
+/// `TopologyPreservingSimplifier` of GEOS.
+///
+/// The crossings are detected using the planar sweep ([`Intersections`]) over the segments of all
+/// the rings.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyTopologyPreserving<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, using a topology-preserving variant
+    /// of the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Intersects, Simplify, SimplifyTopologyPreserving};
+    ///
+    /// // The top edge bends up around the tip of the hole.
+    /// let poly = polygon![
+    ///     exterior: [
+    ///         (x: 0., y: 0.),
+    ///         (x: 10., y: 0.),
+    ///         (x: 10., y: 10.),
+    ///         (x: 5., y: 10.5),
+    ///         (x: 0., y: 10.),
+    ///         (x: 0., y: 5.),
+    ///     ],
+    ///     interiors: [[(x: 3., y: 9.5), (x: 7., y: 9.5), (x: 5., y: 10.3)]],
+    /// ];
+    ///
+    /// // Plain simplification makes the exterior cross the hole.
+    /// let simplified = poly.simplify(&0.6);
+    /// assert!(simplified.exterior().intersects(&simplified.interiors()[0]));
+    ///
+    /// let simplified = poly.simplify_topology_preserving(&0.6);
+    /// assert!(!simplified.exterior().intersects(&simplified.interiors()[0]));
+    /// // The collinear vertex at (0, 5) is still removed.
+    /// assert_eq!(simplified.exterior().0.len(), 6);
+    /// ```
+    fn simplify_topology_preserving(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+/// Simplifies a geometry, keeping a set of fixed vertices.
+///
+/// The [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) is run separately on
+/// the parts of each linestring or ring between its `fixed` vertices, so that no segment of the
+/// output passes over one of them. The output still stays within `epsilon` of the input.
+///
+/// A vertex is fixed if it is equal to one of the `fixed` coordinates: they are compared exactly,
+/// and coordinates that are not vertices of the input are ignored.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyWithFixed<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, keeping the `fixed` vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, line_string, Simplify, SimplifyWithFixed};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 0.4),
+    ///     (x: 10.0, y: 0.0),
+    ///     (x: 15.0, y: -0.4),
+    ///     (x: 20.0, y: 0.0),
+    /// ];
+    /// assert_eq!(line_string.simplify(&1.0).0.len(), 2);
+    ///
+    /// let simplified = line_string.simplify_with_fixed(&1.0, &[coord! { x: 5.0, y: 0.4 }]);
+    ///
+    /// let expected = line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 0.4), (x: 20.0, y: 0.0)];
+    /// assert_eq!(expected, simplified)
+    /// ```
+    fn simplify_with_fixed(&self, epsilon: &T, fixed: &[Coordinate<T>]) -> Self
+    where
+        T: GeoFloat;
+}
+
+/// Simplifies a geometry, keeping a minimum number of vertices.
+///
+/// The [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) removes vertices
+/// within `epsilon` of the simplified line, which may collapse a ring of a polygon to a line. Here,
+/// the simplified line is refined at its farthest vertex from the input until it is within
+/// `epsilon`, as in [`Simplify`], and further until it has at least `min_points` coordinates. The
+/// vertices kept are thus the most significant ones: with a `min_points` of 4, a closed ring is
+/// kept as at least a triangle. Rings with fewer coordinates are kept unaltered.
+///
+/// Polygons drop their interior rings which are simplified to zero area, and multi polygons their
+/// polygons whose exterior is.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyWithMinPoints<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, with at least `min_points`
+    /// coordinates in each line string or ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Simplify, SimplifyWithMinPoints};
+    ///
+    /// let polygon = polygon![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 10.0, y: 0.0),
+    ///     (x: 10.0, y: 1.0),
+    ///     (x: 0.0, y: 1.5),
+    /// ];
+    /// // The exterior collapses to a line.
+    /// assert_eq!(polygon.simplify(&2.0).exterior().0.len(), 3);
+    ///
+    /// let simplified = polygon.simplify_with_min_points(&2.0, 4);
+    ///
+    /// let expected = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 1.0), (x: 0.0, y: 1.5)];
+    /// assert_eq!(expected, simplified)
+    /// ```
+    fn simplify_with_min_points(&self, epsilon: &T, min_points: usize) -> Self
+    where
+        T: GeoFloat;
+}
+
 impl<T> Simplify<T> for LineString<T>
 where
     T: GeoFloat,
 {
     fn simplify(&self, epsilon: &T) -> Self {
-        LineString::from(rdp(self.coords_iter(), epsilon))
+        self.simplify_idx(epsilon)
+            .into_iter()
+            .map(|idx| self.0[idx])
+            .collect()
     }
 }
 
@@ -224,10 +368,10 @@ where
 {
     fn simplify(&self, epsilon: &T) -> Self {
         Polygon::new(
-            self.exterior().simplify(epsilon),
+            simplify_ring(self.exterior(), epsilon),
             self.interiors()
                 .iter()
-                .map(|l| l.simplify(epsilon))
+                .map(|l| simplify_ring(l, epsilon))
                 .collect(),
         )
     }
@@ -242,10 +386,489 @@ where
     }
 }
 
+/// Simplifies a closed ring using the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), treating it as
+/// cyclic.
+///
+/// Running the RDP algorithm on the coordinates of a ring always keeps its first vertex, and
+/// measures the others from the degenerate line joining it to the closing coordinate, so the
+/// output depends on where the coordinates start. Here, the ring is anchored at its extreme
+/// vertex, the lowest of the leftmost ones, and at the vertex farthest from it, and both halves
+/// between them are simplified. The output starts at the extreme vertex, and is closed by an exact
+/// copy of it, so simplifying any rotation of the same ring gives the same output.
+///
+/// This is used for the rings of polygons by [`Simplify`]. A line string that is not closed, or
+/// has fewer than four coordinates, is simplified as a line string.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, simplify_ring};
+///
+/// let ring = line_string![
+///     (x: 5., y: 11.),
+///     (x: 10., y: 10.),
+///     (x: 10., y: 0.),
+///     (x: 0., y: 0.),
+///     (x: 0., y: 10.),
+///     (x: 5., y: 11.),
+/// ];
+///
+/// let expected = line_string![
+///     (x: 0., y: 0.),
+///     (x: 0., y: 10.),
+///     (x: 10., y: 10.),
+///     (x: 10., y: 0.),
+///     (x: 0., y: 0.),
+/// ];
+/// assert_eq!(simplify_ring(&ring, &2.), expected);
+/// ```
+pub fn simplify_ring<T: GeoFloat>(ring: &LineString<T>, epsilon: &T) -> LineString<T> {
+    if ring.0.len() < 4 || !ring.is_closed() {
+        return ring.simplify(epsilon);
+    }
+    let vertices = &ring.0[..ring.0.len() - 1];
+    let n = vertices.len();
+    let start = (0..n).fold(0, |best, idx| {
+        let (c, b) = (vertices[idx], vertices[best]);
+        if c.x < b.x || (c.x == b.x && c.y < b.y) {
+            idx
+        } else {
+            best
+        }
+    });
+    let indexed: Vec<_> = (0..=n)
+        .map(|k| RdpIndex {
+            index: k,
+            coord: vertices[(start + k) % n],
+        })
+        .collect();
+    let first = indexed[0].coord;
+    let (mid, _) = indexed
+        .iter()
+        .fold((0, T::zero()), |(best, best_dist), rdp_index| {
+            let dist = rdp_index.coord.euclidean_distance(&first);
+            if dist > best_dist {
+                (rdp_index.index, dist)
+            } else {
+                (best, best_dist)
+            }
+        });
+    if mid == 0 {
+        return ring.clone();
+    }
+    let mut kept = calculate_rdp_indices(&indexed[..=mid], epsilon);
+    kept.pop();
+    kept.extend(calculate_rdp_indices(&indexed[mid..], epsilon));
+    kept.into_iter().map(|idx| indexed[idx].coord).collect()
+}
+
+impl<T> SimplifyWithFixed<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_fixed(&self, epsilon: &T, fixed: &[Coordinate<T>]) -> Self {
+        let coords = &self.0;
+        if coords.len() < 3 {
+            return self.clone();
+        }
+        // The fixed inner vertices, and the end points.
+        let splits = iter::once(0)
+            .chain((1..coords.len() - 1).filter(|&idx| fixed.contains(&coords[idx])))
+            .chain(iter::once(coords.len() - 1))
+            .collect::<Vec<_>>();
+        let mut simplified = vec![coords[0]];
+        for w in splits.windows(2) {
+            let span = rdp(coords[w[0]..=w[1]].iter().copied(), epsilon);
+            simplified.extend_from_slice(&span[1..]);
+        }
+        LineString::from(simplified)
+    }
+}
+
+impl<T> SimplifyWithFixed<T> for MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_fixed(&self, epsilon: &T, fixed: &[Coordinate<T>]) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|l| l.simplify_with_fixed(epsilon, fixed))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyWithFixed<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_fixed(&self, epsilon: &T, fixed: &[Coordinate<T>]) -> Self {
+        Polygon::new(
+            self.exterior().simplify_with_fixed(epsilon, fixed),
+            self.interiors()
+                .iter()
+                .map(|l| l.simplify_with_fixed(epsilon, fixed))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyWithFixed<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_fixed(&self, epsilon: &T, fixed: &[Coordinate<T>]) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_with_fixed(epsilon, fixed))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyWithMinPoints<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_min_points(&self, epsilon: &T, min_points: usize) -> Self {
+        LineString::from(rdp_min_points(&self.0, epsilon, min_points))
+    }
+}
+
+impl<T> SimplifyWithMinPoints<T> for MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_min_points(&self, epsilon: &T, min_points: usize) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|l| l.simplify_with_min_points(epsilon, min_points))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyWithMinPoints<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_min_points(&self, epsilon: &T, min_points: usize) -> Self {
+        Polygon::new(
+            self.exterior()
+                .simplify_with_min_points(epsilon, min_points),
+            self.interiors()
+                .iter()
+                .map(|l| l.simplify_with_min_points(epsilon, min_points))
+                .filter(|l| twice_signed_ring_area(l) != T::zero())
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyWithMinPoints<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_min_points(&self, epsilon: &T, min_points: usize) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_with_min_points(epsilon, min_points))
+                .filter(|p| twice_signed_ring_area(p.exterior()) != T::zero())
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyTopologyPreserving<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_topology_preserving(&self, epsilon: &T) -> Self {
+        let rings: Vec<_> = iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        let mut simplified = simplify_rings_preserving(&rings, epsilon).into_iter();
+        let exterior = simplified.next().unwrap();
+        Polygon::new(exterior, simplified.collect())
+    }
+}
+
+impl<T> SimplifyTopologyPreserving<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_topology_preserving(&self, epsilon: &T) -> Self {
+        let rings: Vec<_> = self
+            .iter()
+            .flat_map(|p| iter::once(p.exterior()).chain(p.interiors()))
+            .collect();
+        let mut simplified = simplify_rings_preserving(&rings, epsilon).into_iter();
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| {
+                    let exterior = simplified.next().unwrap();
+                    let interiors = simplified.by_ref().take(p.interiors().len()).collect();
+                    Polygon::new(exterior, interiors)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A segment of a simplified ring, spanning the vertices `kept[pos]`
+/// to `kept[pos + 1]` of the input ring.
+#[derive(Debug, Clone, Copy)]
+struct Segment<T: GeoFloat> {
+    ring: usize,
+    pos: usize,
+    line: Line<T>,
+}
+
+impl<T: GeoFloat> Cross for Segment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+fn simplify_rings_preserving<T>(rings: &[&LineString<T>], epsilon: &T) -> Vec<LineString<T>>
+where
+    T: GeoFloat,
+{
+    if *epsilon <= T::zero() {
+        return rings.iter().map(|&r| r.clone()).collect();
+    }
+
+    // The indices of the retained vertices of each ring.
+    let mut kept: Vec<Vec<usize>> = rings.iter().map(|r| initial_rdp(r, epsilon)).collect();
+
+    loop {
+        let mut offending = BTreeSet::new();
+
+        let segments = kept.iter().enumerate().flat_map(|(ring, kept)| {
+            let coords = &rings[ring].0;
+            kept.windows(2).enumerate().map(move |(pos, w)| Segment {
+                ring,
+                pos,
+                line: Line::new(coords[w[0]], coords[w[1]]),
+            })
+        });
+        for (a, b, int) in Intersections::from_iter(segments) {
+            if !is_shared_vertex(&a, &b, &int, &kept) {
+                offending.insert((a.ring, a.pos));
+                offending.insert((b.ring, b.pos));
+            }
+        }
+
+        let vertices: Vec<_> = kept
+            .iter()
+            .zip(rings)
+            .flat_map(|(kept, ring)| kept.iter().map(move |&idx| ring.0[idx]))
+            .collect();
+        for (ring, kept) in kept.iter().enumerate() {
+            for (pos, w) in kept.windows(2).enumerate() {
+                if w[1] - w[0] > 1 && jumps_vertex(&rings[ring].0[w[0]..=w[1]], &vertices) {
+                    offending.insert((ring, pos));
+                }
+            }
+        }
+
+        // Segments of the input can not be split further.
+        offending.retain(|&(ring, pos)| kept[ring][pos + 1] - kept[ring][pos] > 1);
+        if offending.is_empty() {
+            break;
+        }
+        // Split in reverse, so the positions of the remaining segments
+        // are unchanged.
+        for &(ring, pos) in offending.iter().rev() {
+            let (start, end) = (kept[ring][pos], kept[ring][pos + 1]);
+            let split = farthest(&rings[ring].0[start..=end]) + start;
+            kept[ring].insert(pos + 1, split);
+        }
+    }
+
+    kept.iter()
+        .zip(rings)
+        .map(|(kept, ring)| kept.iter().map(|&idx| ring.0[idx]).collect())
+        .collect()
+}
+
+/// Indices retained by the RDP algorithm on a ring. A closed ring is
+/// first split at its farthest vertex from the start, so that both
+/// halves have distinct end points.
+fn initial_rdp<T: GeoFloat>(ring: &LineString<T>, epsilon: &T) -> Vec<usize> {
+    let indexed: Vec<_> = ring
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, &coord)| RdpIndex { index, coord })
+        .collect();
+    if ring.0.len() < 4 || !ring.is_closed() {
+        return calculate_rdp_indices(&indexed, epsilon);
+    }
+    let first = ring.0[0];
+    let (mid, _) = ring
+        .0
+        .iter()
+        .enumerate()
+        .fold((0, T::zero()), |(best, best_dist), (idx, c)| {
+            let dist = c.euclidean_distance(&first);
+            if dist > best_dist {
+                (idx, dist)
+            } else {
+                (best, best_dist)
+            }
+        });
+    let mut kept = calculate_rdp_indices(&indexed[..=mid], epsilon);
+    kept.pop();
+    kept.extend(calculate_rdp_indices(&indexed[mid..], epsilon));
+    kept
+}
+
+/// Whether the intersection of `a` and `b` is only their common vertex
+/// as consecutive segments of a ring.
+fn is_shared_vertex<T: GeoFloat>(
+    a: &Segment<T>,
+    b: &Segment<T>,
+    int: &LineIntersection<T>,
+    kept: &[Vec<usize>],
+) -> bool {
+    if a.ring != b.ring {
+        return false;
+    }
+    let last = kept[a.ring].len() - 2;
+    let shared = match (a.pos, b.pos) {
+        (i, j) if j == i + 1 => a.line.end,
+        (i, j) if i == j + 1 => a.line.start,
+        (0, j) if j == last && a.line.start == b.line.end => a.line.start,
+        (i, 0) if i == last && a.line.end == b.line.start => a.line.end,
+        _ => return false,
+    };
+    matches!(int, LineIntersection::SinglePoint { intersection, .. } if *intersection == shared)
+}
+
+/// Whether any of the `vertices` lies strictly inside the region
+/// enclosed by a span of a ring and its simplified segment.
+fn jumps_vertex<T: GeoFloat>(span: &[Coordinate<T>], vertices: &[Coordinate<T>]) -> bool {
+    let region = Polygon::new(LineString::from(span.to_vec()), vec![]);
+    let bounds = match region.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return false,
+    };
+    vertices
+        .iter()
+        .filter(|c| bounds.contains(*c))
+        .any(|c| region.contains(c))
+}
+
+/// Index of the vertex of a span farthest from the line joining its
+/// end points, or its middle vertex if all lie on this line.
+fn farthest<T: GeoFloat>(span: &[Coordinate<T>]) -> usize {
+    let line = Line::new(span[0], span[span.len() - 1]);
+    let (idx, dist) = span.iter().enumerate().take(span.len() - 1).skip(1).fold(
+        (0, T::zero()),
+        |(best, best_dist), (idx, c)| {
+            let dist = c.euclidean_distance(&line);
+            if dist > best_dist {
+                (idx, dist)
+            } else {
+                (best, best_dist)
+            }
+        },
+    );
+    if dist > T::zero() {
+        idx
+    } else {
+        span.len() / 2
+    }
+}
+
+/// A span of a line string to split at its `farthest` vertex from the
+/// line joining its end points, at `distance`.
+struct Split<T: GeoFloat> {
+    distance: T,
+    farthest: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<T: GeoFloat> PartialEq for Split<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: GeoFloat> Eq for Split<T> {}
+
+impl<T: GeoFloat> PartialOrd for Split<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Split<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .partial_cmp(&other.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The RDP algorithm, refining the farthest span first, and going on
+/// until at least `min_points` coordinates are kept.
+fn rdp_min_points<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    epsilon: &T,
+    min_points: usize,
+) -> Vec<Coordinate<T>> {
+    if *epsilon <= T::zero() || coords.len() <= 2 {
+        return coords.to_vec();
+    }
+    let split = |start: usize, end: usize| {
+        let line = Line::new(coords[start], coords[end]);
+        (start + 1..end).fold(None, |best: Option<Split<T>>, idx| {
+            let distance = coords[idx].euclidean_distance(&line);
+            match best {
+                Some(best) if distance <= best.distance => Some(best),
+                _ => Some(Split {
+                    distance,
+                    farthest: idx,
+                    start,
+                    end,
+                }),
+            }
+        })
+    };
+
+    let mut kept = vec![false; coords.len()];
+    kept[0] = true;
+    kept[coords.len() - 1] = true;
+    let mut count = 2;
+    let mut splits: BinaryHeap<_> = split(0, coords.len() - 1).into_iter().collect();
+    while let Some(next) = splits.pop() {
+        if next.distance <= *epsilon && count >= min_points {
+            break;
+        }
+        kept[next.farthest] = true;
+        count += 1;
+        splits.extend(split(next.start, next.farthest));
+        splits.extend(split(next.farthest, next.end));
+    }
+    coords
+        .iter()
+        .zip(kept)
+        .filter(|(_, kept)| *kept)
+        .map(|(c, _)| *c)
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::geo_types::coord;
+    use crate::Intersects;
     use crate::{line_string, polygon};
 
     #[test]
@@ -329,6 +952,48 @@ mod test {
         );
     }
 
+    #[test]
+    fn ring_rotation_invariant() {
+        let ring = line_string![
+            (x: 3., y: 0.),
+            (x: 6., y: 0.5),
+            (x: 9., y: 0.),
+            (x: 10., y: 4.),
+            (x: 9.6, y: 7.),
+            (x: 10., y: 10.),
+            (x: 4., y: 9.),
+            (x: 0., y: 10.),
+            (x: 0.3, y: 5.),
+            (x: 0., y: 2.),
+            (x: 3., y: 0.),
+        ];
+        let expected = simplify_ring(&ring, &1.);
+        // Starts and ends at the lowest leftmost vertex.
+        assert_eq!(expected.0[0], coord! { x: 0., y: 2. });
+        assert!(expected.is_closed());
+        assert_eq!(expected.0.len(), 6);
+
+        let vertices = &ring.0[..ring.0.len() - 1];
+        for shift in 0..vertices.len() {
+            let mut rotated: Vec<_> = vertices[shift..]
+                .iter()
+                .chain(&vertices[..shift])
+                .copied()
+                .collect();
+            rotated.push(rotated[0]);
+            let rotated = LineString::new(rotated);
+            assert_eq!(simplify_ring(&rotated, &1.), expected);
+            assert_eq!(
+                Polygon::new(rotated, vec![]).simplify(&1.),
+                Polygon::new(expected.clone(), vec![])
+            );
+        }
+
+        // An unclosed line string is simplified as such.
+        let line = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.), (x: 10., y: 10.)];
+        assert_eq!(simplify_ring(&line, &1.), line.simplify(&1.));
+    }
+
     #[test]
     fn multipolygon() {
         let mpoly = MultiPolygon::new(vec![polygon![
@@ -354,6 +1019,30 @@ mod test {
         );
     }
 
+    #[test]
+    fn with_fixed_monuments() {
+        // A boundary that plain simplification reduces to a square.
+        let boundary = MultiPolygon::new(vec![polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.5),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5., y: 9.5),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ]]);
+        assert_eq!(boundary.simplify(&1.).0[0].exterior().0.len(), 5);
+
+        let monuments = [coord! { x: 5., y: 0.5 }, coord! { x: 5., y: 9.5 }];
+        let simplified = boundary.simplify_with_fixed(&1., &monuments);
+        assert_eq!(simplified, boundary);
+
+        // Coordinates that are not vertices are ignored.
+        let simplified = boundary.simplify_with_fixed(&1., &[coord! { x: 5., y: 0. }]);
+        assert_eq!(simplified, boundary.simplify(&1.));
+        assert_eq!(boundary.simplify_with_fixed(&-1., &[]), boundary);
+    }
+
     #[test]
     fn simplify_negative_epsilon() {
         let ls = line_string![
@@ -379,4 +1068,189 @@ mod test {
         let indices = ls.simplify_idx(&-1.0);
         assert_eq!(vec![0usize, 1, 2, 3, 4], indices);
     }
+
+    #[test]
+    fn simplify_idx_collinear() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: 2.),
+            (x: 3., y: 3.),
+            (x: 4., y: 4.),
+        ];
+        assert_eq!(ls.simplify_idx(&0.1), vec![0, 4]);
+        assert_eq!(
+            ls.simplify(&0.1),
+            line_string![(x: 0., y: 0.), (x: 4., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn topology_preserving_hole() {
+        // The hole pokes above the straightened top edge.
+        let poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 10.5),
+                (x: 0., y: 10.),
+                (x: 0., y: 5.),
+            ],
+            interiors: [[(x: 3., y: 9.5), (x: 7., y: 9.5), (x: 5., y: 10.3)]],
+        ];
+        let naive = poly.simplify(&0.6);
+        assert!(naive.exterior().intersects(&naive.interiors()[0]));
+
+        let simplified = poly.simplify_topology_preserving(&0.6);
+        assert_eq!(
+            simplified,
+            polygon![
+                exterior: [
+                    (x: 0., y: 0.),
+                    (x: 10., y: 0.),
+                    (x: 10., y: 10.),
+                    (x: 5., y: 10.5),
+                    (x: 0., y: 10.),
+                ],
+                interiors: [[(x: 3., y: 9.5), (x: 7., y: 9.5), (x: 5., y: 10.3)]],
+            ]
+        );
+    }
+
+    #[test]
+    fn topology_preserving_jumped_hole() {
+        // Straightening the top edge would leave the hole outside,
+        // without crossing any edge.
+        let poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 11.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[(x: 4.5, y: 10.2), (x: 5.5, y: 10.2), (x: 5., y: 10.6)]],
+        ];
+        let naive = poly.simplify(&2.);
+        assert!(!naive.exterior().intersects(&naive.interiors()[0]));
+        assert_eq!(naive.exterior().0.len(), 5);
+
+        let simplified = poly.simplify_topology_preserving(&2.);
+        assert_eq!(simplified.exterior(), poly.exterior());
+    }
+
+    #[test]
+    fn topology_preserving_spiral() {
+        // The ring curls around a slit open to the left. The tip of the
+        // inner arm dips below the straightened top edge of the body.
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 12., y: 0.),
+            (x: 12., y: 13.),
+            (x: 0., y: 13.),
+            (x: 0., y: 11.),
+            (x: 4., y: 11.),
+            (x: 5., y: 9.8),
+            (x: 6., y: 11.),
+            (x: 10., y: 11.),
+            (x: 10., y: 10.),
+            (x: 5., y: 9.5),
+            (x: 0., y: 10.),
+        ];
+        assert!(!has_crossing(poly.exterior()));
+        let naive = poly.simplify(&0.6);
+        assert!(has_crossing(naive.exterior()));
+
+        let simplified = poly.simplify_topology_preserving(&0.6);
+        assert!(!has_crossing(simplified.exterior()));
+        assert!(simplified.exterior().0.contains(&coord! { x: 5., y: 9.5 }));
+    }
+
+    #[test]
+    fn topology_preserving_multipolygon() {
+        let mp = MultiPolygon::new(vec![
+            polygon![
+                (x: 0., y: 0.5),
+                (x: 5., y: 1.),
+                (x: 10., y: 0.5),
+                (x: 10., y: 5.),
+                (x: 0., y: 5.),
+            ],
+            polygon![(x: 3., y: 0.), (x: 7., y: 0.), (x: 5., y: 0.8)],
+        ]);
+        let naive = mp.simplify(&0.6);
+        assert!(naive.0[0].intersects(&naive.0[1]));
+
+        let simplified = mp.simplify_topology_preserving(&0.6);
+        assert!(!simplified.0[0].intersects(&simplified.0[1]));
+        assert_eq!(simplified.0[1], mp.0[1]);
+
+        assert_eq!(mp.simplify_topology_preserving(&0.), mp);
+    }
+
+    /// Whether two non-consecutive segments of a ring intersect.
+    fn has_crossing(ring: &LineString<f64>) -> bool {
+        let lines: Vec<_> = ring.lines().collect();
+        let n = lines.len();
+        (0..n).any(|i| {
+            (i + 2..n)
+                .filter(|&j| !(i == 0 && j == n - 1))
+                .any(|j| lines[i].intersects(&lines[j]))
+        })
+    }
+
+    #[test]
+    fn min_points_matches_rdp() {
+        let ls: LineString<f64> = geo_test_fixtures::norway_main();
+        for epsilon in [0.001, 0.01, 0.1] {
+            assert_eq!(
+                ls.simplify_with_min_points(&epsilon, 0),
+                ls.simplify(&epsilon)
+            );
+        }
+        let simplified = ls.simplify_with_min_points(&1.0, 100);
+        assert_eq!(simplified.0.len(), 100);
+        assert!(simplified.0.iter().all(|c| ls.0.contains(c)));
+    }
+
+    #[test]
+    fn min_points_keeps_triangle() {
+        use crate::{Area, IsValidReason};
+
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10.5, y: 0.2), (x: 10., y: 0.4), (x: 0., y: 0.4)],
+            interiors: [[(x: 1., y: 0.1), (x: 2., y: 0.1), (x: 2., y: 0.2), (x: 1., y: 0.2)]],
+        ];
+        // The exterior collapses to a line.
+        let collapsed = poly.simplify(&1.);
+        assert_eq!(collapsed.exterior().0.len(), 3);
+        assert_eq!(collapsed.unsigned_area(), 0.);
+
+        // Valid triangles, for the exterior and the hole.
+        let simplified = poly.simplify_with_min_points(&1., 4);
+        assert_eq!(
+            simplified,
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10.5, y: 0.2), (x: 0., y: 0.4)],
+                interiors: [[(x: 1., y: 0.1), (x: 2., y: 0.1), (x: 2., y: 0.2)]],
+            ]
+        );
+        assert_eq!(simplified.is_valid_reason(), None);
+
+        // A hole with collinear vertices collapses whatever the minimum.
+        let flat_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 3., y: 1.), (x: 2., y: 1.)]],
+        ];
+        assert!(flat_hole
+            .simplify_with_min_points(&1., 4)
+            .interiors()
+            .is_empty());
+
+        // As is a polygon of a multi polygon.
+        let flat = polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 22., y: 0.)];
+        let simplified = MultiPolygon::new(vec![poly, flat]).simplify_with_min_points(&1., 4);
+        assert_eq!(simplified.0.len(), 1);
+    }
 }
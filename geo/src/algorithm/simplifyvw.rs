@@ -699,6 +699,30 @@ mod test {
         assert_eq!(simplified, correct_ls);
     }
     #[test]
+    fn visvalingam_removes_near_collinear_first() {
+        // (1, 0.01) is almost collinear with its neighbours (area 0.01),
+        // while (3, 1) spans a triangle of area 1.
+        let ls = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.01),
+            (x: 2.0, y: 0.0),
+            (x: 3.0, y: 1.0),
+            (x: 4.0, y: 0.0)
+        ];
+
+        let simplified = visvalingam(&ls, &0.5);
+        assert_eq!(
+            simplified,
+            vec![
+                Coordinate::from((0.0, 0.0)),
+                (2.0, 0.0).into(),
+                (3.0, 1.0).into(),
+                (4.0, 0.0).into(),
+            ]
+        );
+        assert_eq!(ls.simplifyvw_preserve(&0.5).0, simplified);
+    }
+    #[test]
     fn vwp_intersection_test() {
         // does the intersection check always work
         let a = point!(x: 1., y: 3.);
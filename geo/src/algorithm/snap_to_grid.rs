@@ -0,0 +1,265 @@
+use crate::{coord, CoordFloat, Coordinate, LineString, MultiPolygon, Polygon};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Round the coordinates of a geometry to the nearest multiple of a
+/// grid cell size.
+///
+/// After snapping, consecutive duplicate vertices are removed, and the
+/// rings of polygons are re-closed. Snapping may hence reduce the
+/// number of vertices, and collapse small rings: a hole left with fewer
+/// than four coordinates is dropped, and a polygon whose exterior
+/// collapses is emptied, or dropped from a `MultiPolygon`. It does not
+/// otherwise repair the geometry.
+///
+/// A `cell_size` that is not positive (or `NaN`) returns an unaltered
+/// copy of the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SnapToGrid};
+///
+/// let ls = line_string![
+///     (x: 0.1, y: 0.0),
+///     (x: 0.9, y: 0.2),
+///     (x: 1.1, y: -0.1),
+///     (x: 2.2, y: 1.6),
+/// ];
+/// assert_eq!(
+///     ls.snap_to_grid(1.),
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 2.)],
+/// );
+/// ```
+pub trait SnapToGrid<T: CoordFloat> {
+    fn snap_to_grid(&self, cell_size: T) -> Self;
+}
+
+impl<T: CoordFloat> SnapToGrid<T> for Coordinate<T> {
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        if !is_positive(cell_size) {
+            return *self;
+        }
+        coord! {
+            x: (self.x / cell_size).round() * cell_size,
+            y: (self.y / cell_size).round() * cell_size,
+        }
+    }
+}
+
+impl<T: CoordFloat> SnapToGrid<T> for LineString<T> {
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        if !is_positive(cell_size) {
+            return self.clone();
+        }
+        let mut coords: Vec<_> = self.0.iter().map(|c| c.snap_to_grid(cell_size)).collect();
+        coords.dedup();
+        LineString::new(coords)
+    }
+}
+
+impl<T: CoordFloat> SnapToGrid<T> for Polygon<T> {
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        if !is_positive(cell_size) {
+            return self.clone();
+        }
+        // The snapped ring, or `None` if it collapsed.
+        let snap_ring = |ring: &LineString<T>| {
+            let mut ring = ring.snap_to_grid(cell_size);
+            ring.close();
+            if ring.0.len() > 3 {
+                Some(ring)
+            } else {
+                None
+            }
+        };
+        match snap_ring(self.exterior()) {
+            Some(exterior) => Polygon::new(
+                exterior,
+                self.interiors().iter().filter_map(snap_ring).collect(),
+            ),
+            None => Polygon::new(LineString::new(vec![]), vec![]),
+        }
+    }
+}
+
+impl<T: CoordFloat> SnapToGrid<T> for MultiPolygon<T> {
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.snap_to_grid(cell_size))
+                .filter(|p| !p.exterior().0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+/// A coordinate compared and hashed by the grid point it snaps to, as
+/// [`SnapToGrid`] with a `precision` cell size.
+///
+/// The coordinates of the same grid cell are equal, and have the same
+/// hash, so they can key a `HashMap` to find the shared vertices of
+/// noisy inputs. Note that, as by snapping, two coordinates on either
+/// side of the border of a cell are not equal, however close.
+///
+/// Coordinates of different precisions are not equal. The values are
+/// compared by their bits, once `-0.` is turned into `0.`, and every
+/// `NaN` into the same one, so that a `NaN` coordinate is equal to
+/// itself, and `Eq` holds.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, HashableCoord};
+/// use std::collections::HashSet;
+///
+/// let vertices: HashSet<_> = [(0.1, 0.), (1., 1.), (1.04, 0.98)]
+///     .into_iter()
+///     .map(|(x, y)| HashableCoord::new(coord! { x: x, y: y }, 0.1))
+///     .collect();
+/// assert_eq!(vertices.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HashableCoord<T: CoordFloat> {
+    pub coord: Coordinate<T>,
+    pub precision: T,
+}
+
+impl<T: CoordFloat> HashableCoord<T> {
+    pub fn new(coord: Coordinate<T>, precision: T) -> Self {
+        HashableCoord { coord, precision }
+    }
+
+    /// The grid point `coord` snaps to.
+    pub fn snapped(&self) -> Coordinate<T> {
+        self.coord.snap_to_grid(self.precision)
+    }
+
+    /// The bits of the snapped coordinate and of the precision, compared
+    /// and hashed in place of the values.
+    fn key(&self) -> [u64; 3] {
+        let snapped = self.snapped();
+        [snapped.x, snapped.y, self.precision].map(|value| {
+            if value.is_nan() {
+                return f64::NAN.to_bits();
+            }
+            // Adding zero turns `-0.` into `0.`, which compare equal.
+            let value = value + T::zero();
+            value.to_f64().unwrap().to_bits()
+        })
+    }
+}
+
+impl<T: CoordFloat> PartialEq for HashableCoord<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T: CoordFloat> Eq for HashableCoord<T> {}
+
+impl<T: CoordFloat> Hash for HashableCoord<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+fn is_positive<T: CoordFloat>(cell_size: T) -> bool {
+    cell_size.partial_cmp(&T::zero()) == Some(Ordering::Greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon;
+    use std::collections::hash_map::DefaultHasher;
+    use wkt::TryFromWkt;
+
+    #[test]
+    fn test_ulp_apart_rectangle() {
+        let mp = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((-1 -2,-1.0000000000000002 2,-0.8823529411764707 2,-0.8823529411764706 -2,-1 -2)))",
+        )
+        .unwrap();
+        let snapped = mp.snap_to_grid(1e-9);
+        let xs: Vec<f64> = snapped.0[0].exterior().0.iter().map(|c| c.x).collect();
+        assert_eq!(xs[0], xs[1]);
+        assert_eq!(xs[2], xs[3]);
+        assert_eq!(xs[0], -1.);
+        assert_relative_eq!(xs[2], -0.882352941, epsilon = 1e-12);
+        for c in snapped.0[0].exterior().coords() {
+            let cells = c.x / 1e-9;
+            assert_relative_eq!(cells, cells.round(), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_dedup_and_close() {
+        let poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 0.1, y: 0.1),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+                (x: -0.1, y: 0.2),
+            ],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1.2, y: 1.), (x: 1.2, y: 1.2)],
+                [(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)],
+            ],
+        ];
+        let snapped = poly.snap_to_grid(1.);
+        // The first hole collapses to a point, and is dropped.
+        assert_eq!(
+            snapped,
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)]],
+            ]
+        );
+        assert!(snapped.exterior().is_closed());
+
+        // A non-positive cell size is a no-op.
+        assert_eq!(poly.snap_to_grid(0.), poly);
+        assert_eq!(poly.snap_to_grid(f64::NAN), poly);
+    }
+
+    #[test]
+    fn test_collapsed_sliver() {
+        // A sliver narrower than a grid cell collapses to a line.
+        let sliver = polygon![(x: 0., y: 0.), (x: 3., y: 0.1), (x: 3., y: 0.3), (x: 0., y: 0.2)];
+        let snapped = sliver.snap_to_grid(1.);
+        assert!(snapped.exterior().0.is_empty());
+        assert!(snapped.interiors().is_empty());
+
+        let square = polygon![(x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 4.), (x: 0., y: 4.)];
+        let mp = MultiPolygon::new(vec![sliver, square.clone()]);
+        assert_eq!(mp.snap_to_grid(1.), MultiPolygon::new(vec![square]));
+    }
+
+    #[test]
+    fn test_hashable_coord() {
+        let hash = |c: &HashableCoord<f64>| {
+            let mut hasher = DefaultHasher::new();
+            c.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = HashableCoord::new(coord! { x: 1.02, y: -0.01 }, 0.1);
+        let b = HashableCoord::new(coord! { x: 0.98, y: 0.04 }, 0.1);
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        // Both snap to y = 0, of either sign.
+        assert_eq!(a.snapped().y, 0.);
+
+        let c = HashableCoord::new(coord! { x: 1.06, y: 0. }, 0.1);
+        assert_ne!(a, c);
+        assert_ne!(a, HashableCoord::new(a.coord, 0.2));
+        let nan = HashableCoord::new(coord! { x: f64::NAN, y: 0. }, 0.1);
+        assert_eq!(nan, nan);
+        let negative_nan = HashableCoord::new(coord! { x: -f64::NAN, y: 0. }, 0.1);
+        assert_eq!(nan, negative_nan);
+        assert_eq!(hash(&nan), hash(&negative_nan));
+        assert_ne!(nan, a);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    coord, BooleanOps, BoundingRect, Coordinate, GeoFloat, LineString, MapCoords, MultiPolygon,
+    Polygon, Rect,
+};
+
+/// Split a polygonal geometry in longitude/latitude degrees along the
+/// antimeridian (±180°), so that planar algorithms may be applied.
+///
+/// Every edge is taken to be the shorter way around the globe: an edge
+/// from `170°` to `-170°` crosses the antimeridian, and is `20°` long.
+/// The output is a `MultiPolygon` with all longitudes in `[-180, 180]`,
+/// where the parts on either side of the antimeridian are separate
+/// polygons, touching along `x = 180` and `x = -180`.
+///
+/// # Remarks
+///
+/// - Polygons that do not cross the antimeridian, and lie within
+///   `[-180, 180]`, are returned as-is.
+///
+/// - Rings that wind around a pole (and hence do not close up when
+///   unwrapped) cannot be split along a single meridian; polygons
+///   with such a ring are returned as-is.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, SplitAtAntimeridian};
+///
+/// // From 170°E to 170°W, crossing the antimeridian.
+/// let poly = polygon![
+///     (x: 170., y: 0.),
+///     (x: -170., y: 0.),
+///     (x: -170., y: 10.),
+///     (x: 170., y: 10.),
+/// ];
+///
+/// let split = poly.split_at_antimeridian();
+/// assert_eq!(split.0.len(), 2);
+/// assert_eq!(split.unsigned_area(), 200.);
+/// ```
+pub trait SplitAtAntimeridian<T: GeoFloat> {
+    fn split_at_antimeridian(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> SplitAtAntimeridian<T> for Polygon<T> {
+    fn split_at_antimeridian(&self) -> MultiPolygon<T> {
+        let exterior = match unwrap_ring(self.exterior()) {
+            Some(ring) => ring,
+            None => return self.clone().into(),
+        };
+        let mut interiors = Vec::with_capacity(self.interiors().len());
+        for hole in self.interiors() {
+            match unwrap_ring(hole) {
+                Some(ring) => interiors.push(ring),
+                None => return self.clone().into(),
+            }
+        }
+
+        let limit = T::from(180).unwrap();
+        let within_limits = |ls: &LineString<T>| ls.0.iter().all(|c| c.x.abs() <= limit);
+        if within_limits(&exterior) && interiors.iter().all(within_limits) {
+            return self.clone().into();
+        }
+
+        let exterior = split_ring(exterior);
+        if interiors.is_empty() {
+            return exterior;
+        }
+        let holes = MultiPolygon::new(interiors.into_iter().flat_map(split_ring).collect());
+        exterior.difference(&holes)
+    }
+}
+
+impl<T: GeoFloat> SplitAtAntimeridian<T> for MultiPolygon<T> {
+    fn split_at_antimeridian(&self) -> MultiPolygon<T> {
+        MultiPolygon::new(
+            self.iter()
+                .flat_map(|p| p.split_at_antimeridian())
+                .collect(),
+        )
+    }
+}
+
+/// Shift the longitudes of a ring so that no edge is longer than 180°,
+/// or `None` if the unwrapped ring does not close up.
+fn unwrap_ring<T: GeoFloat>(ring: &LineString<T>) -> Option<LineString<T>> {
+    let half = T::from(180).unwrap();
+    let full = T::from(360).unwrap();
+
+    let mut coords: Vec<Coordinate<T>> = Vec::with_capacity(ring.0.len());
+    for c in ring.coords() {
+        let x = match coords.last() {
+            Some(prev) => {
+                let mut dx = (c.x - prev.x) % full;
+                if dx > half {
+                    dx = dx - full;
+                } else if dx < -half {
+                    dx = dx + full;
+                }
+                prev.x + dx
+            }
+            None => c.x,
+        };
+        coords.push(coord! { x: x, y: c.y });
+    }
+    match (coords.first(), coords.last()) {
+        (Some(first), Some(last)) if first.x != last.x => None,
+        _ => Some(LineString::new(coords)),
+    }
+}
+
+/// Clip an unwrapped ring into `360°` wide strips centered on the
+/// multiples of `360°`, and shift each piece into `[-180, 180]`.
+fn split_ring<T: GeoFloat>(ring: LineString<T>) -> MultiPolygon<T> {
+    let half = T::from(180).unwrap();
+    let full = T::from(360).unwrap();
+
+    let poly = Polygon::new(ring, vec![]);
+    let bounds = match poly.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return MultiPolygon::new(vec![]),
+    };
+
+    let mut parts = vec![];
+    let mut k = ((bounds.min().x + half) / full).floor();
+    while k * full - half < bounds.max().x {
+        let strip = Rect::new(
+            coord! { x: k * full - half, y: bounds.min().y },
+            coord! { x: k * full + half, y: bounds.max().y },
+        );
+        let shift = k * full;
+        parts.extend(
+            poly.intersection(&strip.to_polygon())
+                .map_coords(|c| coord! { x: c.x - shift, y: c.y }),
+        );
+        k = k + T::one();
+    }
+    MultiPolygon::new(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, Area};
+
+    #[test]
+    fn test_crossing() {
+        let poly = polygon![
+            (x: 170., y: 0.),
+            (x: -170., y: 0.),
+            (x: -170., y: 10.),
+            (x: 170., y: 10.),
+        ];
+        let split = poly.split_at_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        assert_relative_eq!(split.unsigned_area(), 200.);
+
+        let east = Rect::new(coord! { x: 170., y: 0. }, coord! { x: 180., y: 10. });
+        let west = Rect::new(coord! { x: -180., y: 0. }, coord! { x: -170., y: 10. });
+        for rect in [east, west] {
+            assert!(split
+                .iter()
+                .any(|p| p.bounding_rect() == Some(rect) && p.unsigned_area() == 100.));
+        }
+    }
+
+    #[test]
+    fn test_crossing_with_hole() {
+        let poly = polygon![
+            exterior: [(x: 160., y: 0.), (x: -160., y: 0.), (x: -160., y: 10.), (x: 160., y: 10.)],
+            interiors: [[(x: 175., y: 2.), (x: -175., y: 2.), (x: -175., y: 8.), (x: 175., y: 8.)]],
+        ];
+        let split = poly.split_at_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        assert_relative_eq!(split.unsigned_area(), 400. - 60.);
+        for p in split.iter() {
+            assert!(p.bounding_rect().unwrap().max().x <= 180.);
+            assert!(p.bounding_rect().unwrap().min().x >= -180.);
+        }
+    }
+
+    #[test]
+    fn test_not_crossing() {
+        let poly = polygon![
+            (x: 10., y: 0.),
+            (x: 20., y: 0.),
+            (x: 20., y: 10.),
+            (x: 10., y: 10.),
+        ];
+        assert_eq!(poly.split_at_antimeridian(), poly.clone().into());
+
+        // Winds around the north pole.
+        let polar = polygon![
+            (x: -120., y: 80.),
+            (x: 0., y: 80.),
+            (x: 120., y: 80.),
+        ];
+        assert_eq!(polar.split_at_antimeridian(), polar.clone().into());
+    }
+}
@@ -1,11 +1,13 @@
-use std::{
+use alloc::collections::BTreeSet;
+use core::{
     borrow::Borrow,
     cmp::Ordering,
-    collections::BTreeSet,
     fmt::Debug,
     ops::{Bound, Deref},
 };
 
+use super::Error;
+
 /// A segment currently active in the sweep.
 ///
 /// As the sweep-line progresses from left to right, it intersects a subset of
@@ -23,7 +25,7 @@ pub(super) struct Active<T>(pub(super) T);
 
 impl<T> Active<T> {
     pub(super) fn active_ref(t: &T) -> &Active<T> {
-        unsafe { std::mem::transmute(t) }
+        unsafe { core::mem::transmute(t) }
     }
 }
 
@@ -62,7 +64,9 @@ pub(super) trait ActiveSet: Default {
     type Seg;
     fn previous(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>>;
     fn next(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>>;
-    fn insert_active(&mut self, segment: Self::Seg);
+    /// Insert a segment, unless the set already holds `max_len`
+    /// segments.
+    fn insert_active(&mut self, segment: Self::Seg, max_len: usize) -> Result<(), Error>;
     fn remove_active(&mut self, segment: &Self::Seg);
 }
 
@@ -85,9 +89,13 @@ impl<T: PartialOrd> ActiveSet for BTreeSet<Active<T>> {
         .next()
     }
 
-    fn insert_active(&mut self, segment: Self::Seg) {
+    fn insert_active(&mut self, segment: Self::Seg, max_len: usize) -> Result<(), Error> {
+        if self.len() >= max_len {
+            return Err(Error::ActiveSetTooLarge);
+        }
         let result = self.insert(Active(segment));
         debug_assert!(result);
+        Ok(())
     }
 
     fn remove_active(&mut self, segment: &Self::Seg) {
@@ -1,9 +1,10 @@
-use std::{fmt::Debug, rc::Rc, sync::Arc};
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::fmt::Debug;
 
 use geo_types::Line;
 
 use super::*;
-use crate::GeoFloat;
+use crate::{GeoFloat, Orientation};
 
 /// Interface for types that can be processed to detect crossings.
 ///
@@ -11,6 +12,32 @@ use crate::GeoFloat;
 /// this on custom types to store extra information. Any type that represents an
 /// ordered line-segment may implement this.
 ///
+/// # Lines
+///
+/// The geo-type [`Line`] implements this trait with `Scalar = T`; so a
+/// slice of `Line<f64>` may be fed to the sweep as-is. To identify the
+/// input lines in the output, pair each with its index: `(usize, C)`
+/// implements this trait for any `C: Cross`, using the geometry of `C`.
+///
+/// ```rust
+/// use geo::Line;
+/// use geo::sweep::Intersections;
+///
+/// let lines: Vec<Line<f64>> = vec![
+///     Line::from([(0., 0.), (1., 1.)]),
+///     Line::from([(1., 0.), (0., 1.)]),
+///     Line::from([(2., 0.), (3., 1.)]),
+/// ];
+/// let pairs: Vec<_> = lines
+///     .iter()
+///     .copied()
+///     .enumerate()
+///     .collect::<Intersections<_>>()
+///     .map(|((i, _), (j, _), _)| (i.min(j), i.max(j)))
+///     .collect();
+/// assert_eq!(pairs, vec![(0, 1)]);
+/// ```
+///
 /// # Cloning
 ///
 /// Note that for usage with the planar sweep iterators, the type must
@@ -24,6 +51,20 @@ pub trait Cross: Sized + Debug {
     /// The geometry associated with this type. Use a `Line` with the
     /// `start` and `end` coordinates to represent a point.
     fn line(&self) -> LineOrPoint<Self::Scalar>;
+
+    /// The side of the line toward which the intersection points in its
+    /// interior are rounded, if any.
+    ///
+    /// `Orientation::CounterClockwise` is the side above the line: on
+    /// the left going from its left end to its right end, and
+    /// `Orientation::Clockwise` the side below it. An intersection point
+    /// is moved by a few units in the last place, to a coordinate
+    /// strictly on the requested sides of both the lines crossing there,
+    /// if there is one. Otherwise, and by default, the intersections are
+    /// rounded to the nearest coordinates.
+    fn rounding_side(&self) -> Option<Orientation> {
+        None
+    }
 }
 
 impl<'a, T: Cross> Cross for &'a T {
@@ -32,6 +73,10 @@ impl<'a, T: Cross> Cross for &'a T {
     fn line(&self) -> LineOrPoint<Self::Scalar> {
         T::line(*self)
     }
+
+    fn rounding_side(&self) -> Option<Orientation> {
+        T::rounding_side(*self)
+    }
 }
 
 impl<T: GeoFloat> Cross for LineOrPoint<T> {
@@ -50,6 +95,18 @@ impl<T: GeoFloat> Cross for Line<T> {
     }
 }
 
+impl<C: Cross> Cross for (usize, C) {
+    type Scalar = C::Scalar;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.1.line()
+    }
+
+    fn rounding_side(&self) -> Option<Orientation> {
+        self.1.rounding_side()
+    }
+}
+
 macro_rules! blanket_impl_smart_pointer {
     ($ty:ty) => {
         impl<T: Cross> Cross for $ty {
@@ -58,6 +115,10 @@ macro_rules! blanket_impl_smart_pointer {
             fn line(&self) -> LineOrPoint<Self::Scalar> {
                 T::line(self)
             }
+
+            fn rounding_side(&self) -> Option<Orientation> {
+                T::rounding_side(self)
+            }
         }
     };
 }
@@ -0,0 +1,44 @@
+use core::fmt;
+
+use crate::Coordinate;
+
+/// Errors reported by the fallible entry points of the planar sweep and
+/// the algorithms built on it.
+///
+/// The variants carry the offending input when available, so that the
+/// error can be located in the input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A coordinate of the input is `NaN` or infinite. Such coordinates
+    /// can not be ordered, and would otherwise panic deep in the sweep.
+    NonFiniteCoordinate(Coordinate<f64>),
+    /// The number of segments simultaneously active in the sweep
+    /// exceeded the configured maximum. This happens on pathological
+    /// inputs with many overlapping segments.
+    ActiveSetTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NonFiniteCoordinate(c) => {
+                write!(f, "non-finite coordinate in input: ({}, {})", c.x, c.y)
+            }
+            Error::ActiveSetTooLarge => {
+                write!(f, "too many active segments in the sweep")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Report the error as [`io::ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData).
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
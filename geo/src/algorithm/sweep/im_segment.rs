@@ -1,4 +1,5 @@
-use std::{borrow::Borrow, cell::UnsafeCell, cmp::Ordering, fmt::Debug, ops::Deref, rc::Rc};
+use alloc::rc::Rc;
+use core::{borrow::Borrow, cell::UnsafeCell, cmp::Ordering, fmt::Debug, ops::Deref};
 
 use super::*;
 
@@ -39,7 +40,7 @@ impl<C: Cross> From<Segment<C>> for IMSegment<C> {
 }
 
 impl<C: Cross> Debug for IMSegment<C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         unsafe { self.get() }.fmt(f)
     }
 }
@@ -62,6 +63,10 @@ impl<C: Cross> IMSegment<C> {
         &unsafe { self.get() }.cross
     }
 
+    pub fn input_idx(&self) -> usize {
+        unsafe { self.get() }.input_idx
+    }
+
     pub fn set_left_event_done(&self) {
         unsafe { self.get_mut() }.left_event_done = true;
     }
@@ -153,11 +158,12 @@ impl<C: Cross> IMSegment<C> {
 impl<C: Cross + Clone> IMSegment<C> {
     pub(super) fn create_segment<F: FnMut(Event<C::Scalar, Self>)>(
         crossable: C,
+        input_idx: usize,
         geom: Option<LineOrPoint<C::Scalar>>,
         parent: Option<&Self>,
         mut cb: F,
     ) -> Self {
-        let segment: Self = Segment::new(crossable, geom).into();
+        let segment: Self = Segment::new(crossable, input_idx, geom).into();
 
         // Push events to process the created segment.
         for e in [segment.left_event(), segment.right_event()] {
@@ -176,7 +182,12 @@ impl<C: Cross + Clone> IMSegment<C> {
                 let child_overlapping = &child_inner_seg.overlapping;
                 let child_crossable = child_inner_seg.cross.clone();
 
-                let new_segment: Self = Segment::new(child_crossable, Some(segment_geom)).into();
+                let new_segment: Self = Segment::new(
+                    child_crossable,
+                    child_inner_seg.input_idx,
+                    Some(segment_geom),
+                )
+                .into();
 
                 unsafe { tgt.get_mut() }.overlapping = Some(new_segment.clone());
                 unsafe { new_segment.get_mut() }.is_overlapping = true;
@@ -195,12 +206,14 @@ impl<C: Cross + Clone> IMSegment<C> {
     ) -> Option<Self> {
         let adj_segment = &mut unsafe { self.get() };
         let adj_cross = adj_segment.cross.clone();
+        let adj_idx = adj_segment.input_idx;
         use SplitSegments::*;
         match self.adjust_for_intersection(adj_intersection) {
             Unchanged { overlap } => overlap.then(|| self.clone()),
             SplitOnce { overlap, right } => {
                 cb(self.right_event());
-                let new_key = Self::create_segment(adj_cross, Some(right), Some(self), &mut cb);
+                let new_key =
+                    Self::create_segment(adj_cross, adj_idx, Some(right), Some(self), &mut cb);
                 match overlap {
                     Some(false) => Some(self.clone()),
                     Some(true) => Some(new_key),
@@ -209,9 +222,14 @@ impl<C: Cross + Clone> IMSegment<C> {
             }
             SplitTwice { right } => {
                 cb(self.right_event());
-                Self::create_segment(adj_cross.clone(), Some(right), Some(self), &mut cb);
-                let middle =
-                    Self::create_segment(adj_cross, Some(adj_intersection), Some(self), &mut cb);
+                Self::create_segment(adj_cross.clone(), adj_idx, Some(right), Some(self), &mut cb);
+                let middle = Self::create_segment(
+                    adj_cross,
+                    adj_idx,
+                    Some(adj_intersection),
+                    Some(self),
+                    &mut cb,
+                );
                 Some(middle)
             }
         }
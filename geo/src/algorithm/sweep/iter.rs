@@ -1,7 +1,8 @@
-use std::borrow::Borrow;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
 
 use super::*;
-use crate::{line_intersection::line_intersection, Coordinate, LineIntersection};
+use crate::{line_intersection::line_intersection, Coordinate, Line, LineIntersection, Rect};
 
 /// A segment of a input [`Cross`] type.
 ///
@@ -31,8 +32,8 @@ pub(crate) struct Crossing<C: Cross> {
     ///
     /// Note that the overlapping segments may not always
     /// _all_ get batched together. They may be reported as
-    /// one or more set of overlapping segments in an
-    /// arbitrary order.
+    /// one or more set of overlapping segments. Within a set, the
+    /// segments are in the order of the inputs they are part of.
     pub has_overlap: bool,
 
     /// Flag that is `true` if the `geom` starts at the intersection
@@ -44,13 +45,17 @@ pub(crate) struct Crossing<C: Cross> {
 
 impl<C: Cross + Clone> Crossing<C> {
     /// Convert `self` into a `Crossing` to return to user.
-    pub(super) fn from_segment(segment: &IMSegment<C>, event_ty: EventType) -> Crossing<C> {
+    pub(super) fn from_segment(
+        segment: &IMSegment<C>,
+        event_ty: EventType,
+        has_overlap: bool,
+    ) -> Crossing<C> {
         let seg: &Segment<_> = segment.borrow();
         Crossing {
             cross: seg.cross.clone(),
             line: seg.geom,
             first_segment: seg.first_segment,
-            has_overlap: seg.overlapping.is_some(),
+            has_overlap,
             at_left: event_ty == EventType::LineLeft,
             segment: segment.clone(),
         }
@@ -115,24 +120,87 @@ where
     pub(crate) fn prev_active(&self, c: &Crossing<C>) -> Option<(LineOrPoint<C::Scalar>, &C)> {
         self.sweep.prev_active(c).map(|s| (s.geom, &s.cross))
     }
+
+    /// The bottommost and topmost segments crossing the sweep line just
+    /// after the last point yielded, with their inputs: the envelope of
+    /// the inputs at the frontier of the sweep.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn frontier_extent(
+        &self,
+    ) -> Option<((LineOrPoint<C::Scalar>, &C), (LineOrPoint<C::Scalar>, &C))> {
+        self.sweep
+            .frontier_extent()
+            .map(|(bottom, top)| ((bottom.geom, &bottom.cross), (top.geom, &top.cross)))
+    }
+
+    /// Counters accumulated by the sweep so far.
+    pub fn statistics(&self) -> SweepStats {
+        self.sweep.stats()
+    }
+
+    /// Stop the sweep with [`Error::ActiveSetTooLarge`] once more than
+    /// `max_active` segments are active. Must be set before iterating.
+    pub(crate) fn set_max_active(&mut self, max_active: usize) {
+        self.sweep.set_max_active(max_active);
+    }
+
+    /// Only check for intersections within `region`. Must be set before
+    /// iterating.
+    pub(crate) fn set_region(&mut self, region: Rect<C::Scalar>) {
+        self.sweep.set_region(region);
+    }
+
+    /// Assume the segments only meet at end points, or overlap as a
+    /// whole, and skip the other intersection checks. Must be set before
+    /// iterating.
+    pub(crate) fn set_noded(&mut self, noded: bool) {
+        self.sweep.set_noded(noded);
+    }
+
+    /// Record the last `len` events processed by the sweep, retrieved by
+    /// [`trace`](Self::trace). Must be set before iterating.
+    pub(crate) fn set_trace_len(&mut self, len: usize) {
+        self.sweep.set_trace_len(len);
+    }
+
+    /// The events recorded so far, oldest first.
+    pub(crate) fn trace(&self) -> Vec<TraceEvent<C::Scalar>> {
+        self.sweep.trace()
+    }
+
+    /// The error that stopped the iteration early, if any.
+    pub(crate) fn error(&self) -> Option<Error> {
+        self.sweep.error()
+    }
 }
 
-impl<C> FromIterator<C> for CrossingsIter<C>
+impl<C> CrossingsIter<C>
 where
     C: Cross + Clone,
 {
-    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+    /// Collect the segments of `iter`, with an event queue sized for at
+    /// least `segment_count` segments.
+    pub(crate) fn with_capacity<T: IntoIterator<Item = C>>(iter: T, segment_count: usize) -> Self {
         let iter = iter.into_iter();
         let size = {
             let (min_size, max_size) = iter.size_hint();
             max_size.unwrap_or(min_size)
         };
-        let sweep = Sweep::new(iter);
+        let sweep = Sweep::with_capacity(iter, segment_count);
         let segments = Vec::with_capacity(4 * size);
         Self { sweep, segments }
     }
 }
 
+impl<C> FromIterator<C> for CrossingsIter<C>
+where
+    C: Cross + Clone,
+{
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+        Self::with_capacity(iter, 0)
+    }
+}
+
 impl<C> Iterator for CrossingsIter<C>
 where
     C: Cross + Clone,
@@ -146,16 +214,22 @@ where
         let mut last_point = self.sweep.peek_point();
         debug!("pt: {last_point:?}");
         while last_point == self.sweep.peek_point() && self.sweep.peek_point().is_some() {
-            last_point = self.sweep.next_event(|seg, ty| {
+            last_point = self.sweep.next_event(|seg, ty, has_overlap| {
                 trace!(
                     "cb: {seg:?} {ty:?} (crossable = {cross:?})",
                     cross = seg.cross().line()
                 );
-                segments.push(Crossing::from_segment(seg, ty))
+                segments.push(Crossing::from_segment(seg, ty, has_overlap))
             });
         }
+        // A segment reported at an earlier event at this point may have
+        // been split since, by an overlap with a later one.
+        for c in segments.iter_mut().filter(|c| c.at_left) {
+            let seg: &Segment<_> = c.segment.borrow();
+            c.line = seg.geom;
+        }
 
-        if segments.is_empty() {
+        if segments.is_empty() || self.sweep.error().is_some() {
             None
         } else {
             last_point.map(|p| *p)
@@ -203,6 +277,22 @@ pub struct Intersections<C: Cross + Clone> {
     jdx: usize,
     is_overlap: bool,
     pt: Option<Coordinate<C::Scalar>>,
+    collinear: CollinearReport,
+    /// The second end of an overlap reported as its end points.
+    pending: Option<(C, C, LineIntersection<C::Scalar>)>,
+}
+
+/// How [`Intersections`] reports the pairs of collinear segments that
+/// overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollinearReport {
+    /// Yield the overlap as a [`LineIntersection::Collinear`] interval.
+    AsInterval,
+    /// Yield the two ends of the overlap, one after the other, as
+    /// [`LineIntersection::SinglePoint`]s that are not proper.
+    AsEndpoints,
+    /// Skip the overlaps, only yielding the pairs meeting at a point.
+    Ignore,
 }
 
 impl<C> FromIterator<C> for Intersections<C>
@@ -216,6 +306,8 @@ where
             jdx: 0,
             is_overlap: false,
             pt: None,
+            collinear: CollinearReport::AsInterval,
+            pending: None,
         }
     }
 }
@@ -224,7 +316,47 @@ impl<C> Intersections<C>
 where
     C: Cross + Clone,
 {
-    fn intersection(&mut self) -> Option<(C, C, LineIntersection<C::Scalar>)> {
+    /// Report the overlaps of collinear segments as by `report`, instead
+    /// of as intervals.
+    ///
+    /// ```rust
+    /// use geo::sweep::{CollinearReport, Intersections};
+    /// use geo::{Coordinate, Line, LineIntersection};
+    /// use std::iter::FromIterator;
+    ///
+    /// let input = vec![
+    ///     Line::from([(0., 0.), (2., 0.)]),
+    ///     Line::from([(1., 0.), (3., 0.)]),
+    ///     Line::from([(1., -1.), (1., 1.)]),
+    /// ];
+    /// let crossings: Vec<_> = Intersections::from_iter(input)
+    ///     .with_collinear_report(CollinearReport::Ignore)
+    ///     .collect();
+    /// // Only the vertical line crossing the others.
+    /// assert_eq!(crossings.len(), 2);
+    /// assert!(crossings.iter().all(|(_, _, int)| matches!(
+    ///     int,
+    ///     LineIntersection::SinglePoint { intersection, .. }
+    ///         if *intersection == Coordinate { x: 1., y: 0. }
+    /// )));
+    /// ```
+    pub fn with_collinear_report(mut self, report: CollinearReport) -> Self {
+        self.collinear = report;
+        self
+    }
+
+    /// Counters accumulated by the underlying sweep so far. These are
+    /// complete once the iterator is exhausted.
+    pub fn statistics(&self) -> SweepStats {
+        self.inner.statistics()
+    }
+
+    /// The intersection of the current pair of segments, if it has not
+    /// been processed yet and the pair passes `filter`.
+    fn intersection<F>(&self, filter: &mut F) -> Option<(C, C, LineIntersection<C::Scalar>)>
+    where
+        F: FnMut(&C, &C) -> bool,
+    {
         let (si, sj) = {
             let segments = self.inner.intersections();
             (&segments[self.idx], &segments[self.jdx])
@@ -252,7 +384,7 @@ where
             (!si.at_left || si.first_segment) && (!sj.at_left || sj.first_segment)
         };
 
-        if should_compute {
+        if should_compute && filter(&si.cross, &sj.cross) {
             let si = si.cross.clone();
             let sj = sj.cross.clone();
 
@@ -299,19 +431,118 @@ where
     type Item = (C, C, LineIntersection<C::Scalar>);
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
         loop {
             if !self.step() {
                 return None;
             }
-            let it = self.intersection();
+            let it = self.intersection(&mut |_, _| true);
             debug!("\t{it:?}", it = it.is_some());
-            if let Some(result) = it {
-                return Some(result);
+            match it {
+                Some((a, b, LineIntersection::Collinear { intersection })) => {
+                    match self.collinear {
+                        CollinearReport::AsInterval => {
+                            return Some((a, b, LineIntersection::Collinear { intersection }));
+                        }
+                        CollinearReport::AsEndpoints => {
+                            let end = |intersection| LineIntersection::SinglePoint {
+                                intersection,
+                                is_proper: false,
+                            };
+                            self.pending = Some((a.clone(), b.clone(), end(intersection.end)));
+                            return Some((a, b, end(intersection.start)));
+                        }
+                        CollinearReport::Ignore => {}
+                    }
+                }
+                Some(result) => return Some(result),
+                None => {}
             }
         }
     }
 }
 
+/// Call `callback` on each intersection of a collection of lines whose
+/// pair of crossables passes `filter`.
+///
+/// This is [`Intersections`] restricted to the pairs for which `filter`
+/// returns `true`, e.g. the crossings between segments of different
+/// groups. The sweep still processes every segment, but the
+/// [`LineIntersection`] of the other pairs is never computed. The
+/// crossables may be passed to `filter` in either order.
+///
+/// ```rust
+/// use geo::Line;
+/// use geo::sweep::for_each_intersection;
+/// // Two lines in group 0, crossing each other and a line in group 1.
+/// let input = vec![
+///     (0, Line::from([(0., 0.), (2., 2.)])),
+///     (0, Line::from([(0., 2.), (2., 0.)])),
+///     (1, Line::from([(0., 1.5), (2., 1.5)])),
+/// ];
+/// let mut count = 0;
+/// for_each_intersection(input, |(a, _), (b, _)| a != b, |_, _, _| count += 1);
+/// assert_eq!(count, 2);
+/// ```
+pub fn for_each_intersection<C, I, F, G>(crosses: I, mut filter: F, mut callback: G)
+where
+    C: Cross + Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(&C, &C) -> bool,
+    G: FnMut(C, C, LineIntersection<C::Scalar>),
+{
+    let mut iter: Intersections<C> = crosses.into_iter().collect();
+    while iter.step() {
+        if let Some((a, b, int)) = iter.intersection(&mut filter) {
+            callback(a, b, int);
+        }
+    }
+}
+
+/// Call `callback` at each point of a sweep over a collection of lines,
+/// with the bottommost and topmost pieces of the lines crossing the
+/// sweep line just after it, if any.
+///
+/// The points are the end points and the intersections of the lines,
+/// from left to right, and the pieces are passed with the crossable
+/// they are part of. Together, the pieces trace the envelope of the
+/// input, e.g. the silhouette of overlapping shapes.
+///
+/// ```rust
+/// use geo::{Line, Rect};
+/// use geo::sweep::for_each_frontier;
+/// // A low and wide rectangle, and a narrow one standing above it.
+/// let low = Rect::new((0., 0.), (4., 1.));
+/// let high = Rect::new((1., 2.), (2., 5.));
+/// let input = low.to_lines().into_iter().chain(high.to_lines());
+///
+/// let mut tops = vec![];
+/// for_each_frontier(input, |pt, extent| {
+///     if let Some((_, (top, _))) = extent {
+///         tops.push((pt.x, top.end.y));
+///     }
+/// });
+/// assert_eq!(tops.first(), Some(&(0., 1.)));
+/// assert!(tops.contains(&(1., 5.)));
+/// assert_eq!(tops.last(), Some(&(4., 1.)));
+/// ```
+pub fn for_each_frontier<C, I, F>(crosses: I, mut callback: F)
+where
+    C: Cross + Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(Coordinate<C::Scalar>, Option<((Line<C::Scalar>, &C), (Line<C::Scalar>, &C))>),
+{
+    let mut iter: CrossingsIter<C> = crosses.into_iter().collect();
+    while let Some(pt) = iter.next() {
+        let extent = iter
+            .frontier_extent()
+            .map(|((bottom, a), (top, b))| ((bottom.line(), a), (top.line(), b)));
+        callback(pt, extent);
+    }
+}
+
 #[cfg(test)]
 pub(super) mod tests {
     use crate::Line;
@@ -379,6 +610,293 @@ pub(super) mod tests {
         assert_eq!(count, verify);
     }
 
+    #[test]
+    fn star_at_shared_vertex() {
+        init_log();
+
+        // Three segments crossing the origin, and two starting there.
+        let input = [
+            Line::from([(-1., -1.), (1., 1.)]),
+            [(-1., 1.), (1., -1.)].into(),
+            [(-1., 0.), (1., 0.)].into(),
+            [(0., 0.), (1., 0.5)].into(),
+            [(0., 0.), (1., -0.5)].into(),
+        ];
+
+        let mut iter: CrossingsIter<_> = input.iter().collect();
+        let mut at_origin = None;
+        while let Some(pt) = iter.next() {
+            if pt == Coordinate::zero() {
+                at_origin = Some(iter.intersections().len());
+            }
+        }
+        // The three crossing segments end there, and all five start.
+        assert_eq!(at_origin, Some(8));
+
+        let iter: Intersections<_> = input.iter().collect();
+        assert_eq!(iter.count(), 10);
+    }
+
+    #[test]
+    fn overlap_order() {
+        init_log();
+
+        let input: Vec<Line<f64>> = vec![
+            Line::from([(0., 0.), (3., 0.)]),
+            [(1., 0.), (2., 0.)].into(),
+            [(0., 0.), (2., 0.)].into(),
+        ];
+        let crossings = |input: &[Line<f64>]| {
+            let mut iter: CrossingsIter<_> = input.iter().copied().enumerate().collect();
+            let mut crossings = vec![];
+            while let Some(pt) = iter.next() {
+                for c in iter.intersections() {
+                    crossings.push((pt, c.cross.0, c.at_left, c.has_overlap));
+                }
+            }
+            crossings
+        };
+
+        let all = crossings(&input);
+        assert_eq!(all, crossings(&input));
+        let at = |x: f64, at_left: bool| -> Vec<_> {
+            all.iter()
+                .filter(|c| c.0 == Coordinate { x, y: 0. } && c.2 == at_left)
+                .map(|c| (c.1, c.3))
+                .collect()
+        };
+        // The three segments overlap from x = 1, and are passed in the
+        // order of the input.
+        assert_eq!(at(1., true), vec![(0, true), (1, true), (2, false)]);
+        assert_eq!(at(1., false), vec![(0, true), (2, false)]);
+        assert_eq!(at(2., false), vec![(0, true), (1, true), (2, false)]);
+
+        // The same holds for any order of the input.
+        let mut reversed = input;
+        reversed.reverse();
+        let all = crossings(&reversed);
+        for w in all.windows(2) {
+            if w[0].3 {
+                assert!(w[0].1 < w[1].1, "overlaps out of order: {all:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn crossing_lines_after_split() {
+        init_log();
+
+        // The longer segment is reported first, and then split at x = 1
+        // by its overlap with the shorter one, starting at the same point.
+        let long = Line::from([(0., 0.), (2., 0.)]);
+        let short = Line::from([(0., 0.), (1., 0.)]);
+        for input in [[long, short], [short, long]] {
+            let mut iter: CrossingsIter<_> = input.iter().copied().collect();
+            assert_eq!(iter.next(), Some(Coordinate::zero()));
+            let ends: Vec<_> = iter
+                .intersections()
+                .iter()
+                .map(|c| (*c.line.left(), *c.line.right()))
+                .collect();
+            assert_eq!(ends, vec![(short.start, short.end); 2]);
+        }
+    }
+
+    #[test]
+    fn indexed_lines() {
+        let input: Vec<Line<f64>> = vec![
+            Line::from([(0., 0.), (2., 2.)]),
+            [(0., 2.), (2., 0.)].into(),
+            [(0., 1.5), (2., 1.5)].into(),
+            [(3., 0.), (3., 1.)].into(),
+        ];
+        let mut pairs: Vec<_> = input
+            .into_iter()
+            .enumerate()
+            .collect::<Intersections<_>>()
+            .map(|((i, _), (j, _), _)| (i.min(j), i.max(j)))
+            .collect();
+        pairs.sort_unstable();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn filtered_intersections() {
+        // A grid of lines in two layers, with crossings within each layer.
+        let mut input = vec![];
+        for k in 0..4 {
+            let k = k as f64;
+            input.push((0, Line::from([(k, 0.), (k + 3., 5.)])));
+            input.push((0, Line::from([(k + 3., 0.), (k, 5.)])));
+            input.push((1, Line::from([(-1., k + 0.5), (8., k + 1.)])));
+            input.push((1, Line::from([(-1., k + 1.), (8., k)])));
+        }
+
+        let mut filtered = vec![];
+        for_each_intersection(
+            input.iter().copied(),
+            |(a, _), (b, _)| a != b,
+            |(a, l1), (b, l2), int| filtered.push((a, l1, b, l2, int)),
+        );
+        assert!(filtered.iter().all(|(a, _, b, _, _)| a != b));
+
+        let all: Vec<_> = input
+            .iter()
+            .copied()
+            .collect::<Intersections<_>>()
+            .collect();
+        let across: Vec<_> = all
+            .iter()
+            .filter(|((a, _), (b, _), _)| a != b)
+            .map(|&((a, l1), (b, l2), int)| (a, l1, b, l2, int))
+            .collect();
+        assert!(across.len() < all.len());
+        assert_eq!(filtered, across);
+    }
+
+    #[test]
+    fn frontier_extent() {
+        // Three stacked rectangles: `b` above `a`, and `c` below it.
+        let rects = [
+            Rect::new((0., 0.), (4., 1.)),
+            Rect::new((1., 2.), (3., 3.)),
+            Rect::new((2., -2.), (5., -1.)),
+        ];
+        let input = rects.iter().flat_map(|rect| rect.to_lines());
+        let mut extents = vec![];
+        for_each_frontier(input, |pt, extent| {
+            let extent = extent.map(|((bottom, _), (top, _))| (bottom, top));
+            extents.push((pt.x_y(), extent));
+        });
+        let at = |x: f64, y: f64| {
+            extents
+                .iter()
+                .find(|(pt, _)| *pt == (x, y))
+                .and_then(|(_, extent)| *extent)
+        };
+        let line = |a: (f64, f64), b: (f64, f64)| Line::from([a, b]);
+
+        assert_eq!(
+            at(0., 1.),
+            Some((line((0., 0.), (4., 0.)), line((0., 1.), (4., 1.))))
+        );
+        assert_eq!(
+            at(1., 3.),
+            Some((line((0., 0.), (4., 0.)), line((1., 3.), (3., 3.))))
+        );
+        assert_eq!(
+            at(2., -1.),
+            Some((line((2., -2.), (5., -2.)), line((1., 3.), (3., 3.))))
+        );
+        assert_eq!(
+            at(3., 3.),
+            Some((line((2., -2.), (5., -2.)), line((0., 1.), (4., 1.))))
+        );
+        assert_eq!(
+            at(4., 1.),
+            Some((line((2., -2.), (5., -2.)), line((2., -1.), (5., -1.))))
+        );
+        assert_eq!(at(5., -1.), None);
+    }
+
+    #[test]
+    fn collinear_report() {
+        let input = [
+            Line::from([(0., 0.), (2., 2.)]),
+            Line::from([(1., 1.), (3., 3.)]),
+        ];
+        let report = |mode| {
+            Intersections::from_iter(input)
+                .with_collinear_report(mode)
+                .map(|(_, _, int)| int)
+                .collect::<Vec<_>>()
+        };
+        let overlap = Line::from([(1., 1.), (2., 2.)]);
+        assert_eq!(
+            report(CollinearReport::AsInterval),
+            vec![LineIntersection::Collinear {
+                intersection: overlap
+            }]
+        );
+        let end = |intersection| LineIntersection::SinglePoint {
+            intersection,
+            is_proper: false,
+        };
+        assert_eq!(
+            report(CollinearReport::AsEndpoints),
+            vec![end(overlap.start), end(overlap.end)]
+        );
+        assert_eq!(report(CollinearReport::Ignore), vec![]);
+    }
+
+    #[test]
+    fn sweep_steps() {
+        // Three stacked rectangles, as for `frontier_extent`.
+        let rects = [
+            Rect::new((0., 0.), (4., 1.)),
+            Rect::new((1., 2.), (3., 3.)),
+            Rect::new((2., -2.), (5., -1.)),
+        ];
+        let input: Vec<_> = rects.iter().flat_map(|rect| rect.to_lines()).collect();
+        let mut steps: SweepSteps<_> = input.iter().copied().collect();
+        let all: Vec<_> = steps.by_ref().collect();
+
+        // Every event is a step, and the frontier is that of the last
+        // step at each point.
+        assert_eq!(all.len(), steps.statistics().events);
+        assert!(all.last().unwrap().active.is_empty());
+        let mut frontiers = vec![];
+        for_each_frontier(input, |pt, extent| {
+            let extent = extent.map(|((bottom, _), (top, _))| (bottom, top));
+            frontiers.push((pt, extent));
+        });
+        for (pt, extent) in frontiers {
+            let last = all.iter().rev().find(|step| step.point == pt).unwrap();
+            let active = (last.active.first().copied()).zip(last.active.last().copied());
+            assert_eq!(active, extent);
+        }
+
+        // After both sides of the lowest rectangle start at its corner.
+        let last = all
+            .iter()
+            .rev()
+            .find(|step| step.point == (2., -2.).into())
+            .unwrap();
+        assert_eq!(last.kind, TraceEventKind::LineStart);
+        let line = |a: (f64, f64), b: (f64, f64)| Line::from([a, b]);
+        assert_eq!(
+            last.active,
+            vec![
+                line((2., -2.), (5., -2.)),
+                line((2., -2.), (2., -1.)),
+                line((0., 0.), (4., 0.)),
+                line((0., 1.), (4., 1.)),
+                line((1., 2.), (3., 2.)),
+                line((1., 3.), (3., 3.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn statistics() {
+        let input = vec![
+            Line::from([(0., 0.), (1., 1.)]),
+            [(1., 0.), (0., 1.)].into(),
+            [(0., 0.5), (1., 0.5)].into(),
+        ];
+        let mut iter: Intersections<_> = input.into_iter().collect();
+        assert_eq!(iter.by_ref().count(), 3);
+
+        let stats = iter.statistics();
+        assert_eq!(stats.input_segments, 3);
+        assert_eq!(stats.max_active_segments, 3);
+        assert_eq!(stats.overlap_chains, 0);
+        assert!(stats.intersections >= 2);
+        // All three lines cross at (0.5, 0.5), and are split once each.
+        assert_eq!(stats.splits, 3);
+        assert_eq!(stats.events, 2 * (stats.input_segments + stats.splits));
+    }
+
     #[test]
     #[ignore]
     fn check_adhoc_crossings() {
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use super::SweepPoint;
 use crate::{
@@ -17,8 +17,8 @@ pub struct LineOrPoint<T: GeoNum> {
     right: SweepPoint<T>,
 }
 
-impl<T: GeoNum> std::fmt::Debug for LineOrPoint<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: GeoNum> core::fmt::Debug for LineOrPoint<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple(if self.is_line() { "LPt" } else { "Pt" })
             .field(&self.left.x_y())
             .field(&self.right.x_y())
@@ -93,7 +93,6 @@ impl<T: GeoNum> LineOrPoint<T> {
         self.right
     }
 
-    #[cfg(test)]
     pub fn coords_equal(&self, other: &LineOrPoint<T>) -> bool {
         self.is_line() == other.is_line() && self.end_points() == other.end_points()
     }
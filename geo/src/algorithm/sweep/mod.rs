@@ -1,5 +1,5 @@
 mod point;
-pub use point::SweepPoint;
+pub use point::{sweep_cmp, SweepPoint};
 
 mod events;
 pub(crate) use events::{Event, EventType};
@@ -22,6 +22,19 @@ use im_segment::IMSegment;
 mod proc;
 use proc::Sweep;
 
+mod stats;
+pub use stats::SweepStats;
+
+mod error;
+pub use error::Error;
+
+mod trace;
+use trace::EventTrace;
+pub use trace::{TraceEvent, TraceEventKind};
+
+mod steps;
+pub use steps::{SweepStep, SweepSteps};
+
 mod iter;
-pub use iter::Intersections;
+pub use iter::{for_each_frontier, for_each_intersection, CollinearReport, Intersections};
 pub(crate) use iter::{Crossing, CrossingsIter};
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, ops::Deref};
+use core::{cmp::Ordering, ops::Deref};
 
 use geo_types::Coordinate;
 
@@ -16,8 +16,8 @@ use crate::GeoNum;
 #[derive(PartialEq, Clone, Copy)]
 pub struct SweepPoint<T: GeoNum>(Coordinate<T>);
 
-impl<T: GeoNum> std::fmt::Debug for SweepPoint<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: GeoNum> core::fmt::Debug for SweepPoint<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPt")
             .field(&self.0.x)
             .field(&self.0.y)
@@ -46,6 +46,41 @@ impl<T: GeoNum> Ord for SweepPoint<T> {
 /// We derive `Eq` manually to not require `T: Eq`.
 impl<T: GeoNum> Eq for SweepPoint<T> {}
 
+/// Compare two coordinates in the order of the sweep: by `x`, and then
+/// by `y`.
+///
+/// This is the order of [`SweepPoint`]; sorting the input in this order
+/// beforehand matches the order the sweep processes it in.
+///
+/// # Panics
+///
+/// If a coordinate is `NaN`, as the sweep does.
+///
+/// # Examples
+///
+/// ```
+/// use geo::sweep::sweep_cmp;
+/// use geo::Coordinate;
+///
+/// let mut coords = vec![
+///     Coordinate { x: 1., y: 0. },
+///     Coordinate { x: 0., y: 1. },
+///     Coordinate { x: 0., y: 0. },
+/// ];
+/// coords.sort_by(|a, b| sweep_cmp(*a, *b));
+/// assert_eq!(
+///     coords,
+///     [
+///         Coordinate { x: 0., y: 0. },
+///         Coordinate { x: 0., y: 1. },
+///         Coordinate { x: 1., y: 0. },
+///     ]
+/// );
+/// ```
+pub fn sweep_cmp<T: GeoNum>(a: Coordinate<T>, b: Coordinate<T>) -> Ordering {
+    SweepPoint(a).cmp(&SweepPoint(b))
+}
+
 /// Conversion from type that can be converted to a `Coordinate`.
 impl<T: GeoNum, X: Into<Coordinate<T>>> From<X> for SweepPoint<T> {
     fn from(pt: X) -> Self {
@@ -84,4 +119,35 @@ mod tests {
         assert!(p2 < p3);
         assert!(p3 <= p4);
     }
+
+    #[test]
+    fn test_sweep_cmp() {
+        let sorted: Vec<_> = (0..5)
+            .flat_map(|x| (0..5).map(move |y| Coordinate { x, y }))
+            .map(|c| Coordinate {
+                x: c.x as f64 - 2.,
+                y: c.y as f64 * 0.5,
+            })
+            .collect();
+        // A fixed permutation, as 7 is coprime to 25.
+        let mut coords: Vec<_> = (0..sorted.len())
+            .map(|i| sorted[i * 7 % sorted.len()])
+            .collect();
+        assert_ne!(coords, sorted);
+
+        coords.sort_by(|a, b| sweep_cmp(*a, *b));
+        assert_eq!(coords, sorted);
+        assert!(coords
+            .windows(2)
+            .all(|w| SweepPoint::from(w[0]) < SweepPoint::from(w[1])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sweep_cmp_nan() {
+        sweep_cmp(
+            Coordinate { x: f64::NAN, y: 0. },
+            Coordinate { x: 0., y: 0. },
+        );
+    }
 }
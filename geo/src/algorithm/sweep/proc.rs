@@ -1,32 +1,58 @@
-use std::{
-    borrow::Borrow,
+use alloc::{
     collections::{BTreeSet, BinaryHeap},
+    vec,
+    vec::Vec,
 };
+use core::borrow::Borrow;
 
 use super::*;
+use crate::{Coordinate, GeoFloat, Kernel, Orientation, Rect};
 
 pub(crate) struct Sweep<C: Cross> {
     events: BinaryHeap<Event<C::Scalar, IMSegment<C>>>,
     active_segments: BTreeSet<Active<IMSegment<C>>>,
+    max_active: usize,
+    region: Option<Rect<C::Scalar>>,
+    noded: bool,
+    error: Option<Error>,
+    stats: SweepStats,
+    trace: Option<EventTrace<C::Scalar>>,
 }
 
 impl<C: Cross + Clone> Sweep<C> {
     pub(crate) fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Self::with_capacity(iter, 0)
+    }
+
+    /// Create a sweep of the segments of `iter`, with an event queue
+    /// sized for at least `segment_count` segments.
+    pub(crate) fn with_capacity<I>(iter: I, segment_count: usize) -> Self
     where
         I: IntoIterator<Item = C>,
     {
         let iter = iter.into_iter();
         let size = {
             let (min_size, max_size) = iter.size_hint();
-            max_size.unwrap_or(min_size)
+            max_size.unwrap_or(min_size).max(segment_count)
         };
 
         let mut sweep = Sweep {
-            events: BinaryHeap::with_capacity(size),
+            // Each segment starts with an event at each of its ends.
+            events: BinaryHeap::with_capacity(size.saturating_mul(2)),
             active_segments: Default::default(),
+            max_active: usize::MAX,
+            region: None,
+            noded: false,
+            error: None,
+            stats: Default::default(),
+            trace: None,
         };
-        for cr in iter {
-            IMSegment::create_segment(cr, None, None, |ev| sweep.events.push(ev));
+        for (idx, cr) in iter.enumerate() {
+            IMSegment::create_segment(cr, idx, None, None, |ev| sweep.events.push(ev));
+            sweep.stats.input_segments += 1;
         }
 
         sweep
@@ -34,11 +60,14 @@ impl<C: Cross + Clone> Sweep<C> {
 
     /// Process the next event in heap.
     ///
-    /// Calls the callback unless the event is spurious.
+    /// Calls the callback unless the event is spurious, with a flag that
+    /// is `true` if the next segment it is called with overlaps this
+    /// one. Overlapping segments are passed in a row, in the order of
+    /// the inputs they are part of.
     #[inline]
     pub(super) fn next_event<F>(&mut self, mut cb: F) -> Option<SweepPoint<C::Scalar>>
     where
-        F: for<'a> FnMut(&'a IMSegment<C>, EventType),
+        F: for<'a> FnMut(&'a IMSegment<C>, EventType, bool),
     {
         self.events.pop().map(|event| {
             let pt = event.point;
@@ -50,13 +79,21 @@ impl<C: Cross + Clone> Sweep<C> {
 
     fn handle_event<F>(&mut self, event: Event<C::Scalar, IMSegment<C>>, cb: &mut F) -> bool
     where
-        F: for<'a> FnMut(&'a IMSegment<C>, EventType),
+        F: for<'a> FnMut(&'a IMSegment<C>, EventType, bool),
     {
         use EventType::*;
         let segment = match IMSegment::is_correct(&event) {
             false => return false,
             _ => event.payload,
         };
+        self.stats.events += 1;
+        if let Some(trace) = &mut self.trace {
+            trace.record(TraceEvent {
+                point: *event.point,
+                kind: event.ty.into(),
+                segment: segment.input_idx(),
+            });
+        }
         trace!(
             "handling event: {pt:?} ({ty:?}) @ {seg:?}",
             pt = event.point,
@@ -71,34 +108,46 @@ impl<C: Cross + Clone> Sweep<C> {
             LineLeft => {
                 let mut should_add = true;
                 for adj_segment in prev.into_iter().chain(next.into_iter()) {
+                    if !self.may_intersect(&segment, &adj_segment) {
+                        continue;
+                    }
                     if let Some(adj_intersection) =
-                        segment.geom().intersect_line_ordered(&adj_segment.geom())
+                        self.intersect(&segment, &adj_segment, event.point)
                     {
                         trace!("Found intersection (LL):\n\tsegment1: {:?}\n\tsegment2: {:?}\n\tintersection: {:?}", segment, adj_segment, adj_intersection);
+                        self.stats.intersections += 1;
                         // 1. Split adj_segment, and extra splits to storage
                         let adj_overlap = adj_segment
-                            .adjust_one_segment(adj_intersection, |e| self.events.push(e));
+                            .adjust_one_segment(adj_intersection, |e| self.push_split(e));
 
                         // A special case is if adj_segment was split, and the
                         // intersection is at the start of this segment. In this
                         // case, there is an right-end event in the heap, that
                         // needs to be handled before finishing up this event.
-                        let handle_end_event = {
-                            // Get first point of intersection
-                            let int_pt = adj_intersection.left();
-                            // Check its not first point of the adjusted, but is
-                            // first point of current segment
-                            int_pt != adj_segment.geom().left() && int_pt == segment.geom().left()
-                        };
+                        // Get first point of intersection
+                        let int_pt = adj_intersection.left();
+                        // Check its not first point of the adjusted, but is
+                        // first point of current segment
+                        let handle_end_event =
+                            int_pt != adj_segment.geom().left() && int_pt == segment.geom().left();
                         if handle_end_event {
-                            let event = self.events.pop().unwrap();
-                            let done = self.handle_event(event, cb);
+                            // Several segments may meet at this point, so
+                            // drain all the events ordered before this one
+                            // there; some of them may be spurious.
+                            let mut done = false;
+                            while matches!(
+                                self.events.peek(),
+                                Some(e) if e.point == int_pt && e.ty < LineLeft
+                            ) {
+                                let event = self.events.pop().unwrap();
+                                done |= self.handle_event(event, cb);
+                            }
                             debug_assert!(done, "special right-end event handling failed")
                         }
 
                         // 2. Split segment, adding extra segments as needed.
                         let seg_overlap_key =
-                            segment.adjust_one_segment(adj_intersection, |e| self.events.push(e));
+                            segment.adjust_one_segment(adj_intersection, |e| self.push_split(e));
 
                         assert_eq!(
                             adj_overlap.is_some(),
@@ -109,6 +158,7 @@ impl<C: Cross + Clone> Sweep<C> {
                             let tgt = seg_overlap_key.unwrap();
                             trace!("setting overlap: {adj_ovl:?} -> {tgt:?}");
                             adj_ovl.chain_overlap(tgt.clone());
+                            self.stats.overlap_chains += 1;
 
                             if tgt == segment {
                                 // The whole event segment is now overlapping
@@ -131,14 +181,25 @@ impl<C: Cross + Clone> Sweep<C> {
                     // Add current segment as active
                     // Safety: `self.segments` is a `Box` that is not
                     // de-allocated until `self` is dropped.
-                    self.active_segments.insert_active(segment.clone());
+                    if let Err(err) = self
+                        .active_segments
+                        .insert_active(segment.clone(), self.max_active)
+                    {
+                        // Abort the sweep: drop the remaining events.
+                        self.error = Some(err);
+                        self.events.clear();
+                        return true;
+                    }
+                    self.stats.max_active_segments = self
+                        .stats
+                        .max_active_segments
+                        .max(self.active_segments.len());
                 }
 
-                let mut cb_seg = Some(segment);
-                while let Some(seg) = cb_seg {
-                    cb(&seg, event.ty);
+                let chain = overlap_chain(segment);
+                for (idx, seg) in chain.iter().enumerate() {
+                    cb(seg, event.ty, idx + 1 < chain.len());
                     seg.set_left_event_done();
-                    cb_seg = seg.overlapping().cloned();
                 }
             }
             LineRight => {
@@ -146,22 +207,23 @@ impl<C: Cross + Clone> Sweep<C> {
                 // de-allocated until `self` is dropped.
                 self.active_segments.remove_active(&segment);
 
-                let mut cb_seg = Some(segment);
-                while let Some(seg) = cb_seg {
-                    cb(&seg, event.ty);
-                    cb_seg = seg.overlapping().cloned();
+                let chain = overlap_chain(segment);
+                for (idx, seg) in chain.iter().enumerate() {
+                    cb(seg, event.ty, idx + 1 < chain.len());
                 }
 
                 if let (Some(prev), Some(next)) = (prev, next) {
-                    let prev_geom = prev.geom();
-                    let next_geom = next.geom();
-                    if let Some(adj_intersection) = prev_geom.intersect_line_ordered(&next_geom) {
+                    if !self.may_intersect(&prev, &next) {
+                        return true;
+                    }
+                    if let Some(adj_intersection) = self.intersect(&prev, &next, event.point) {
+                        self.stats.intersections += 1;
                         // 1. Split prev_segment, and extra splits to storage
                         let first = prev
-                            .adjust_one_segment(adj_intersection, |e| self.events.push(e))
+                            .adjust_one_segment(adj_intersection, |e| self.push_split(e))
                             .is_none();
                         let second = next
-                            .adjust_one_segment(adj_intersection, |e| self.events.push(e))
+                            .adjust_one_segment(adj_intersection, |e| self.push_split(e))
                             .is_none();
                         debug_assert!(
                             first && second,
@@ -172,12 +234,17 @@ impl<C: Cross + Clone> Sweep<C> {
             }
             PointLeft => {
                 for adj_segment in prev.into_iter().chain(next.into_iter()) {
-                    let geom = adj_segment.geom();
-                    if let Some(adj_intersection) = segment.geom().intersect_line_ordered(&geom) {
+                    if !self.may_intersect(&segment, &adj_segment) {
+                        continue;
+                    }
+                    if let Some(adj_intersection) =
+                        self.intersect(&segment, &adj_segment, event.point)
+                    {
                         trace!("Found intersection:\n\tsegment1: {:?}\n\tsegment2: {:?}\n\tintersection: {:?}", segment, adj_segment, adj_intersection);
+                        self.stats.intersections += 1;
                         // 1. Split adj_segment, and extra splits to storage
                         let adj_overlap = adj_segment
-                            .adjust_one_segment(adj_intersection, |e| self.events.push(e));
+                            .adjust_one_segment(adj_intersection, |e| self.push_split(e));
 
                         // Can't have overlap with a point
                         debug_assert!(adj_overlap.is_none());
@@ -186,7 +253,7 @@ impl<C: Cross + Clone> Sweep<C> {
 
                 // Points need not be active segments.
                 // Send the point-segment to callback.
-                cb(&segment, event.ty);
+                cb(&segment, event.ty, false);
             }
             PointRight => {
                 // Nothing to do. We could remove this variant once we
@@ -196,10 +263,142 @@ impl<C: Cross + Clone> Sweep<C> {
         true
     }
 
+    /// Push an event generated while splitting a segment.
+    ///
+    /// Every new segment generates exactly one left event, which is
+    /// counted as a split.
+    #[inline]
+    fn push_split(&mut self, event: Event<C::Scalar, IMSegment<C>>) {
+        if matches!(event.ty, EventType::LineLeft | EventType::PointLeft) {
+            self.stats.splits += 1;
+        }
+        self.events.push(event);
+    }
+
+    /// Set the maximum number of active segments. The sweep stops with
+    /// an error once it is exceeded.
+    pub(super) fn set_max_active(&mut self, max_active: usize) {
+        self.max_active = max_active;
+    }
+
+    /// Only check for intersections within `region`: the input is
+    /// assumed not to intersect outside it, except at end points.
+    pub(super) fn set_region(&mut self, region: Rect<C::Scalar>) {
+        self.region = Some(region);
+    }
+
+    /// Assume the input is noded: its segments only meet at end points,
+    /// or overlap as a whole.
+    pub(super) fn set_noded(&mut self, noded: bool) {
+        self.noded = noded;
+    }
+
+    /// Whether the segments may intersect within the region set, if any.
+    /// Counts the skipped checks.
+    fn may_intersect(&mut self, a: &IMSegment<C>, b: &IMSegment<C>) -> bool {
+        if self.noded {
+            // Only the overlaps of identical segments are left to chain.
+            let may = a.geom().end_points() == b.geom().end_points();
+            if !may {
+                self.stats.skipped_checks += 1;
+            }
+            return may;
+        }
+        let region = match self.region {
+            Some(region) => region,
+            None => return true,
+        };
+        let bounds = |seg: &IMSegment<C>| {
+            let (left, right) = seg.geom().end_points();
+            Rect::new(*left, *right)
+        };
+        let may = bounds(a)
+            .intersection(&bounds(b))
+            .and_then(|rect| rect.intersection(&region))
+            .is_some();
+        if !may {
+            self.stats.skipped_checks += 1;
+        }
+        may
+    }
+
+    /// The intersection of two segments, with a point in the interior
+    /// of both rounded toward their [`rounding_side`](Cross::rounding_side),
+    /// but not before the current event point `min`.
+    fn intersect(
+        &self,
+        a: &IMSegment<C>,
+        b: &IMSegment<C>,
+        min: SweepPoint<C::Scalar>,
+    ) -> Option<LineOrPoint<C::Scalar>> {
+        let intersection = a.geom().intersect_line_ordered(&b.geom())?;
+        if intersection.is_line() {
+            return Some(intersection);
+        }
+        let lines = [
+            (a.geom(), a.cross().rounding_side()),
+            (b.geom(), b.cross().rounding_side()),
+        ];
+        Some(round_toward(intersection.left(), lines, min).into())
+    }
+
+    /// Record the last `len` events processed.
+    pub(super) fn set_trace_len(&mut self, len: usize) {
+        self.trace = Some(EventTrace::new(len));
+    }
+
+    /// The recorded events, oldest first.
+    pub(super) fn trace(&self) -> Vec<TraceEvent<C::Scalar>> {
+        self.trace
+            .as_ref()
+            .map_or_else(Vec::new, |trace| trace.events())
+    }
+
+    /// The error that stopped the sweep, if any.
+    #[inline]
+    pub(super) fn error(&self) -> Option<Error> {
+        self.error
+    }
+
+    /// Counters accumulated so far.
+    #[inline]
+    pub(super) fn stats(&self) -> SweepStats {
+        self.stats
+    }
+
+    /// The bottommost and topmost active segments: the first and last by
+    /// the order of the active set, if any segment is active.
+    pub(super) fn frontier_extent(&self) -> Option<(&Segment<C>, &Segment<C>)> {
+        let mut active = self.active_segments.iter();
+        let bottom: &IMSegment<_> = active.next()?.borrow();
+        let top: &IMSegment<_> = active.next_back().map_or(bottom, |aseg| aseg.borrow());
+        Some((bottom.borrow(), top.borrow()))
+    }
+
+    /// The active segments, from the bottom to the top.
+    pub(super) fn active_segments(&self) -> impl Iterator<Item = &Segment<C>> {
+        self.active_segments.iter().map(|aseg| {
+            let im: &IMSegment<_> = aseg.borrow();
+            im.borrow()
+        })
+    }
+
+    /// The type of the next event in the heap, which may be spurious.
+    #[inline]
+    pub(super) fn peek_event_type(&self) -> Option<EventType> {
+        self.events.peek().map(|e| e.ty)
+    }
+
     #[inline]
     pub(super) fn prev_active(&self, c: &Crossing<C>) -> Option<&Segment<C>> {
         debug_assert!(c.at_left);
-        self.active_segments.previous(&c.segment).map(|aseg| {
+        let mut prev = self.active_segments.previous(&c.segment);
+        // The segment may be overlapping the active one, in which case
+        // that one is found first.
+        if let Some(aseg) = prev.filter(|aseg| aseg.geom().coords_equal(&c.line)) {
+            prev = self.active_segments.previous(aseg);
+        }
+        prev.map(|aseg| {
             let im: &IMSegment<_> = aseg.borrow();
             im.borrow()
         })
@@ -210,3 +409,86 @@ impl<C: Cross + Clone> Sweep<C> {
         self.events.peek().map(|e| e.point)
     }
 }
+
+/// The segments overlapping along `segment`, in the order of the inputs
+/// they are part of.
+fn overlap_chain<C: Cross>(segment: IMSegment<C>) -> Vec<IMSegment<C>> {
+    let mut chain = vec![];
+    let mut next = Some(segment);
+    while let Some(seg) = next {
+        next = seg.overlapping().cloned();
+        chain.push(seg);
+    }
+    chain.sort_by_key(|seg| seg.input_idx());
+    chain
+}
+
+/// The maximum number of units in the last place by which
+/// [`round_toward`] moves a coordinate.
+const MAX_ROUNDING_ULPS: usize = 8;
+
+/// Move `pt`, a point in the interior of both `lines`, strictly to the
+/// requested side of each line, by the least number of units in the
+/// last place in each coordinate, and not before `min`. Returns `pt` if
+/// no side is requested, or no such point is found.
+fn round_toward<T: GeoFloat>(
+    pt: SweepPoint<T>,
+    lines: [(LineOrPoint<T>, Option<Orientation>); 2],
+    min: SweepPoint<T>,
+) -> SweepPoint<T> {
+    let is_interior = |line: &LineOrPoint<T>, pt: SweepPoint<T>| {
+        line.is_line() && line.left() < pt && pt < line.right()
+    };
+    let has_side = |side: &Option<Orientation>| {
+        matches!(
+            side,
+            Some(Orientation::CounterClockwise | Orientation::Clockwise)
+        )
+    };
+    if !lines.iter().any(|(_, side)| has_side(side))
+        || !lines.iter().all(|(line, _)| is_interior(line, pt))
+    {
+        return pt;
+    }
+    let is_on_sides = |pt: SweepPoint<T>| {
+        pt >= min
+            && lines.iter().all(|(line, side)| {
+                is_interior(line, pt)
+                    && (!has_side(side)
+                        || Some(T::Ker::orient2d(*line.left(), *line.right(), *pt)) == *side)
+            })
+    };
+    if is_on_sides(pt) {
+        return pt;
+    }
+
+    // The coordinates up to `MAX_ROUNDING_ULPS` away, in increasing order.
+    let steps = |v: T| {
+        let mut steps = [v; 2 * MAX_ROUNDING_ULPS + 1];
+        for k in 1..=MAX_ROUNDING_ULPS {
+            steps[MAX_ROUNDING_ULPS + k] =
+                steps[MAX_ROUNDING_ULPS + k - 1].next_after(T::infinity());
+            steps[MAX_ROUNDING_ULPS - k] =
+                steps[MAX_ROUNDING_ULPS - k + 1].next_after(T::neg_infinity());
+        }
+        steps
+    };
+    let (xs, ys) = (steps(pt.x), steps(pt.y));
+    let distance = |i: usize| i.max(MAX_ROUNDING_ULPS) - i.min(MAX_ROUNDING_ULPS);
+    // Search the squares of candidates around `pt`, nearest first.
+    for k in 1..=MAX_ROUNDING_ULPS {
+        let range = MAX_ROUNDING_ULPS - k..=MAX_ROUNDING_ULPS + k;
+        for i in range.clone() {
+            for j in range.clone() {
+                if distance(i).max(distance(j)) != k {
+                    continue;
+                }
+                let candidate: SweepPoint<T> = Coordinate { x: xs[i], y: ys[j] }.into();
+                if is_on_sides(candidate) {
+                    return candidate;
+                }
+            }
+        }
+    }
+    pt
+}
@@ -1,12 +1,14 @@
 use super::*;
 use crate::GeoFloat;
-use std::{cmp::Ordering, fmt::Debug};
+use core::{cmp::Ordering, fmt::Debug};
 
 /// A segment of input [`LineOrPoint`] generated during the sweep.
 #[derive(Clone)]
 pub(super) struct Segment<C: Cross> {
     pub(super) geom: LineOrPoint<C::Scalar>,
     pub(super) cross: C,
+    /// The index of the input this is a part of.
+    pub(super) input_idx: usize,
     pub(super) first_segment: bool,
     pub(super) left_event_done: bool,
     pub(super) overlapping: Option<IMSegment<C>>,
@@ -14,12 +16,13 @@ pub(super) struct Segment<C: Cross> {
 }
 
 impl<C: Cross> Segment<C> {
-    pub fn new(cross: C, geom: Option<LineOrPoint<C::Scalar>>) -> Self {
+    pub fn new(cross: C, input_idx: usize, geom: Option<LineOrPoint<C::Scalar>>) -> Self {
         let first_segment = geom.is_none();
         let geom = geom.unwrap_or_else(|| cross.line());
         Self {
             geom,
             cross,
+            input_idx,
             first_segment,
             left_event_done: false,
             overlapping: None,
@@ -103,7 +106,7 @@ impl<C: Cross> Segment<C> {
 
 /// A more concise debug impl.
 impl<C: Cross> Debug for Segment<C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Segment{{ {geom:?}\n\tof {c:?}\n\t{first} [{has}/{ovl}] }}",
@@ -195,7 +198,8 @@ mod tests {
             ((10., 10.).into(), (5., 5.).into()).into(),
         ]
         .into_iter()
-        .map(|lp| Segment::new(lp, None))
+        .enumerate()
+        .map(|(idx, lp)| Segment::new(lp, idx, None))
         .collect();
 
         struct TestCase {
@@ -0,0 +1,27 @@
+/// Counters accumulated while running a planar sweep.
+///
+/// These are cheap to maintain, and help identify pathological inputs:
+/// for instance, a large `max_active_segments` relative to
+/// `input_segments` means the sweep-line crosses many segments at once,
+/// and each event becomes correspondingly expensive.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SweepStats {
+    /// Number of segments (lines or points) provided as input.
+    pub input_segments: usize,
+    /// Number of new segments created by splitting a segment at an
+    /// intersection.
+    pub splits: usize,
+    /// Number of intersections detected between adjacent segments.
+    pub intersections: usize,
+    /// Number of times a segment was chained as overlapping another.
+    pub overlap_chains: usize,
+    /// Largest number of segments simultaneously active.
+    pub max_active_segments: usize,
+    /// Number of events processed; spurious events are not counted.
+    pub events: usize,
+    /// Number of intersection checks between adjacent segments skipped,
+    /// as they could only intersect outside the region set by
+    /// [`Op::with_intersection_region`](crate::bool_ops::Op::with_intersection_region),
+    /// or not at all by [`Op::assume_noded`](crate::bool_ops::Op::assume_noded).
+    pub skipped_checks: usize,
+}
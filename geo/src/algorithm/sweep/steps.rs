@@ -0,0 +1,99 @@
+use alloc::vec::Vec;
+
+use super::*;
+use crate::{Coordinate, GeoNum, Line};
+
+/// A snapshot of the sweep, taken after an event was processed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepStep<T: GeoNum> {
+    /// The position of the sweep-line.
+    pub point: Coordinate<T>,
+    /// The kind of the event processed.
+    pub kind: TraceEventKind,
+    /// The segments crossing the sweep-line just after `point`, from the
+    /// bottom to the top.
+    ///
+    /// Only one of a set of overlapping segments is active. The segments
+    /// are split lazily, so an active segment may still be split at an
+    /// intersection further right.
+    pub active: Vec<Line<T>>,
+}
+
+/// Drive a sweep over a collection of lines one event at a time, and
+/// observe its state after each, e.g. to render the sweep as an
+/// animation.
+///
+/// Construct it by `collect`-ing an iterator of [`Cross`], and call
+/// [`step`](SweepSteps::step), or iterate over it, until the sweep is
+/// done. This runs the same sweep as [`Intersections`], and only adds
+/// the cost of copying the active segments at each step.
+///
+/// ```rust
+/// use geo::Line;
+/// use geo::sweep::{SweepSteps, TraceEventKind};
+///
+/// // Two crossing lines.
+/// let input = vec![
+///     Line::from([(0., 0.), (2., 2.)]),
+///     Line::from([(0., 2.), (2., 0.)]),
+/// ];
+/// let mut steps: SweepSteps<_> = input.into_iter().collect();
+///
+/// let first = steps.step().unwrap();
+/// assert_eq!(first.point, (0., 0.).into());
+/// assert_eq!(first.kind, TraceEventKind::LineStart);
+/// assert_eq!(first.active, vec![Line::from([(0., 0.), (2., 2.)])]);
+///
+/// // Each line is split at the crossing, in two pieces of one start
+/// // and one end each.
+/// assert_eq!(steps.count(), 7);
+/// ```
+pub struct SweepSteps<C: Cross + Clone> {
+    sweep: Sweep<C>,
+}
+
+impl<C: Cross + Clone> SweepSteps<C> {
+    /// Process the next event, and return the state of the sweep after
+    /// it, or `None` once all the events are processed.
+    pub fn step(&mut self) -> Option<SweepStep<C::Scalar>> {
+        loop {
+            let ty = self.sweep.peek_event_type()?;
+            let events = self.sweep.stats().events;
+            let point = self.sweep.next_event(|_, _, _| {})?;
+            // Spurious events are skipped, and not counted.
+            if self.sweep.stats().events == events {
+                continue;
+            }
+            return Some(SweepStep {
+                point: *point,
+                kind: ty.into(),
+                active: self
+                    .sweep
+                    .active_segments()
+                    .map(|seg| seg.geom.line())
+                    .collect(),
+            });
+        }
+    }
+
+    /// Counters accumulated by the sweep so far.
+    pub fn statistics(&self) -> SweepStats {
+        self.sweep.stats()
+    }
+}
+
+impl<C: Cross + Clone> FromIterator<C> for SweepSteps<C> {
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+        SweepSteps {
+            sweep: Sweep::new(iter),
+        }
+    }
+}
+
+impl<C: Cross + Clone> Iterator for SweepSteps<C> {
+    type Item = SweepStep<C::Scalar>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use super::EventType;
+use crate::{Coordinate, GeoNum};
+
+/// The kind of an event processed by the sweep.
+///
+/// A segment is started at its left (lexicographically smallest) end,
+/// and ended at its right end. Points are treated as infinitesimal
+/// vertical segments, started before and ended after the lines at the
+/// same position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraceEventKind {
+    PointStart,
+    LineEnd,
+    LineStart,
+    PointEnd,
+}
+
+impl From<EventType> for TraceEventKind {
+    fn from(ty: EventType) -> Self {
+        match ty {
+            EventType::PointLeft => TraceEventKind::PointStart,
+            EventType::LineRight => TraceEventKind::LineEnd,
+            EventType::LineLeft => TraceEventKind::LineStart,
+            EventType::PointRight => TraceEventKind::PointEnd,
+        }
+    }
+}
+
+/// An event processed by the sweep, as recorded for diagnosis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceEvent<T: GeoNum> {
+    /// The position of the sweep-line.
+    pub point: Coordinate<T>,
+    pub kind: TraceEventKind,
+    /// The index of the input segment the event is for; the pieces
+    /// split from a segment keep its index.
+    pub segment: usize,
+}
+
+/// A bounded buffer of the last events processed by the sweep.
+#[derive(Debug, Clone)]
+pub(super) struct EventTrace<T: GeoNum> {
+    events: VecDeque<TraceEvent<T>>,
+    capacity: usize,
+}
+
+impl<T: GeoNum> EventTrace<T> {
+    pub(super) fn new(capacity: usize) -> Self {
+        EventTrace {
+            // The buffer grows as needed: a large `capacity` is only a
+            // bound, and need not be allocated up front.
+            events: VecDeque::with_capacity(capacity.min(1024)),
+            capacity,
+        }
+    }
+
+    pub(super) fn record(&mut self, event: TraceEvent<T>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// The recorded events, oldest first.
+    pub(super) fn events(&self) -> Vec<TraceEvent<T>> {
+        self.events.iter().copied().collect()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{Coordinate, GeoNum, LineString, MultiPolygon, Polygon};
+
+/// The number of times the boundary of a geometry winds around a
+/// coordinate, counter-clockwise.
+///
+/// Unlike [`Contains`](crate::Contains), this tells apart the regions
+/// covered several times by self-overlapping input. The edges are
+/// classified by the robust orientation predicate, so the result is
+/// exact.
+///
+/// For a `LineString`, the ring is taken as oriented: a clockwise ring
+/// winds `-1` times around its inside. For a `Polygon` or a
+/// `MultiPolygon`, the rings are oriented as by the boolean operations,
+/// the exteriors counter-clockwise and the interiors clockwise, whatever
+/// their orientation in the input. The winding number is then positive
+/// inside the exteriors, decreased by the holes, and negative in the
+/// holes not covered by an exterior.
+///
+/// The winding number of a coordinate on the boundary is that of one of
+/// the regions it bounds.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, WindingNumber};
+///
+/// // A pentagram, covering its center twice.
+/// let star = polygon![
+///     (x: 0., y: 10.),
+///     (x: -5.9, y: -8.1),
+///     (x: 9.5, y: 3.1),
+///     (x: -9.5, y: 3.1),
+///     (x: 5.9, y: -8.1),
+/// ];
+///
+/// assert_eq!(star.winding_number(coord! { x: 0., y: 0. }), 2);
+/// assert_eq!(star.winding_number(coord! { x: 0., y: 8. }), 1);
+/// assert_eq!(star.winding_number(coord! { x: 0., y: -9. }), 0);
+/// ```
+pub trait WindingNumber<T: GeoNum> {
+    fn winding_number(&self, coord: Coordinate<T>) -> i32;
+}
+
+impl<T: GeoNum> WindingNumber<T> for LineString<T> {
+    fn winding_number(&self, coord: Coordinate<T>) -> i32 {
+        self.lines().fold(0, |winding, line| {
+            // Count the upward edges with `coord` on their left, and the
+            // downward edges with `coord` on their right, each including
+            // its lower end.
+            let orientation = || T::Ker::orient2d(line.start, line.end, coord);
+            if line.start.y <= coord.y {
+                if line.end.y > coord.y && orientation() == Orientation::CounterClockwise {
+                    return winding + 1;
+                }
+            } else if line.end.y <= coord.y && orientation() == Orientation::Clockwise {
+                return winding - 1;
+            }
+            winding
+        })
+    }
+}
+
+/// The winding number around `coord` of `ring` oriented as `interior_left`.
+fn oriented_winding_number<T: GeoNum>(
+    ring: &LineString<T>,
+    interior_left: WindingOrder,
+    coord: Coordinate<T>,
+) -> i32 {
+    match ring.winding_order() {
+        Some(winding) if winding == interior_left => ring.winding_number(coord),
+        Some(_) => -ring.winding_number(coord),
+        None => 0,
+    }
+}
+
+impl<T: GeoNum> WindingNumber<T> for Polygon<T> {
+    fn winding_number(&self, coord: Coordinate<T>) -> i32 {
+        self.interiors().iter().fold(
+            oriented_winding_number(self.exterior(), WindingOrder::CounterClockwise, coord),
+            |winding, hole| winding + oriented_winding_number(hole, WindingOrder::Clockwise, coord),
+        )
+    }
+}
+
+impl<T: GeoNum> WindingNumber<T> for MultiPolygon<T> {
+    fn winding_number(&self, coord: Coordinate<T>) -> i32 {
+        self.iter().map(|poly| poly.winding_number(coord)).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon};
+
+    #[test]
+    fn doubly_wound() {
+        let twice = line_string![
+            (x: 0, y: 0),
+            (x: 4, y: 0),
+            (x: 4, y: 4),
+            (x: 0, y: 4),
+            (x: 0, y: 0),
+            (x: 4, y: 0),
+            (x: 4, y: 4),
+            (x: 0, y: 4),
+            (x: 0, y: 0),
+        ];
+        assert_eq!(twice.winding_number(coord! { x: 2, y: 2 }), 2);
+        assert_eq!(twice.winding_number(coord! { x: 5, y: 2 }), 0);
+
+        // As oriented for a line string, but not for a polygon.
+        let mut reversed = twice.clone();
+        reversed.0.reverse();
+        assert_eq!(reversed.winding_number(coord! { x: 2, y: 2 }), -2);
+        let poly = Polygon::new(reversed, vec![]);
+        assert_eq!(poly.winding_number(coord! { x: 2, y: 2 }), 2);
+    }
+
+    #[test]
+    fn holes() {
+        // A square with a hole partly outside it, and a clockwise exterior.
+        let poly = polygon![
+            exterior: [(x: 0, y: 0), (x: 0, y: 6), (x: 6, y: 6), (x: 6, y: 0)],
+            interiors: [[(x: 4, y: 2), (x: 8, y: 2), (x: 8, y: 4), (x: 4, y: 4)]],
+        ];
+        assert_eq!(poly.winding_number(coord! { x: 1, y: 1 }), 1);
+        assert_eq!(poly.winding_number(coord! { x: 5, y: 3 }), 0);
+        assert_eq!(poly.winding_number(coord! { x: 7, y: 3 }), -1);
+        assert_eq!(poly.winding_number(coord! { x: 9, y: 3 }), 0);
+
+        // Overlapping polygons add up.
+        let other = polygon![(x: 3, y: 1), (x: 9, y: 1), (x: 9, y: 9), (x: 3, y: 9)];
+        let both = MultiPolygon::new(vec![poly, other]);
+        assert_eq!(both.winding_number(coord! { x: 5, y: 5 }), 2);
+        assert_eq!(both.winding_number(coord! { x: 7, y: 3 }), 0);
+        assert_eq!(both.winding_number(coord! { x: 7, y: 7 }), 1);
+    }
+}
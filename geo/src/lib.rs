@@ -1,4 +1,5 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! The `geo` crate provides geospatial primitive types and algorithms.
 //!
@@ -146,6 +147,10 @@
 //!
 //! The following optional [Cargo features] are available:
 //!
+//! - `std`: Enabled by default. Without it, the crate only depends on `core` and `alloc`, and only
+//!   provides the [`sweep`] and the algorithms it is built on, such as [`line_intersection`] and
+//!   the [`kernels`]. The debug traces of the sweep are then only logged if the `log` feature is
+//!   enabled
 //! - `proj-network`: Enables [network grid] support for the [`proj` crate]. After enabling this feature, [further configuration][proj crate file download] is required to use the network grid
 //! - `use-proj`: Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
 //! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde]
@@ -179,6 +184,7 @@
 //! [proj crate file download]: https://docs.rs/proj/*/proj/#grid-file-download
 //! [Serde]: https://serde.rs/
 
+extern crate alloc;
 extern crate geo_types;
 extern crate num_traits;
 #[cfg(feature = "use-serde")]
@@ -188,9 +194,28 @@ extern crate serde;
 extern crate proj;
 extern crate rstar;
 
+#[cfg(feature = "log")]
+#[macro_use]
+extern crate log;
+
+// Without `log`, the traces are type-checked, but not formatted.
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+
 pub use crate::algorithm::*;
 #[allow(deprecated)]
 pub use crate::traits::ToGeo;
+#[cfg(feature = "std")]
 pub use crate::types::Closest;
 
 pub use geo_types::{coord, line_string, point, polygon, CoordFloat, CoordNum};
@@ -199,10 +224,51 @@ pub mod geometry;
 pub use geometry::*;
 
 /// This module includes all the functions of geometric calculations
+#[cfg(feature = "std")]
 pub mod algorithm;
+
+/// This module includes the functions of geometric calculations available without `std`
+#[cfg(not(feature = "std"))]
+pub mod algorithm {
+    /// Kernels to compute various predicates
+    pub mod kernels;
+    pub use kernels::{orient2d, HasKernel, Kernel, Orientation};
+
+    /// Calculate the bounding rectangle of a `Geometry`.
+    pub mod bounding_rect;
+    pub use bounding_rect::BoundingRect;
+
+    /// Determine whether a `Coordinate` lies inside, outside, or on the boundary of a geometry.
+    pub mod coordinate_position;
+    pub use coordinate_position::CoordinatePosition;
+
+    /// Iterate over geometry coordinates.
+    pub mod coords_iter;
+    pub use coords_iter::CoordsIter;
+
+    /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
+    pub mod dimensions;
+    pub use dimensions::HasDimensions;
+
+    /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
+    pub mod intersects;
+    pub use intersects::{lines_intersect, Intersects};
+
+    /// Computes the intersection of two Lines.
+    pub mod line_intersection;
+    pub use line_intersection::LineIntersection;
+
+    /// Planar sweep algorithm and related utils
+    // Parts of the sweep are only used by the boolean operations.
+    #[allow(dead_code, unused_imports)]
+    pub mod sweep;
+}
+// Without `std`, some of the helpers are only used by the excluded algorithms.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
 mod geometry_cow;
 mod traits;
 mod types;
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
 mod utils;
 pub(crate) use geometry_cow::GeometryCow;
 
@@ -210,9 +276,6 @@ pub(crate) use geometry_cow::GeometryCow;
 #[macro_use]
 extern crate approx;
 
-#[macro_use]
-extern crate log;
-
 /// Mean radius of Earth in meters
 /// This is the value recommended by the IUGG:
 /// Moritz, H. (2000). Geodetic Reference System 1980. Journal of Geodesy, 74(1), 128–133. doi:10.1007/s001900050278
@@ -220,15 +283,19 @@ extern crate log;
 /// https://link.springer.com/article/10.1007%2Fs001900050278
 /// https://sci-hub.se/https://doi.org/10.1007/s001900050278
 /// https://en.wikipedia.org/wiki/Earth_radius#Mean_radius
+#[cfg(feature = "std")]
 const MEAN_EARTH_RADIUS: f64 = 6371008.8;
 
 // Radius of Earth at the equator in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const EQUATORIAL_EARTH_RADIUS: f64 = 6_378_137.0;
 
 // Radius of Earth at the poles in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const POLAR_EARTH_RADIUS: f64 = 6_356_752.314_245;
 
 // Flattening of the WGS-84 ellipsoid - https://en.wikipedia.org/wiki/Flattening
+#[cfg(feature = "std")]
 const EARTH_FLATTENING: f64 =
     (EQUATORIAL_EARTH_RADIUS - POLAR_EARTH_RADIUS) / EQUATORIAL_EARTH_RADIUS;
 
@@ -269,11 +336,7 @@ pub mod prelude {
 /// }
 /// ```
 pub trait GeoFloat:
-    GeoNum
-    + num_traits::Float
-    + num_traits::Signed
-    + num_traits::Bounded
-    + float_next_after::NextAfter<Self>
+    GeoNum + num_traits::Float + num_traits::Signed + num_traits::Bounded + float_next_after::NextAfter
 {
 }
 impl<T> GeoFloat for T where
@@ -281,7 +344,7 @@ impl<T> GeoFloat for T where
         + num_traits::Float
         + num_traits::Signed
         + num_traits::Bounded
-        + float_next_after::NextAfter<Self>
+        + float_next_after::NextAfter
 {
 }
 
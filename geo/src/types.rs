@@ -1,8 +1,10 @@
+#[cfg(feature = "std")]
 use crate::{GeoFloat, Point};
 
 /// The result of trying to find the closest spot on an object to a point.
 #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg(feature = "std")]
 pub enum Closest<F: GeoFloat> {
     /// The point actually intersects with the object.
     Intersection(Point<F>),
@@ -12,11 +14,12 @@ pub enum Closest<F: GeoFloat> {
     Indeterminate,
 }
 
+#[cfg(feature = "std")]
 impl<F: GeoFloat> Closest<F> {
     /// Compare two `Closest`s relative to `p` and return a copy of the best
     /// one.
     pub fn best_of_two(&self, other: &Self, p: Point<F>) -> Self {
-        use crate::EuclideanDistance;
+        use crate::SquaredEuclideanDistance;
 
         let left = match *self {
             Closest::Indeterminate => return *other,
@@ -29,7 +32,7 @@ impl<F: GeoFloat> Closest<F> {
             Closest::SinglePoint(r) => r,
         };
 
-        if left.euclidean_distance(&p) <= right.euclidean_distance(&p) {
+        if left.squared_euclidean_distance(&p) <= right.squared_euclidean_distance(&p) {
             *self
         } else {
             *other
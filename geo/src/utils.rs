@@ -77,7 +77,7 @@ pub fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
 // Moved to their own module, but we re-export to avoid breaking the API.
 pub use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 /// Compare two coordinates lexicographically: first by the
 /// x coordinate, and break ties with the y coordinate.